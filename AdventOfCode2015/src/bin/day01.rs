@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 1
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn part1(input: &str) -> i64
 {
     let mut floor = 0i64;
@@ -30,24 +28,36 @@ fn part2(input: &str) -> usize
     return 0;   // No solution?
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocaocfetch::get_data(2015, 1).unwrap();
+    aoclib::main::<Day>(2015, 1);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("(())"), 0);
-    assert_eq!(part1("()()"), 0);
-    assert_eq!(part1("((("), 3);
-    assert_eq!(part1("(()(()("), 3);
-    assert_eq!(part1("))((((("), 3);
-    assert_eq!(part1("())"), -1);
-    assert_eq!(part1("))("), -1);
-    assert_eq!(part1(")))"), -3);
-    assert_eq!(part1(")())())"), -3);
-    assert_eq!(part2(")"), 1);
-    assert_eq!(part2("()())"), 5);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("(())"), 0);
+        assert_eq!(part1("()()"), 0);
+        assert_eq!(part1("((("), 3);
+        assert_eq!(part1("(()(()("), 3);
+        assert_eq!(part1("))((((("), 3);
+        assert_eq!(part1("())"), -1);
+        assert_eq!(part1("))("), -1);
+        assert_eq!(part1(")))"), -3);
+        assert_eq!(part1(")())())"), -3);
+        assert_eq!(part2(")"), 1);
+        assert_eq!(part2("()())"), 5);
+    }
 }
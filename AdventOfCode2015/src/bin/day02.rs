@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 2
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::cmp::max;
 use std::cmp::min;
 
@@ -51,17 +50,29 @@ fn part2(input: &str) -> usize
     input.lines().map(ribbon).sum()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 2).unwrap();
+    aoclib::main::<Day>(2015, 2);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("2x3x4"), 58);
-    assert_eq!(part1("1x1x10"), 43);
-    assert_eq!(part2("2x3x4"), 34);
-    assert_eq!(part2("1x1x10"), 14);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("2x3x4"), 58);
+        assert_eq!(part1("1x1x10"), 43);
+        assert_eq!(part2("2x3x4"), 34);
+        assert_eq!(part2("1x1x10"), 14);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 3
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Pos = (i64, i64);
@@ -49,19 +48,31 @@ fn part2(input: &str) -> usize
     return visit.len();
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 3).unwrap();
+    aoclib::main::<Day>(2015, 3);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(">"), 2);
-    assert_eq!(part1("^>v<"), 4);
-    assert_eq!(part1("^v^v^v^v^v"), 2);
-    assert_eq!(part2("^v"), 3);
-    assert_eq!(part2("^>v<"), 3);
-    assert_eq!(part2("^v^v^v^v^v"), 11);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(">"), 2);
+        assert_eq!(part1("^>v<"), 4);
+        assert_eq!(part1("^v^v^v^v^v"), 2);
+        assert_eq!(part2("^v"), 3);
+        assert_eq!(part2("^>v<"), 3);
+        assert_eq!(part2("^v^v^v^v^v"), 11);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 4
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-use md5;
 
 fn md5_prefix(salt: &str, idx: usize) -> u32
 {
@@ -30,15 +28,27 @@ fn part2(input: &str) -> usize
     solve(input, 0xFFFFFFu32)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 4).unwrap();
+    aoclib::main::<Day>(2015, 4);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("abcdef"), 609043);
-    assert_eq!(part1("pqrstuv"), 1048970);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("abcdef"), 609043);
+        assert_eq!(part1("pqrstuv"), 1048970);
+    }
 }
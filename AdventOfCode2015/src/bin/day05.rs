@@ -2,12 +2,11 @@
 /// Copyright 2023 by Alex Utter
 
 use std::collections::HashMap;
-use aocfetch;
 
 fn is_nice1(input: &str) -> bool
 {
-    let vowels = vec!['a', 'e', 'i', 'o', 'u'];
-    let bad_pairs = vec![('a','b'), ('c','d'), ('p','q'), ('x','y')];
+    let vowels = ['a', 'e', 'i', 'o', 'u'];
+    let bad_pairs = [('a','b'), ('c','d'), ('p','q'), ('x','y')];
     let mut ct_vowels = 0usize;
     let mut flag_bad = false;
     let mut flag_dbl = false;
@@ -55,23 +54,35 @@ fn part2(input: &str) -> usize
     input.lines().filter(|x| is_nice2(x)).count()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 5).unwrap();
+    aoclib::main::<Day>(2015, 5);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(is_nice1("ugknbfddgicrmopn"), true);
-    assert_eq!(is_nice1("aaa"), true);
-    assert_eq!(is_nice1("jchzalrnumimnmhp"), false);
-    assert_eq!(is_nice1("haegwjzuvuyypxyu"), false);
-    assert_eq!(is_nice1("dvszwmarrgswjxmb"), false);
-    assert_eq!(is_nice2("aaa"), false);
-    assert_eq!(is_nice2("qjhvhtzxzqqjkmpb"), true);
-    assert_eq!(is_nice2("xxyxx"), true);
-    assert_eq!(is_nice2("uurcxstgmygtbstg"), false);
-    assert_eq!(is_nice2("ieodomkazucvgmuy"), false);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(is_nice1("ugknbfddgicrmopn"), true);
+        assert_eq!(is_nice1("aaa"), true);
+        assert_eq!(is_nice1("jchzalrnumimnmhp"), false);
+        assert_eq!(is_nice1("haegwjzuvuyypxyu"), false);
+        assert_eq!(is_nice1("dvszwmarrgswjxmb"), false);
+        assert_eq!(is_nice2("aaa"), false);
+        assert_eq!(is_nice2("qjhvhtzxzqqjkmpb"), true);
+        assert_eq!(is_nice2("xxyxx"), true);
+        assert_eq!(is_nice2("uurcxstgmygtbstg"), false);
+        assert_eq!(is_nice2("ieodomkazucvgmuy"), false);
+    }
 }
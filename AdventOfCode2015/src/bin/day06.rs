@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 6
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 enum Action {
     Set(bool),
     Toggle,
@@ -103,11 +101,16 @@ fn part2(input: &str) -> usize
     return grid.count();
 }
 
-fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 6).unwrap();
+/// Solution for the unified runner.
+pub struct Day;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
+fn main() {
+    aoclib::main::<Day>(2015, 6);
 }
@@ -2,7 +2,6 @@
 /// Copyright 2023 by Alex Utter
 
 use std::collections::HashMap;
-use aocfetch;
 
 #[derive(Clone,Copy)]
 enum Source {
@@ -66,7 +65,7 @@ impl Circuit {
     }
 
     fn get_source(&mut self, lbl: &str) -> Source {
-        if let Some(value) = lbl.parse().ok() {
+        if let Ok(value) = lbl.parse() {
             Source::Fixed(value)
         } else {
             Source::Wire(self.get_wire(lbl))
@@ -96,7 +95,7 @@ impl Circuit {
         if let Some(value) = self.wires.get(&wire) {return *value;}
         // Recursively calculate the output.
         if self.verbose {println!("Solving #{}...", wire);}
-        let gate: Operator = self.gates.get(&wire).unwrap().clone();
+        let gate: Operator = *self.gates.get(&wire).unwrap();
         let value: u16 = match gate {
             Operator::Buffer(x)     => self.solve(x),
             Operator::Not(x)        => !self.solve(x),
@@ -131,6 +130,7 @@ fn part2(input: &str) -> u16
     return ckt.solve_lbl("a");
 }
 
+#[cfg(test)]
 const TEST: &'static str = "\
     123 -> x
     456 -> y
@@ -141,22 +141,34 @@ const TEST: &'static str = "\
     NOT x -> h
     NOT y -> i";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 7).unwrap();
-
-    // Unit tests on provided example.
-    let mut test = Circuit::new(TEST, false);
-    assert_eq!(test.solve_lbl("d"), 72);
-    assert_eq!(test.solve_lbl("e"), 507);
-    assert_eq!(test.solve_lbl("f"), 492);
-    assert_eq!(test.solve_lbl("g"), 114);
-    assert_eq!(test.solve_lbl("h"), 65412);
-    assert_eq!(test.solve_lbl("i"), 65079);
-    assert_eq!(test.solve_lbl("x"), 123);
-    assert_eq!(test.solve_lbl("y"), 456);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2015, 7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let mut test = Circuit::new(TEST, false);
+        assert_eq!(test.solve_lbl("d"), 72);
+        assert_eq!(test.solve_lbl("e"), 507);
+        assert_eq!(test.solve_lbl("f"), 492);
+        assert_eq!(test.solve_lbl("g"), 114);
+        assert_eq!(test.solve_lbl("h"), 65412);
+        assert_eq!(test.solve_lbl("i"), 65079);
+        assert_eq!(test.solve_lbl("x"), 123);
+        assert_eq!(test.solve_lbl("y"), 456);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 8
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Decoder state for the "escape" function.
 enum Decode {
     Begin,
@@ -85,29 +83,46 @@ fn part2(input: &str) -> usize
 }
 
 // Unit tests.
+#[cfg(test)]
 const TEST1: &str = "\"\"";             // ""
+#[cfg(test)]
 const TEST2: &str = "\"abc\"";          // "abc"
+#[cfg(test)]
 const TEST3: &str = "\"aaa\\\"aaa\"";   // "aaa\"aaa"
+#[cfg(test)]
 const TEST4: &str = "\"\\x27\"";        // "\x27"
+#[cfg(test)]
 const TEST5: &str = "\"\"\n\"abc\"\n\"aaa\\\"aaa\"\n\"\\x27\"\n";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 8).unwrap();
+    aoclib::main::<Day>(2015, 8);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(escape(TEST1), "");
-    assert_eq!(escape(TEST2), "abc");
-    assert_eq!(escape(TEST3), "aaa\"aaa");
-    assert_eq!(escape(TEST4), "'");
-    assert_eq!(part1(TEST5), 12);
-    assert_eq!(encode(TEST1).len(), 6);
-    assert_eq!(encode(TEST2).len(), 9);
-    assert_eq!(encode(TEST3).len(), 16);
-    assert_eq!(encode(TEST4).len(), 11);
-    assert_eq!(part2(TEST5), 19);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(escape(TEST1), "");
+        assert_eq!(escape(TEST2), "abc");
+        assert_eq!(escape(TEST3), "aaa\"aaa");
+        assert_eq!(escape(TEST4), "'");
+        assert_eq!(part1(TEST5), 12);
+        assert_eq!(encode(TEST1).len(), 6);
+        assert_eq!(encode(TEST2).len(), 9);
+        assert_eq!(encode(TEST3).len(), 16);
+        assert_eq!(encode(TEST4).len(), 11);
+        assert_eq!(part2(TEST5), 19);
+    }
 }
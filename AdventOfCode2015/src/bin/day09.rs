@@ -3,7 +3,6 @@
 
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use aocfetch;
 
 struct Cities {
     labels: HashMap<String, usize>,
@@ -52,7 +51,7 @@ impl Cities {
         }
         // Brute-force search until we visit all cities.
         let mut max_dist = 0u64;
-        let mut min_dist = std::u64::MAX;
+        let mut min_dist = u64::MAX;
         while let Some((city, mask, accum)) = queue.pop_front() {
             for n in 0..num_cities {
                 if city == n {continue;}
@@ -83,20 +82,33 @@ fn part2(input: &str) -> u64
 }
 
 // Example from the problem statement:
+#[cfg(test)]
 const TEST: &str = "\
     London to Dublin = 464
     London to Belfast = 518
     Dublin to Belfast = 141";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 9).unwrap();
+    aoclib::main::<Day>(2015, 9);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST), 605);
-    assert_eq!(part2(TEST), 982);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 605);
+        assert_eq!(part2(TEST), 982);
+    }
 }
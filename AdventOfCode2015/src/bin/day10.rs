@@ -1,12 +1,10 @@
 /// Advent of Code 2015, Day 10
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn look_say(seed: &str) -> String {
     let mut result = String::new();
     let mut count = 0usize;
-    let mut digit = seed.chars().nth(0).unwrap();
+    let mut digit = seed.chars().next().unwrap();
     for ch in seed.chars() {
         if ch == digit {
             count += 1;
@@ -41,14 +39,26 @@ fn part2(input: &str) -> usize
     look_iter(input, 50).len()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 10).unwrap();
+    aoclib::main::<Day>(2015, 10);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(look_iter("1", 5), "312211");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(look_iter("1", 5), "312211");
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 11
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Commonly used characters:
 const CH_A: u32 = 'a' as u32;
 const CH_I: u32 = 'i' as u32;
@@ -57,9 +55,9 @@ impl Password {
             if *ch == CH_Z {
                 *ch = CH_A; continue;   // Wraparound
             } else if BAD_CHARS.contains(&(*ch+1)) {
-                *ch = *ch + 2; break;   // Skip I/L/O
+                *ch += 2; break;   // Skip I/L/O
             } else {
-                *ch = *ch + 1; break;   // Increment
+                *ch += 1; break;   // Increment
             }
         }
     }
@@ -82,20 +80,32 @@ fn part2(input: &str) -> String
     return part1(&part1(input));
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 11).unwrap();
+    aoclib::main::<Day>(2015, 11);
+}
 
-    // Unit tests on provided examples.
-    assert!(!Password::new("hijklmmn").valid());
-    assert!(!Password::new("abbceffg").valid());
-    assert!(!Password::new("abbcegjk").valid());
-    assert!(Password::new("abcdffaa").valid());
-    assert!(Password::new("ghjaabcc").valid());
-    assert_eq!(part1("abcdefgh"), "abcdffaa");
-    assert_eq!(part1("ghijklmn"), "ghjaabcc");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert!(!Password::new("hijklmmn").valid());
+        assert!(!Password::new("abbceffg").valid());
+        assert!(!Password::new("abbcegjk").valid());
+        assert!(Password::new("abcdffaa").valid());
+        assert!(Password::new("ghjaabcc").valid());
+        assert_eq!(part1("abcdefgh"), "abcdffaa");
+        assert_eq!(part1("ghijklmn"), "ghjaabcc");
+    }
 }
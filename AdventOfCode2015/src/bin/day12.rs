@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 12
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn part1(input: &str) -> i64
 {
     // Character-by-character parsing.
@@ -72,25 +70,37 @@ fn part2(input: &str) -> i64
     return accum3 + accum2 * keep;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 12).unwrap();
+    aoclib::main::<Day>(2015, 12);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1("[1,2,3]"), 6);
-    assert_eq!(part1("{\"a\":2,\"b\":4}"), 6);
-    assert_eq!(part1("[[[3]]]"), 3);
-    assert_eq!(part1("{\"a\":{\"b\":4},\"c\":-1}"), 3);
-    assert_eq!(part1("{\"a\":[-1,1]}"), 0);
-    assert_eq!(part1("[-1,{\"a\":1}]"), 0);
-    assert_eq!(part1("[]"), 0);
-    assert_eq!(part1("{}"), 0);
-    assert_eq!(part2("[1,2,3]"), 6);
-    assert_eq!(part2("[1,{\"c\":\"red\",\"b\":2},3]"), 4);
-    assert_eq!(part2("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}"), 0);
-    assert_eq!(part2("[1,\"red\",5]"), 6);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("[1,2,3]"), 6);
+        assert_eq!(part1("{\"a\":2,\"b\":4}"), 6);
+        assert_eq!(part1("[[[3]]]"), 3);
+        assert_eq!(part1("{\"a\":{\"b\":4},\"c\":-1}"), 3);
+        assert_eq!(part1("{\"a\":[-1,1]}"), 0);
+        assert_eq!(part1("[-1,{\"a\":1}]"), 0);
+        assert_eq!(part1("[]"), 0);
+        assert_eq!(part1("{}"), 0);
+        assert_eq!(part2("[1,2,3]"), 6);
+        assert_eq!(part2("[1,{\"c\":\"red\",\"b\":2},3]"), 4);
+        assert_eq!(part2("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}"), 0);
+        assert_eq!(part2("[1,\"red\",5]"), 6);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 13
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 struct Permutation {
@@ -112,6 +111,7 @@ fn part2(input: &str) -> i64
     return diners.best_score(diners.len() + 1);
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     Alice would gain 54 happiness units by sitting next to Bob.
     Alice would lose 79 happiness units by sitting next to Carol.
@@ -126,14 +126,26 @@ const TEST: &str = "\
     David would lose 7 happiness units by sitting next to Bob.
     David would gain 41 happiness units by sitting next to Carol.";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 13).unwrap();
+    aoclib::main::<Day>(2015, 13);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST), 330);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 330);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 14
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Reindeer {
     speed: u64,
     t_fly: u64,
@@ -54,21 +52,33 @@ fn part2(input: &str) -> u64
     return *scores.iter().max().unwrap();
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 14).unwrap();
+    aoclib::main::<Day>(2015, 14);
+}
 
-    // Unit tests on provided examples.
-    let test1 = Reindeer::new(
-        "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.");
-    let test2 = Reindeer::new(
-        "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.");
-    assert_eq!(test1.distance(11), 140);
-    assert_eq!(test2.distance(11), 176);
-    assert_eq!(test1.distance(1000), 1120);
-    assert_eq!(test2.distance(1000), 1056);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        let test1 = Reindeer::new(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.");
+        let test2 = Reindeer::new(
+            "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.");
+        assert_eq!(test1.distance(11), 140);
+        assert_eq!(test2.distance(11), 176);
+        assert_eq!(test1.distance(1000), 1120);
+        assert_eq!(test2.distance(1000), 1056);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 15
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Metric {
     capacity: i64,
     durability: i64,
@@ -103,19 +101,32 @@ fn part2(input: &str) -> i64
     Recipe::new(input).best_score(&Some(500))
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
     Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 15).unwrap();
+    aoclib::main::<Day>(2015, 15);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST), 62842880);
-    assert_eq!(part2(TEST), 57600000);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 62842880);
+        assert_eq!(part2(TEST), 57600000);
+    }
 }
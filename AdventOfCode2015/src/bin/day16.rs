@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 16
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -51,10 +50,10 @@ impl Aunt {
         }
     }
 
-    fn is_match(&self, foo: &Aunt) -> bool {
-        // Each item in self.info must be an match to the one in foo.info.
+    fn is_match(&self, other: &Aunt) -> bool {
+        // Each item in self.info must be an match to the one in other.info.
         // (But not vice-versa, since items in self.info may be missing.)
-        self.info.iter().all(|(typ,qty)| foo.check(typ, *qty))
+        self.info.iter().all(|(typ,qty)| other.check(typ, *qty))
     }
 }
 
@@ -81,11 +80,16 @@ fn part2(input: &str) -> String {
     return String::from("No match");
 }
 
-fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 16).unwrap();
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+fn main() {
+    aoclib::main::<Day>(2015, 16);
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 17
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug)]
@@ -78,16 +77,28 @@ fn part2(input: &str) -> usize {
     Containers::new(input).part2(150)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 17).unwrap();
+    aoclib::main::<Day>(2015, 17);
+}
 
-    // Unit tests based on the provided examples:
-    let test = Containers::new("20\n15\n10\n5\n5");
-    assert_eq!(test.part1(25), 4);
-    assert_eq!(test.part2(25), 3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        let test = Containers::new("20\n15\n10\n5\n5");
+        assert_eq!(test.part1(25), 4);
+        assert_eq!(test.part2(25), 3);
+    }
 }
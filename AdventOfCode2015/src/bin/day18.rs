@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 18
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 #[derive(Clone, Debug)]
@@ -46,7 +45,7 @@ impl Grid {
         let nn = [(r-1,c-1), (r-1,c), (r-1,c+1),
                   (r,  c-1),          (r,  c+1),
                   (r+1,c-1), (r+1,c), (r+1,c+1)];
-        nn.iter().map(|rc| self.lit.contains(&rc) as usize).sum()
+        nn.iter().map(|rc| self.lit.contains(rc) as usize).sum()
     }
 
     fn step_one(&self) -> Self {
@@ -81,6 +80,7 @@ fn part2(input: &str) -> usize {
     Grid::new(input, true).step(100).count()
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     .#.#.#
     ...##.
@@ -89,24 +89,36 @@ const TEST: &str = "\
     #.#..#
     ####..";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 18).unwrap();
-
-    // Unit tests based on the provided examples:
-    assert_eq!(Grid::new(TEST, false).step(0).count(), 15);
-    assert_eq!(Grid::new(TEST, false).step(1).count(), 11);
-    assert_eq!(Grid::new(TEST, false).step(2).count(), 8);
-    assert_eq!(Grid::new(TEST, false).step(3).count(), 4);
-    assert_eq!(Grid::new(TEST, false).step(4).count(), 4);
-    assert_eq!(Grid::new(TEST, true).step(0).count(), 17);
-    assert_eq!(Grid::new(TEST, true).step(1).count(), 18);
-    assert_eq!(Grid::new(TEST, true).step(2).count(), 18);
-    assert_eq!(Grid::new(TEST, true).step(3).count(), 18);
-    assert_eq!(Grid::new(TEST, true).step(4).count(), 14);
-    assert_eq!(Grid::new(TEST, true).step(5).count(), 17);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2015, 18);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(Grid::new(TEST, false).step(0).count(), 15);
+        assert_eq!(Grid::new(TEST, false).step(1).count(), 11);
+        assert_eq!(Grid::new(TEST, false).step(2).count(), 8);
+        assert_eq!(Grid::new(TEST, false).step(3).count(), 4);
+        assert_eq!(Grid::new(TEST, false).step(4).count(), 4);
+        assert_eq!(Grid::new(TEST, true).step(0).count(), 17);
+        assert_eq!(Grid::new(TEST, true).step(1).count(), 18);
+        assert_eq!(Grid::new(TEST, true).step(2).count(), 18);
+        assert_eq!(Grid::new(TEST, true).step(3).count(), 18);
+        assert_eq!(Grid::new(TEST, true).step(4).count(), 14);
+        assert_eq!(Grid::new(TEST, true).step(5).count(), 17);
+    }
 }
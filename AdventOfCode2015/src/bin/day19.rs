@@ -1,7 +1,6 @@
 /// Advent of Code 2015, Day 19
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -42,8 +41,8 @@ impl Rules {
                 let words: Vec<&str> = line.trim().split(' ').collect();
                 let ll = result.str2chem(words[0]);
                 let rr = result.str2chem(words[2]);
-                result.rules.entry(ll[0]).or_insert(Vec::new()).push(rr.clone());
-                result.rinv.entry(rr).or_insert(Vec::new()).push(ll[0]);
+                result.rules.entry(ll[0]).or_default().push(rr.clone());
+                result.rinv.entry(rr).or_default().push(ll[0]);
             } else {
                 chem = result.str2chem(line);
             }
@@ -60,11 +59,11 @@ impl Rules {
             if ch.is_lowercase() {              // Continue current atom?
                 tmp.push(ch);
             } else if ch.is_uppercase() {       // Start a new atom?
-                if tmp.len() > 0 {result.push(tmp);}
+                if !tmp.is_empty() {result.push(tmp);}
                 tmp = String::from(ch);
             }
         }
-        if tmp.len() > 0 {result.push(tmp);}    // Final atom in string
+        if !tmp.is_empty() {result.push(tmp);}    // Final atom in string
         return result;
     }
 
@@ -168,17 +167,30 @@ fn part2(input: &str) -> usize {
     return count;
 }
 
+#[cfg(test)]
 const TEST: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOH";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 19).unwrap();
+    aoclib::main::<Day>(2015, 19);
+}
 
-    // Unit tests based on the provided examples:
-    assert_eq!(part1(TEST), 4);
-    assert_eq!(part2(TEST), 3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 4);
+        assert_eq!(part2(TEST), 3);
+    }
 }
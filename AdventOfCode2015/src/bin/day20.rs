@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 20
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn div_round_up(x: usize, y: usize) -> usize {
     (x + y - 1) / y
 }
@@ -45,16 +43,28 @@ fn part2(input: &str) -> usize {
     return first_above(&score, min_score);
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 20).unwrap();
+    aoclib::main::<Day>(2015, 20);
+}
 
-    // Unit tests based on the provided examples:
-    assert_eq!(part1("70"), 4);
-    assert_eq!(part1("71"), 6);
-    assert_eq!(part1("130"), 8);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("70"), 4);
+        assert_eq!(part1("71"), 6);
+        assert_eq!(part1("130"), 8);
+    }
 }
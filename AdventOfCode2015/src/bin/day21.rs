@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 21
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Item {
     cost: usize,
     damage: usize,
@@ -29,6 +27,7 @@ struct Fighter {
 }
 
 impl Fighter {
+    #[cfg(test)]
     fn new(hp:usize, damage:usize, armor:usize) -> Self {
         Fighter { hp:hp, damage:damage, armor:armor, spent:0 }
     }
@@ -119,7 +118,7 @@ impl Iterator for ItemShop {
         if self.idx_wpn > 4 {return None;}
 
         // Add the current item set.
-        let mut player = self.player.clone();
+        let mut player = self.player;
         player.add_item(&self.weapon());
         player.add_item(&self.armor());
         player.add_item(&self.ring(self.idx_r1));
@@ -168,16 +167,28 @@ fn part2(input: &str) -> usize {
     return budget;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 21).unwrap();
+    aoclib::main::<Day>(2015, 21);
+}
 
-    // Unit tests based on the provided examples:
-    let test_play = Fighter::new(8, 5, 5);
-    let test_boss = Fighter::new(12, 7, 2);
-    assert!(test_play.fight(&test_boss));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        let test_play = Fighter::new(8, 5, 5);
+        let test_boss = Fighter::new(12, 7, 2);
+        assert!(test_play.fight(&test_boss));
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 22
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Subtract hit points, to a minimum of zero (knocked out).
 fn damage(hp: &mut usize, dmg: usize) {
     if *hp > dmg {*hp -= dmg;} else {*hp = 0;}
@@ -161,7 +159,7 @@ impl State {
         // Breadth first search of all possible game states...
         let mut min_mana = usize::MAX;
         let mut states: Vec<State> = vec![self.clone()];
-        while states.len() > 0 {
+        while !states.is_empty() {
             // From each current state, try all possible moves...
             let mut next_states = Vec::new();
             for state in states.iter() {
@@ -191,17 +189,29 @@ fn part2(input: &str) -> usize {
     State::from(input).mana_to_win(true)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 22).unwrap();
-
-    // Unit tests based on the provided examples:
-    let test1 = State::new(13, 8, 10, 250);
-    let test2 = State::new(14, 8, 10, 250);
-    assert!(test1.mana_to_win(false) <= 226);
-    assert!(test2.mana_to_win(false) <= 641);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2015, 22);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test1 = State::new(13, 8, 10, 250);
+        let test2 = State::new(14, 8, 10, 250);
+        assert!(test1.mana_to_win(false) <= 226);
+        assert!(test2.mana_to_win(false) <= 641);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 23
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 enum Opcode {
     HalfA,              // Halve designated register
     HalfB,              // Halve designated register
@@ -97,11 +95,7 @@ impl Program {
     fn next(&self) -> Option<State> {
         if self.state.pctr < 0 {
             None
-        } else if let Some(op) = self.prog.get(self.state.pctr as usize) {
-            Some(self.state.exec(op))
-        } else {
-            None
-        }
+        } else { self.prog.get(self.state.pctr as usize).map(|op| self.state.exec(op)) }
     }
 
     fn run(&mut self) {
@@ -124,15 +118,27 @@ fn part2(input: &str) -> usize {
     return prog.state.reg_b;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 23).unwrap();
+    aoclib::main::<Day>(2015, 23);
+}
 
-    // Unit tests based on the provided examples:
-    let test = "inc b\n jio b, +2\n tpl b\n inc b";
-    assert_eq!(part1(test), 2);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        let test = "inc b\n jio b, +2\n tpl b\n inc b";
+        assert_eq!(part1(test), 2);
+    }
 }
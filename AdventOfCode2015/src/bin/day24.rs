@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 24
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Packages {
     list: Vec<usize>,
     prod: usize,
@@ -42,7 +40,7 @@ impl Packages {
     }
 
     // Return a Splitter object for the designated target weight.
-    fn split(&self, target: usize) -> Splitter {
+    fn split(&self, target: usize) -> Splitter<'_> {
         Splitter { index:0, source:&self.list, target:target }
     }
 
@@ -130,16 +128,28 @@ fn part2(input: &str) -> usize {
     solve(input, 4)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 24).unwrap();
+    aoclib::main::<Day>(2015, 24);
+}
 
-    // Unit tests based on the provided examples:
-    let test = "1\n2\n3\n4\n5\n7\n8\n9\n10\n11";
-    assert_eq!(part1(test), 99);
-    assert_eq!(part2(test), 44);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        let test = "1\n2\n3\n4\n5\n7\n8\n9\n10\n11";
+        assert_eq!(part1(test), 99);
+        assert_eq!(part2(test), 44);
+    }
 }
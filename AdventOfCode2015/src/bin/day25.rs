@@ -1,8 +1,6 @@
 /// Advent of Code 2015, Day 25
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn lcg(x: u64) -> u64 {
     (x * 252533u64) % 33554393u64
 }
@@ -25,48 +23,60 @@ fn part1(input: &str) -> u32 {
     lcg_rc(rc[0], rc[1])
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2015, 25).unwrap();
+    aoclib::main::<Day>(2015, 25);
+}
 
-    // Unit tests based on the provided examples:
-    assert_eq!(lcg_rc(1, 1), 20151125);
-    assert_eq!(lcg_rc(1, 2), 18749137);
-    assert_eq!(lcg_rc(1, 3), 17289845);
-    assert_eq!(lcg_rc(1, 4), 30943339);
-    assert_eq!(lcg_rc(1, 5), 10071777);
-    assert_eq!(lcg_rc(1, 6), 33511524);
-    assert_eq!(lcg_rc(2, 1), 31916031);
-    assert_eq!(lcg_rc(2, 2), 21629792);
-    assert_eq!(lcg_rc(2, 3), 16929656);
-    assert_eq!(lcg_rc(2, 4), 7726640);
-    assert_eq!(lcg_rc(2, 5), 15514188);
-    assert_eq!(lcg_rc(2, 6), 4041754);
-    assert_eq!(lcg_rc(3, 1), 16080970);
-    assert_eq!(lcg_rc(3, 2), 8057251);
-    assert_eq!(lcg_rc(3, 3), 1601130);
-    assert_eq!(lcg_rc(3, 4), 7981243);
-    assert_eq!(lcg_rc(3, 5), 11661866);
-    assert_eq!(lcg_rc(3, 6), 16474243);
-    assert_eq!(lcg_rc(4, 1), 24592653);
-    assert_eq!(lcg_rc(4, 2), 32451966);
-    assert_eq!(lcg_rc(4, 3), 21345942);
-    assert_eq!(lcg_rc(4, 4), 9380097);
-    assert_eq!(lcg_rc(4, 5), 10600672);
-    assert_eq!(lcg_rc(4, 6), 31527494);
-    assert_eq!(lcg_rc(5, 1), 77061);
-    assert_eq!(lcg_rc(5, 2), 17552253);
-    assert_eq!(lcg_rc(5, 3), 28094349);
-    assert_eq!(lcg_rc(5, 4), 6899651);
-    assert_eq!(lcg_rc(5, 5), 9250759);
-    assert_eq!(lcg_rc(5, 6), 31663883);
-    assert_eq!(lcg_rc(6, 1), 33071741);
-    assert_eq!(lcg_rc(6, 2), 6796745);
-    assert_eq!(lcg_rc(6, 3), 25397450);
-    assert_eq!(lcg_rc(6, 4), 24659492);
-    assert_eq!(lcg_rc(6, 5), 1534922);
-    assert_eq!(lcg_rc(6, 6), 27995004);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(lcg_rc(1, 1), 20151125);
+        assert_eq!(lcg_rc(1, 2), 18749137);
+        assert_eq!(lcg_rc(1, 3), 17289845);
+        assert_eq!(lcg_rc(1, 4), 30943339);
+        assert_eq!(lcg_rc(1, 5), 10071777);
+        assert_eq!(lcg_rc(1, 6), 33511524);
+        assert_eq!(lcg_rc(2, 1), 31916031);
+        assert_eq!(lcg_rc(2, 2), 21629792);
+        assert_eq!(lcg_rc(2, 3), 16929656);
+        assert_eq!(lcg_rc(2, 4), 7726640);
+        assert_eq!(lcg_rc(2, 5), 15514188);
+        assert_eq!(lcg_rc(2, 6), 4041754);
+        assert_eq!(lcg_rc(3, 1), 16080970);
+        assert_eq!(lcg_rc(3, 2), 8057251);
+        assert_eq!(lcg_rc(3, 3), 1601130);
+        assert_eq!(lcg_rc(3, 4), 7981243);
+        assert_eq!(lcg_rc(3, 5), 11661866);
+        assert_eq!(lcg_rc(3, 6), 16474243);
+        assert_eq!(lcg_rc(4, 1), 24592653);
+        assert_eq!(lcg_rc(4, 2), 32451966);
+        assert_eq!(lcg_rc(4, 3), 21345942);
+        assert_eq!(lcg_rc(4, 4), 9380097);
+        assert_eq!(lcg_rc(4, 5), 10600672);
+        assert_eq!(lcg_rc(4, 6), 31527494);
+        assert_eq!(lcg_rc(5, 1), 77061);
+        assert_eq!(lcg_rc(5, 2), 17552253);
+        assert_eq!(lcg_rc(5, 3), 28094349);
+        assert_eq!(lcg_rc(5, 4), 6899651);
+        assert_eq!(lcg_rc(5, 5), 9250759);
+        assert_eq!(lcg_rc(5, 6), 31663883);
+        assert_eq!(lcg_rc(6, 1), 33071741);
+        assert_eq!(lcg_rc(6, 2), 6796745);
+        assert_eq!(lcg_rc(6, 3), 25397450);
+        assert_eq!(lcg_rc(6, 4), 24659492);
+        assert_eq!(lcg_rc(6, 5), 1534922);
+        assert_eq!(lcg_rc(6, 6), 27995004);
+    }
 }
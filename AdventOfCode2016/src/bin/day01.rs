@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 1
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 use std::collections::HashSet;
 
 struct Direction {
@@ -75,16 +73,28 @@ fn part2(input: &str) -> i64
     return 0;                       // No match found...
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 1).unwrap();
+    aoclib::main::<Day>(2016, 1);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("R2, L3"), 5);
-    assert_eq!(part1("R2, R2, R2"), 2);
-    assert_eq!(part1("R5, L5, R5, R3"), 12);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("R2, L3"), 5);
+        assert_eq!(part1("R2, R2, R2"), 2);
+        assert_eq!(part1("R5, L5, R5, R3"), 12);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 2
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Posn(i64, i64);
 
 fn move_one(prev: &Posn, step: char) -> Posn {
@@ -56,7 +54,7 @@ fn part1(input: &str) -> i64
         // ignoring attempts to move out-of-bounds.
         for step in line.trim().chars() {
             let next = move_one(&pos, step);
-            if let Some(_) = decode3(&next) {pos = next;}
+            if decode3(&next).is_some() {pos = next;}
         }
         // Append current button to the sequence.
         seq = 10*seq + decode3(&pos).unwrap();
@@ -73,7 +71,7 @@ fn part2(input: &str) -> i64
         // ignoring attempts to move out-of-bounds.
         for step in line.trim().chars() {
             let next = move_one(&pos, step);
-            if let Some(_) = decode5(&next) {pos = next;}
+            if decode5(&next).is_some() {pos = next;}
         }
         // Append current button to the sequence.
         seq = 16*seq + decode5(&pos).unwrap();
@@ -81,15 +79,27 @@ fn part2(input: &str) -> i64
     return seq;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {format!("{:x}", part2(input))}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 2).unwrap();
+    aoclib::main::<Day>(2016, 2);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("ULL\nRRDDD\nLURDL\nUUUUD"), 1985);
-    assert_eq!(part2("ULL\nRRDDD\nLURDL\nUUUUD"), 0x5DB3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {:x}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("ULL\nRRDDD\nLURDL\nUUUUD"), 1985);
+        assert_eq!(part2("ULL\nRRDDD\nLURDL\nUUUUD"), 0x5DB3);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 3
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Triplet(i64, i64, i64);
 
 fn read_line(line: &str) -> Triplet
@@ -44,6 +42,7 @@ fn part2(input: &str) -> i64
     return count;
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 101 301 501
 102 302 502
@@ -52,16 +51,28 @@ const TEST: &str = "\
 202 402 602
 203 403 603";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 3).unwrap();
+    aoclib::main::<Day>(2016, 3);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("5 10 25\n3 4 5\n"), 1);
-    assert_eq!(part1(TEST), 3);
-    assert_eq!(part2(TEST), 6);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("5 10 25\n3 4 5\n"), 1);
+        assert_eq!(part1(TEST), 3);
+        assert_eq!(part2(TEST), 6);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 4
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 
@@ -86,20 +84,33 @@ fn part2(input: &str) -> i64
     0
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 aaaaa-bbb-z-y-x-123[abxyz]
 a-b-c-d-e-f-g-h-987[abcde]
 not-a-real-room-404[oarel]
 totally-real-room-200[decoy]";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 4).unwrap();
+    aoclib::main::<Day>(2016, 4);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 1514);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 1514);
+    }
 }
@@ -1,9 +1,6 @@
 /// Advent of Code 2016, Day 5
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
-use md5;
 
 // Return the first four bytes of the MD5 hash.
 fn md5_prefix(base: &str, idx: u64) -> u64 {
@@ -55,18 +52,30 @@ fn part2(input: &str) -> u64
     return password;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {format!("{:08X}", part1(input))}
+    fn part2(input: &String) -> impl std::fmt::Display {format!("{:08X}", part2(input))}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 5).unwrap();
+    aoclib::main::<Day>(2016, 5);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(md5_prefix("abc", 3231929)/256, 1);
-    assert_eq!(md5_prefix("abc", 5017308)/256, 8);
-    assert_eq!(md5_prefix("abc", 5278568)/256, 15);
-    assert_eq!(part1("abc"), 0x18f47a30);
-    assert_eq!(part2("abc"), 0x05ace8e3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {:08X}", part1(input.trim()));
-    println!("Part 2: {:08X}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(md5_prefix("abc", 3231929)/256, 1);
+        assert_eq!(md5_prefix("abc", 5017308)/256, 8);
+        assert_eq!(md5_prefix("abc", 5278568)/256, 15);
+        assert_eq!(part1("abc"), 0x18f47a30);
+        assert_eq!(part2("abc"), 0x05ace8e3);
+    }
 }
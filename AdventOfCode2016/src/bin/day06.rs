@@ -1,12 +1,10 @@
 /// Advent of Code 2016, Day 6
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn solve(input: &str, part1: bool) -> String
 {
     // Measure the length of the target word.
-    let word_len = input.trim().lines().nth(0).unwrap().len();
+    let word_len = input.trim().lines().next().unwrap().len();
 
     // Count occurences of each letter in each position.
     let mut counts = vec![0u64; 26*word_len];
@@ -39,6 +37,7 @@ fn part2(input: &str) -> String
     solve(input, false)
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 eedadn
 drvtee
@@ -57,14 +56,26 @@ vrdear
 dvrsen
 enarar";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 6).unwrap();
+    aoclib::main::<Day>(2016, 6);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), "easter");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), "easter");
+    }
 }
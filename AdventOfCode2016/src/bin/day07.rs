@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 7
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 fn is_tls(line: &str) -> bool
@@ -67,21 +66,33 @@ fn part2(input: &str) -> usize
     input.trim().lines().filter(|&x| is_ssl(x)).count()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 7).unwrap();
+    aoclib::main::<Day>(2016, 7);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(is_tls("abba[mnop]qrst"), true);
-    assert_eq!(is_tls("abcd[bddb]xyyx"), false);
-    assert_eq!(is_tls("aaaa[qwer]tyui"), false);
-    assert_eq!(is_tls("ioxxoj[asdfgh]zxcvbn"), true);
-    assert_eq!(is_ssl("aba[bab]xyz"), true);
-    assert_eq!(is_ssl("xyx[xyx]xyx"), false);
-    assert_eq!(is_ssl("aaa[kek]eke"), true);
-    assert_eq!(is_ssl("zazbz[bzb]cdb"), true);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(is_tls("abba[mnop]qrst"), true);
+        assert_eq!(is_tls("abcd[bddb]xyyx"), false);
+        assert_eq!(is_tls("aaaa[qwer]tyui"), false);
+        assert_eq!(is_tls("ioxxoj[asdfgh]zxcvbn"), true);
+        assert_eq!(is_ssl("aba[bab]xyz"), true);
+        assert_eq!(is_ssl("xyx[xyx]xyx"), false);
+        assert_eq!(is_ssl("aaa[kek]eke"), true);
+        assert_eq!(is_ssl("zazbz[bzb]cdb"), true);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 8
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 const COLS: usize = 50;
 const ROWS: usize = 6;
 const TOKENS: [char;4] = [' ', '=', 'x', 'y'];
@@ -21,7 +19,7 @@ impl Screen {
     }
 
     fn apply(&self, line: &str) -> Self {
-        let mut next = self.pix.clone();
+        let mut next = self.pix;
         let cmd: Vec<&str> = line.split(&TOKENS).collect();
         if cmd[0] == "rect" {
             let cc: usize = cmd[1].parse().unwrap();
@@ -80,20 +78,33 @@ fn part2(input: &str) -> String {
     execute(input, false).render()
 }
 
+#[cfg(test)]
 const TEST_IN: &str = "\
 rect 3x2
 rotate column x=1 by 1
 rotate row y=0 by 4
 rotate column x=1 by 1";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 8).unwrap();
+    aoclib::main::<Day>(2016, 8);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST_IN), 6);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2:\n{}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST_IN), 6);
+    }
 }
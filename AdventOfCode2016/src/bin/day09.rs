@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 9
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Read a marker of the form "(MxN)".
 // Returns tuple (L, M, N), where L is the length of the marker itself.
 fn read_marker(input: &[char]) -> Option<(usize, usize, usize)> {
@@ -54,32 +52,52 @@ fn part2(input: &str) -> usize {
     analyze(&tmp, true)
 }
 
+#[cfg(test)]
 const TEST1: &str = "ADVENT";
+#[cfg(test)]
 const TEST2: &str = "A(1x5)BC";
+#[cfg(test)]
 const TEST3: &str = "(3x3)XYZ";
+#[cfg(test)]
 const TEST4: &str = "A(2x2)BCD(2x2)EFG";
+#[cfg(test)]
 const TEST5: &str = "(6x1)(1x3)A";
+#[cfg(test)]
 const TEST6: &str = "X(8x2)(3x3)ABCY";
+#[cfg(test)]
 const TEST7: &str = "(27x12)(20x12)(13x14)(7x10)(1x12)A";
+#[cfg(test)]
 const TEST8: &str = "(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 9).unwrap();
+    aoclib::main::<Day>(2016, 9);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST1), 6);
-    assert_eq!(part1(TEST2), 7);
-    assert_eq!(part1(TEST3), 9);
-    assert_eq!(part1(TEST4), 11);
-    assert_eq!(part1(TEST5), 6);
-    assert_eq!(part1(TEST6), 18);
-    assert_eq!(part2(TEST3), 9);
-    assert_eq!(part2(TEST6), 20);
-    assert_eq!(part2(TEST7), 241920);
-    assert_eq!(part2(TEST8), 445);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST1), 6);
+        assert_eq!(part1(TEST2), 7);
+        assert_eq!(part1(TEST3), 9);
+        assert_eq!(part1(TEST4), 11);
+        assert_eq!(part1(TEST5), 6);
+        assert_eq!(part1(TEST6), 18);
+        assert_eq!(part2(TEST3), 9);
+        assert_eq!(part2(TEST6), 20);
+        assert_eq!(part2(TEST7), 241920);
+        assert_eq!(part2(TEST8), 445);
+    }
 }
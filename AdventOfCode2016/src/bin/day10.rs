@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 10
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::cmp::max;
 use std::cmp::min;
 use std::collections::HashMap;
@@ -48,8 +47,8 @@ impl Bot {
     fn new(line: &str) -> Self {
         let tokens: Vec<&str> = line.trim().split(' ').collect();
         let label: usize    = tokens[1].parse().unwrap();
-        let tgt_lo: Target  = Target::parse(&tokens[5], &tokens[6]);
-        let tgt_hi: Target  = Target::parse(&tokens[10], &tokens[11]);
+        let tgt_lo: Target  = Target::parse(tokens[5], tokens[6]);
+        let tgt_hi: Target  = Target::parse(tokens[10], tokens[11]);
         return Bot { label: label, rule: (tgt_lo, tgt_hi), held: None };
     }
 
@@ -78,12 +77,12 @@ impl Sim {
     }
 
     fn give_bin(&mut self, bin: usize, token: usize) {
-        let bin = self.bins.entry(bin).or_insert(Vec::new());
+        let bin = self.bins.entry(bin).or_default();
         bin.push(token);
     }
 
     fn give_bot(&mut self, bot: usize, token: usize) {
-        let mut obj = self.bots.get_mut(&bot).unwrap();
+        let obj = self.bots.get_mut(&bot).unwrap();
         if let Some(tok_bot) = obj.held {
             // Pass along the new and old tokens.
             let tok_lo = min(token, tok_bot);
@@ -134,6 +133,7 @@ fn part2(rules: &str) -> usize {
     return tok0 * tok1 * tok2;
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 value 61 goes to bot 2
 bot 2 gives low to bot 1 and high to bot 0
@@ -142,14 +142,26 @@ bot 1 gives low to output 1 and high to bot 0
 bot 0 gives low to output 2 and high to output 0
 value 2 goes to bot 2";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 10).unwrap();
+    aoclib::main::<Day>(2016, 10);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 0);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 0);
+    }
 }
@@ -1,22 +1,23 @@
 /// Advent of Code 2016, Day 11
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::bits::BitSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
 const NUM_FLOORS: usize = 4;
 
-// Mask-related functions:
-fn mask_set(x:&mut u64, y:u64) {*x |= y;}
-fn mask_clr(x:&mut u64, y:u64) {*x &= !y;}
-fn mask_safe(gen: u64, mcu: u64) -> bool
-    {(gen == 0) || (mcu & gen == mcu)}
+// Set of generators or microchips, by label index.
+type Items = BitSet<64>;
+
+// A floor is safe if it has no generators, or every chip has its generator.
+fn is_safe(gen: Items, mcu: Items) -> bool
+    {gen.is_empty() || mcu.is_subset(&gen)}
 
 // Assign sequential indices to each unique label.
 struct Labeler {
-    map: HashMap<String, u64>,
+    map: HashMap<String, usize>,
 }
 
 impl Labeler {
@@ -24,11 +25,11 @@ impl Labeler {
         Labeler { map: HashMap::new() }
     }
 
-    fn len(&self) -> u64 {
-        self.map.len() as u64
+    fn len(&self) -> usize {
+        self.map.len()
     }
 
-    fn lookup_or_create(&mut self, lbl: &str) -> u64 {
+    fn lookup_or_create(&mut self, lbl: &str) -> usize {
         if let Some(idx) = self.map.get(lbl) {
             return *idx;        // Match existing label.
         } else {
@@ -42,10 +43,10 @@ impl Labeler {
 // Combined state for elevator, generators, and microchips.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct State {
-    num_chips: u64,             // Number of chip-generator pairs
-    elevator: usize,            // Elevator position [0..NUM_FLOORS)
-    mask_gen: [u64;NUM_FLOORS], // Generators on each floor
-    mask_mcu: [u64;NUM_FLOORS], // Microchips on each floor
+    num_chips: usize,               // Number of chip-generator pairs
+    elevator: usize,                // Elevator position [0..NUM_FLOORS)
+    mask_gen: [Items;NUM_FLOORS],   // Generators on each floor
+    mask_mcu: [Items;NUM_FLOORS],   // Microchips on each floor
 }
 
 impl State {
@@ -55,8 +56,8 @@ impl State {
         let mut state = State {
             num_chips: 0,
             elevator: 0,
-            mask_gen: [0;NUM_FLOORS],
-            mask_mcu: [0;NUM_FLOORS],
+            mask_gen: [Items::new();NUM_FLOORS],
+            mask_mcu: [Items::new();NUM_FLOORS],
         };
         // On each line, look for the word "generator" or "microchip".
         for (floor,line) in input.trim().lines().enumerate() {
@@ -75,44 +76,44 @@ impl State {
     }
 
     // Add a generator or microchip to the designated floor.
-    fn add_gen(&mut self, floor: usize, index: u64) {
-        mask_set(&mut self.mask_gen[floor], 1u64 << index);
+    fn add_gen(&mut self, floor: usize, index: usize) {
+        self.mask_gen[floor].insert(index);
         if index >= self.num_chips {self.num_chips += 1;}
     }
-    fn add_mcu(&mut self, floor: usize, index: u64) {
-        mask_set(&mut self.mask_mcu[floor], 1u64 << index);
+    fn add_mcu(&mut self, floor: usize, index: usize) {
+        self.mask_mcu[floor].insert(index);
         if index >= self.num_chips {self.num_chips += 1;}
     }
 
     // Have we reached the desired final state?
     fn done(&self) -> bool {
-        let target = (1u64 << self.num_chips) - 1;
+        let target: Items = (0..self.num_chips).collect();
         return (self.elevator == NUM_FLOORS-1)
             && (self.mask_gen[self.elevator] == target)
             && (self.mask_mcu[self.elevator] == target);
     }
 
     // Move the elevator up or down, bringing designated device(s).
-    fn next(&self, up:bool, take_gen:u64, take_mcu:u64) -> Option<State> {
+    fn next(&self, up:bool, take_gen:Items, take_mcu:Items) -> Option<State> {
         // Movement rules check before we start.
-        if !mask_safe(take_gen, take_mcu) {return None;}
+        if !is_safe(take_gen, take_mcu) {return None;}
         if !up && self.elevator == 0 {return None;}
         if up && self.elevator >= NUM_FLOORS-1 {return None;}
-        if take_gen == 0 && take_mcu == 0 {return None;}
-        if take_gen & self.mask_gen[self.elevator] != take_gen {return None;}
-        if take_mcu & self.mask_mcu[self.elevator] != take_mcu {return None;}
+        if take_gen.is_empty() && take_mcu.is_empty() {return None;}
+        if !take_gen.is_subset(&self.mask_gen[self.elevator]) {return None;}
+        if !take_mcu.is_subset(&self.mask_mcu[self.elevator]) {return None;}
         // Move the designated objects.
         let mut next = self.clone();
         next.elevator = if up {self.elevator+1} else {self.elevator-1};
-        mask_clr(&mut next.mask_gen[self.elevator], take_gen);
-        mask_clr(&mut next.mask_mcu[self.elevator], take_mcu);
-        mask_set(&mut next.mask_gen[next.elevator], take_gen);
-        mask_set(&mut next.mask_mcu[next.elevator], take_mcu);
+        next.mask_gen[self.elevator] -= take_gen;
+        next.mask_mcu[self.elevator] -= take_mcu;
+        next.mask_gen[next.elevator] |= take_gen;
+        next.mask_mcu[next.elevator] |= take_mcu;
         // Safety checks on the old and new floors.
-        if !mask_safe(next.mask_gen[self.elevator],
-                      next.mask_mcu[self.elevator]) {return None;}
-        if !mask_safe(next.mask_gen[next.elevator],
-                      next.mask_mcu[next.elevator]) {return None;}
+        if !is_safe(next.mask_gen[self.elevator],
+                    next.mask_mcu[self.elevator]) {return None;}
+        if !is_safe(next.mask_gen[next.elevator],
+                    next.mask_mcu[next.elevator]) {return None;}
         return Some(next);
     }
 
@@ -123,12 +124,13 @@ impl State {
         for up in [true, false] {
             for aa in 0..self.num_chips {
                 for bb in aa..self.num_chips {
-                    let am = 1u64 << aa;
-                    let bm = 1u64 << bb;
-                    if let Some(x) = self.next(up, am|bm, 0)    {list.push(x);}
+                    let am = Items::single(aa);
+                    let bm = Items::single(bb);
+                    let none = Items::new();
+                    if let Some(x) = self.next(up, am|bm, none) {list.push(x);}
                     if let Some(x) = self.next(up, am, bm)      {list.push(x);}
-                    if let Some(x) = self.next(up, bm, aa)      {list.push(x);}
-                    if let Some(x) = self.next(up, 0, am|bm)    {list.push(x);}
+                    if let Some(x) = self.next(up, bm, am)      {list.push(x);}
+                    if let Some(x) = self.next(up, none, am|bm) {list.push(x);}
                 }
             }
         }
@@ -173,20 +175,42 @@ fn part2(input: &str) -> usize {
     return bfs(&init, false);
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
 The second floor contains a hydrogen generator.
 The third floor contains a lithium generator.
 The fourth floor contains nothing relevant.";
 
+// A full-size puzzle, with five pairs of items.
+#[cfg(test)]
+const TEST2: &str = "\
+The first floor contains a thulium generator, a thulium-compatible microchip, a plutonium generator, and a strontium generator.
+The second floor contains a plutonium-compatible microchip and a strontium-compatible microchip.
+The third floor contains a promethium generator, a promethium-compatible microchip, a ruthenium generator, and a ruthenium-compatible microchip.
+The fourth floor contains nothing relevant.";
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 11).unwrap();
+    aoclib::main::<Day>(2016, 11);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 11);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 11);
+        assert_eq!(part1(TEST2), 31);
+    }
 }
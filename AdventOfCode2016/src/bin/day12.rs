@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 12
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn parse_int(x: Option<&&str>) -> Option<i64> {
     if let Some(y) = x {y.parse().ok()} else {None}
 }
@@ -104,6 +102,7 @@ fn part2(input: &str) -> i64 {
     return state.regs[0];
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 cpy 41 a
 inc a
@@ -112,14 +111,26 @@ dec a
 jnz a 2
 dec a";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 12).unwrap();
+    aoclib::main::<Day>(2016, 12);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 42);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 42);
+    }
 }
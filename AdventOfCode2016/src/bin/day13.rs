@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 13
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
@@ -33,8 +32,8 @@ fn is_open(key: u64, xy: &XY) -> bool {
 fn bfs(key: u64, init: &XY, goal: Option<&XY>, dist: Option<usize>) -> usize {
     let mut queue = VecDeque::<(XY,usize)>::new();
     let mut visit = HashSet::<XY>::new();
-    queue.push_back((init.clone(), 0usize));
-    visit.insert(init.clone());
+    queue.push_back((*init, 0usize));
+    visit.insert(*init);
     while let Some((xy,count)) = queue.pop_front() {
         if let Some(d) = dist {         // Limit search distance?
             if count >= d {continue;}
@@ -61,14 +60,26 @@ fn part2(input: &str) -> usize {
     return bfs(key, &XY(1,1), None, Some(50));
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 13).unwrap();
+    aoclib::main::<Day>(2016, 13);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(bfs(10, &XY(1,1), Some(&XY(7,4)), None), 11);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(bfs(10, &XY(1,1), Some(&XY(7,4)), None), 11);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 14
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-use md5;
 
 #[derive(Clone)]
 struct Hash {
@@ -56,7 +54,7 @@ impl Hash {
         for d in digits {
             let n = d as usize;
             count = if d == prev {count+1} else {1};
-            if count >= 3 && hash.tripl == None {hash.tripl = Some(d);}
+            if count >= 3 && hash.tripl.is_none() {hash.tripl = Some(d);}
             if count >= hash.count[n] {hash.count[n] = count;}
             prev = d;
         }
@@ -120,22 +118,34 @@ fn part2(input: &str) -> usize {
     return index;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 14).unwrap();
-
-    // Unit tests on provided examples
-    let mut test1 = HashFinder::new("abc", 1000, 0);
-    let mut test2 = HashFinder::new("abc", 1000, 2016);
-    assert_eq!(Hash::new("abc", 18, 0).has3(), Some(8));
-    assert_eq!(Hash::new("abc", 5, 2016).has3(), Some(2));
-    assert_eq!(test1.next(), 39);
-    assert_eq!(test1.next(), 92);
-    assert_eq!(test2.next(), 10);
-    assert_eq!(part1("abc"), 22728);
-    assert_eq!(part2("abc"), 22551);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::main::<Day>(2016, 14);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let mut test1 = HashFinder::new("abc", 1000, 0);
+        let mut test2 = HashFinder::new("abc", 1000, 2016);
+        assert_eq!(Hash::new("abc", 18, 0).has3(), Some(8));
+        assert_eq!(Hash::new("abc", 5, 2016).has3(), Some(2));
+        assert_eq!(test1.next(), 39);
+        assert_eq!(test1.next(), 92);
+        assert_eq!(test2.next(), 10);
+        assert_eq!(part1("abc"), 22728);
+        assert_eq!(part2("abc"), 22551);
+    }
 }
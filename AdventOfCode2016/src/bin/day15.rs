@@ -1,69 +1,77 @@
 /// Advent of Code 2016, Day 15
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::numtheory::crt;
 
-#[derive(Clone)]
-struct DiskState {
+struct Disk {
     depth: usize,
     period: usize,
     phase: usize,
 }
 
-const INIT: DiskState = DiskState {depth: 0, period: 1, phase: 0};
-
-impl DiskState {
+impl Disk {
     fn new(line: &str) -> Self {
         let tokens: Vec<&str> = line.trim().split([' ', '#', '.']).collect();
-        DiskState {
+        Disk {
             depth:  tokens[2].parse().unwrap(),
             period: tokens[4].parse().unwrap(),
             phase:  tokens[12].parse().unwrap(),
         }
     }
 
-    fn add(&mut self, next: &Self) {
-        // Advance time in steps until we find a solution.
-        while (self.phase + next.phase + next.depth) % next.period != 0 {
-            self.phase += self.period;
-        }
-        // Update the least-common multiple of the aggregate solution.
-        // Note: Input periods are always mutually prime, no reduce LCM.
-        self.period *= next.period;
-        self.depth   = next.depth;
+    // The capsule passes if it reaches this disk at position zero,
+    // i.e., if t + depth + phase = 0 (mod period).
+    fn congruence(&self) -> (i64, i64) {
+        (-((self.depth + self.phase) as i64), self.period as i64)
     }
 }
 
-fn solve(input: &str) -> DiskState {
-    let mut state = INIT.clone();
-    for line in input.trim().lines() {
-        state.add(&DiskState::new(line));
-    }
-    return state;
+fn parse(input: &str) -> Vec<Disk> {
+    input.trim().lines().map(Disk::new).collect()
+}
+
+// Find the first time that passes through every disk.
+fn solve(disks: &[Disk]) -> usize {
+    let pairs: Vec<_> = disks.iter().map(Disk::congruence).collect();
+    return crt(&pairs).unwrap().0 as usize;
 }
 
 fn part1(input: &str) -> usize {
-    solve(input).phase
+    solve(&parse(input))
 }
 
 fn part2(input: &str) -> usize {
-    let mut soln = solve(input);
-    soln.add(&DiskState {depth: soln.depth+1, period: 11, phase: 0});
-    return soln.phase
+    let mut disks = parse(input);
+    let depth = disks.last().map_or(0, |d| d.depth) + 1;
+    disks.push(Disk {depth: depth, period: 11, phase: 0});
+    return solve(&disks);
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 Disc #1 has 5 positions; at time=0, it is at position 4.
 Disc #2 has 2 positions; at time=0, it is at position 1.";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 15).unwrap();
+    aoclib::main::<Day>(2016, 15);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 5);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 5);
+    }
 }
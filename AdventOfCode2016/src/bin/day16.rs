@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 16
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct BitString {
     bits: Vec<u8>,
 }
@@ -66,18 +64,30 @@ fn part2(input: &str) -> String {
     solve(input.trim(), 35651584)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 16).unwrap();
+    aoclib::main::<Day>(2016, 16);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(
-        BitString::new("111100001010").expand().to_string(),
-        "1111000010100101011110000");
-    assert_eq!(solve("110010110100", 12), "100");
-    assert_eq!(solve("10000", 20), "01100");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(
+            BitString::new("111100001010").expand().to_string(),
+            "1111000010100101011110000");
+        assert_eq!(solve("110010110100", 12), "100");
+        assert_eq!(solve("10000", 20), "01100");
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 17
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-use md5;
 use std::collections::VecDeque;
 
 fn door_open(x: u8) -> bool {(x & 0xF) >= 0xB}
@@ -78,19 +76,31 @@ fn part2(input: &str) -> usize {
     solve(input, false).len()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 17).unwrap();
+    aoclib::main::<Day>(2016, 17);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("ihgpwlah"), "DDRRRD");
-    assert_eq!(part1("kglvqrro"), "DDUDRLRRUDRD");
-    assert_eq!(part1("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
-    assert_eq!(part2("ihgpwlah"), 370);
-    assert_eq!(part2("kglvqrro"), 492);
-    assert_eq!(part2("ulqzkmiv"), 830);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("ihgpwlah"), "DDRRRD");
+        assert_eq!(part1("kglvqrro"), "DDUDRLRRUDRD");
+        assert_eq!(part1("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+        assert_eq!(part2("ihgpwlah"), 370);
+        assert_eq!(part2("kglvqrro"), 492);
+        assert_eq!(part2("ulqzkmiv"), 830);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 18
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Row {
     tiles: Vec<bool>,
 }
@@ -56,15 +54,27 @@ fn part2(input: &str) -> usize {
     count_safe(input, 400000)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 18).unwrap();
+    aoclib::main::<Day>(2016, 18);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(count_safe("..^^.", 3), 6);
-    assert_eq!(count_safe(".^^.^.^^^^", 10), 38);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(count_safe("..^^.", 3), 6);
+        assert_eq!(count_safe(".^^.^.^^^^", 10), 38);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 19
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 struct Elf {
@@ -77,15 +76,27 @@ fn part2(input: &str) -> usize {
     Circle::new(size).part2() + 1
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 19).unwrap();
+    aoclib::main::<Day>(2016, 19);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1("5"), 3);
-    assert_eq!(part2("5"), 2);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("5"), 3);
+        assert_eq!(part2("5"), 2);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2016, Day 21
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 type VChar = Vec<char>;
 
 enum Step {
@@ -143,6 +141,7 @@ fn part2(input: &str) -> String {
     unscramble(input, "fbgdceah")
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     swap position 4 with position 0
     swap letter d with letter b
@@ -153,15 +152,27 @@ const TEST: &str = "\
     rotate based on position of letter b
     rotate based on position of letter d";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 21).unwrap();
+    aoclib::main::<Day>(2016, 21);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(scramble(TEST, "abcde"), "decab");
-    assert_eq!(unscramble(TEST, "decab"), "abcde");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(scramble(TEST, "abcde"), "decab");
+        assert_eq!(unscramble(TEST, "decab"), "abcde");
+    }
 }
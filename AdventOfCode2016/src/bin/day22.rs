@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 22
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
@@ -22,7 +21,7 @@ impl Node {
         // Example: "/dev/grid/node-x0-y0 88T 67T 21T 76%"
         let tokens: Vec<&str> = line.trim()
             .split([' ', 'x', 'y', '-', 'T'])
-            .filter(|x| x.len() > 0)
+            .filter(|x| !x.is_empty())
             .collect();
         let col: usize = tokens[1].parse().unwrap();
         let row: usize = tokens[2].parse().unwrap();
@@ -150,6 +149,7 @@ fn part2(input: &str) -> usize {
     panic!("No solution.");
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     Filesystem            Size  Used  Avail  Use%
     /dev/grid/node-x0-y0   10T    8T     2T   80%
@@ -162,15 +162,27 @@ const TEST: &str = "\
     /dev/grid/node-x2-y1    9T    8T     1T   88%
     /dev/grid/node-x2-y2    9T    6T     3T   66%";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 22).unwrap();
+    aoclib::main::<Day>(2016, 22);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 7);
-    assert_eq!(part2(TEST), 7);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 7);
+        assert_eq!(part2(TEST), 7);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2016, Day 24
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -90,6 +89,7 @@ fn part2(input: &str) -> usize {
     return maze.solve(true);
 }
 
+#[cfg(test)]
 const TEST: &str = "\
     ###########
     #0.1.....2#
@@ -97,14 +97,26 @@ const TEST: &str = "\
     #4.......3#
     ###########";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2016, 24).unwrap();
+    aoclib::main::<Day>(2016, 24);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(TEST), 14);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 14);
+    }
 }
@@ -23,8 +23,22 @@ fn check(guess: u64) -> bool {
     return true;
 }
 
-fn main() {
+fn part1() -> u64 {
     let mut guess = 1u64;
     while !check(guess + 2550) { guess += 1; }
-    println!("Part 1: {guess}");
+    return guess;
+}
+
+/// Solution for the unified runner.
+/// (The input program was analyzed by hand, so it isn't needed here.)
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = ();
+    fn parse(_input: &str) {}
+    fn part1(_input: &()) -> impl std::fmt::Display {part1()}
+}
+
+fn main() {
+    aoclib::print::<Day>("");
 }
@@ -0,0 +1,67 @@
+//! Advent of Code 2016, all days as a library for the unified runner.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each day is still its own binary; this just gathers them in one place.
+
+#![allow(dead_code)]
+
+#[path = "bin/day01.rs"] pub mod day01;
+#[path = "bin/day02.rs"] pub mod day02;
+#[path = "bin/day03.rs"] pub mod day03;
+#[path = "bin/day04.rs"] pub mod day04;
+#[path = "bin/day05.rs"] pub mod day05;
+#[path = "bin/day06.rs"] pub mod day06;
+#[path = "bin/day07.rs"] pub mod day07;
+#[path = "bin/day08.rs"] pub mod day08;
+#[path = "bin/day09.rs"] pub mod day09;
+#[path = "bin/day10.rs"] pub mod day10;
+#[path = "bin/day11.rs"] pub mod day11;
+#[path = "bin/day12.rs"] pub mod day12;
+#[path = "bin/day13.rs"] pub mod day13;
+#[path = "bin/day14.rs"] pub mod day14;
+#[path = "bin/day15.rs"] pub mod day15;
+#[path = "bin/day16.rs"] pub mod day16;
+#[path = "bin/day17.rs"] pub mod day17;
+#[path = "bin/day18.rs"] pub mod day18;
+#[path = "bin/day19.rs"] pub mod day19;
+#[path = "bin/day20.rs"] pub mod day20;
+#[path = "bin/day21.rs"] pub mod day21;
+#[path = "bin/day22.rs"] pub mod day22;
+#[path = "bin/day23.rs"] pub mod day23;
+#[path = "bin/day24.rs"] pub mod day24;
+#[path = "bin/day25.rs"] pub mod day25;
+
+/// Recorded answers for this year, see "aoc verify".
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Registry of solutions for this year.
+pub fn solutions() -> Vec<aoclib::Entry>
+{
+    vec![
+        aoclib::Entry::new::<day01::Day>(2016, 1),
+        aoclib::Entry::new::<day02::Day>(2016, 2),
+        aoclib::Entry::new::<day03::Day>(2016, 3),
+        aoclib::Entry::new::<day04::Day>(2016, 4),
+        aoclib::Entry::new::<day05::Day>(2016, 5),
+        aoclib::Entry::new::<day06::Day>(2016, 6),
+        aoclib::Entry::new::<day07::Day>(2016, 7),
+        aoclib::Entry::new::<day08::Day>(2016, 8),
+        aoclib::Entry::new::<day09::Day>(2016, 9),
+        aoclib::Entry::new::<day10::Day>(2016, 10),
+        aoclib::Entry::new::<day11::Day>(2016, 11),
+        aoclib::Entry::new::<day12::Day>(2016, 12),
+        aoclib::Entry::new::<day13::Day>(2016, 13),
+        aoclib::Entry::new::<day14::Day>(2016, 14),
+        aoclib::Entry::new::<day15::Day>(2016, 15),
+        aoclib::Entry::new::<day16::Day>(2016, 16),
+        aoclib::Entry::new::<day17::Day>(2016, 17),
+        aoclib::Entry::new::<day18::Day>(2016, 18),
+        aoclib::Entry::new::<day19::Day>(2016, 19),
+        aoclib::Entry::new::<day20::Day>(2016, 20),
+        aoclib::Entry::new::<day21::Day>(2016, 21),
+        aoclib::Entry::new::<day22::Day>(2016, 22),
+        aoclib::Entry::new::<day23::Day>(2016, 23),
+        aoclib::Entry::new::<day24::Day>(2016, 24),
+        aoclib::Entry::new::<day25::Day>(2016, 25).embedded(),
+    ]
+}
@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 1
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn to_digit(x: &char) -> u64
 {
     return u64::from(x.to_digit(10).unwrap())
@@ -30,22 +28,34 @@ fn part2(input: &str) -> u64
     return 2*sum
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2017, 1).unwrap();
-
-    // Unit tests on provided examples
-    assert_eq!(part1("1122"), 3);
-    assert_eq!(part1("1111"), 4);
-    assert_eq!(part1("1234"), 0);
-    assert_eq!(part1("91212129"), 9);
-    assert_eq!(part2("1212"), 6);
-    assert_eq!(part2("1221"), 0);
-    assert_eq!(part2("123425"), 4);
-    assert_eq!(part2("123123"), 12);
-    assert_eq!(part2("12131415"), 4);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2017, 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1("1122"), 3);
+        assert_eq!(part1("1111"), 4);
+        assert_eq!(part1("1234"), 0);
+        assert_eq!(part1("91212129"), 9);
+        assert_eq!(part2("1212"), 6);
+        assert_eq!(part2("1221"), 0);
+        assert_eq!(part2("123425"), 4);
+        assert_eq!(part2("123123"), 12);
+        assert_eq!(part2("12131415"), 4);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 2
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 type Row = Vec<i64>;
 type Matrix = Vec<Row>;
 
@@ -45,17 +43,31 @@ fn part2(input: &Matrix) -> i64
     return sum 
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Matrix;
+    fn parse(input: &str) -> Matrix {read_matrix(input)}
+    fn part1(input: &Matrix) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Matrix) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let test1 = read_matrix("5\t1\t9\t5\n7\t5\t3\n2\t4\t6\t8\n");
-    let test2 = read_matrix("5\t9\t2\t8\n9\t4\t7\t3\n3\t8\t6\t5\n");
-    let input = read_matrix(&aocfetch::get_data(2017, 2).unwrap());
-
-    // Unit tests on provided examples
-    assert_eq!(part1(&test1), 18);
-    assert_eq!(part2(&test2), 9);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::main::<Day>(2017, 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test1 = read_matrix("5\t1\t9\t5\n7\t5\t3\n2\t4\t6\t8\n");
+        let test2 = read_matrix("5\t9\t2\t8\n9\t4\t7\t3\n3\t8\t6\t5\n");
+
+        // Unit tests on provided examples
+        assert_eq!(part1(&test1), 18);
+        assert_eq!(part2(&test2), 9);
+    }
 }
@@ -83,22 +83,37 @@ fn part2(input: &i64) -> i64
     }
 }
 
+// Input for this problem is a single number.
+const INPUT: i64 = 289326;
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = i64;
+    fn parse(_input: &str) -> i64 {INPUT}
+    fn part1(input: &i64) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &i64) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Input for this problem is a single number.
-    let input: i64 = 289326;
-
-    // Unit tests on provided examples and other edge cases.
-    assert_eq!(part1(&1), 0);
-    assert_eq!(part1(&12), 3);
-    assert_eq!(part1(&23), 2);
-    assert_eq!(part1(&26), 5);
-    assert_eq!(part1(&31), 6);
-    assert_eq!(part1(&1024), 31);
-    assert_eq!(part2(&24), 25);
-    assert_eq!(part2(&351), 362);
-    assert_eq!(part2(&747), 806);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::print::<Day>("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&1), 0);
+        assert_eq!(part1(&12), 3);
+        assert_eq!(part1(&23), 2);
+        assert_eq!(part1(&26), 5);
+        assert_eq!(part1(&31), 6);
+        assert_eq!(part1(&1024), 31);
+        assert_eq!(part2(&24), 25);
+        assert_eq!(part2(&351), 362);
+        assert_eq!(part2(&747), 806);
+    }
 }
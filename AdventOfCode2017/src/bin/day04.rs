@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 4
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 // Is a given passphrase valid under Part 1 rules?
@@ -41,24 +40,36 @@ fn part2(input: &str) -> usize
     return input.lines().filter(|x| valid_p2(x)).count()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 4).unwrap();
+    aoclib::main::<Day>(2017, 4);
+}
 
-    // Unit tests on provided examples.
-    assert!(valid_p1("aa bb cc dd ee"));
-    assert!(!valid_p1("aa bb cc dd aa"));
-    assert!(valid_p1("aa bb cc dd aaa"));
-    assert!(valid_p1("aa bb cc dd ee"));
-    assert!(!valid_p1("aa bb cc dd aa"));
-    assert!(valid_p1("aa bb cc dd aaa"));
-    assert!(valid_p2("abcde fghij"));
-    assert!(!valid_p2("abcde xyz ecdab"));
-    assert!(valid_p2("a ab abc abd abf abj"));
-    assert!(valid_p2("iiii oiii ooii oooi oooo"));
-    assert!(!valid_p2("oiii ioii iioi iiio"));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert!(valid_p1("aa bb cc dd ee"));
+        assert!(!valid_p1("aa bb cc dd aa"));
+        assert!(valid_p1("aa bb cc dd aaa"));
+        assert!(valid_p1("aa bb cc dd ee"));
+        assert!(!valid_p1("aa bb cc dd aa"));
+        assert!(valid_p1("aa bb cc dd aaa"));
+        assert!(valid_p2("abcde fghij"));
+        assert!(!valid_p2("abcde xyz ecdab"));
+        assert!(valid_p2("a ab abc abd abf abj"));
+        assert!(valid_p2("iiii oiii ooii oooi oooo"));
+        assert!(!valid_p2("oiii ioii iioi iiio"));
+    }
 }
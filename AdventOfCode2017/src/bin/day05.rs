@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 5
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 type Program = Vec<i64>;
 
 fn read_input(input: &str) -> Program
@@ -42,16 +40,30 @@ fn part2(input: &Program) -> usize
     return count    // Number of instructions required to escape.
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Program;
+    fn parse(input: &str) -> Program {read_input(input)}
+    fn part1(input: &Program) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Program) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let test: Program  = vec![0, 3, 0, 1, -3];
-    let input: Program = read_input(&aocfetch::get_data(2017, 5).unwrap());
+    aoclib::main::<Day>(2017, 5);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Unit tests on provided example.
-    assert_eq!(part1(&test), 5);
-    assert_eq!(part2(&test), 10);
+    #[test]
+    fn examples() {
+        let test: Program  = vec![0, 3, 0, 1, -3];
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+        // Unit tests on provided example.
+        assert_eq!(part1(&test), 5);
+        assert_eq!(part2(&test), 10);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 6
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type MemState = Vec<usize>;
@@ -43,15 +42,29 @@ fn solve(input: &MemState) -> (usize, usize)
     return (seen.len(), seen.len() - seen[&next]);
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (usize, usize);
+    fn parse(input: &str) -> (usize, usize) {solve(&read_input(input))}
+    fn part1(input: &(usize, usize)) -> impl std::fmt::Display {input.0}
+    fn part2(input: &(usize, usize)) -> impl std::fmt::Display {input.1}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let test: MemState  = vec![0, 2, 7, 0];
-    let input: MemState = read_input(&aocfetch::get_data(2017, 6).unwrap());
+    aoclib::main::<Day>(2017, 6);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Unit tests on provided example.
-    assert_eq!(solve(&test), (5, 4));
+    #[test]
+    fn examples() {
+        let test: MemState  = vec![0, 2, 7, 0];
 
-    // Solve for real input.
-    println!("Part 1: {}", solve(&input).0);
-    println!("Part 2: {}", solve(&input).1);
+        // Unit tests on provided example.
+        assert_eq!(solve(&test), (5, 4));
+    }
 }
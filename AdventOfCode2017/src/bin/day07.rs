@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 7
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 struct Program {
@@ -34,7 +33,7 @@ impl Program {
     }
 }
 
-struct Stack {
+pub struct Stack {
     pnames: Vec<String>,
     programs: HashMap<String, Program>,
 }
@@ -48,8 +47,7 @@ impl Stack {
             programs.insert(prog.name.clone(), prog);
         }
         // Make a copy of all the program names.
-        let pnames: Vec<String> = programs.keys()
-            .map(|x| x.clone()).collect();
+        let pnames: Vec<String> = programs.keys().cloned().collect();
         // Link parents for each node.
         for parent in pnames.iter() {
             let children = programs.get(parent).unwrap().children.clone();
@@ -74,7 +72,7 @@ impl Stack {
     fn root(&self) -> Option<String> {
         // Find the node with no parent.
         for prog in self.programs.values() {
-            if let None = prog.parent { return Some(prog.name.clone()); }
+            if prog.parent.is_none() { return Some(prog.name.clone()); }
         }
         return None;
     }
@@ -82,7 +80,7 @@ impl Stack {
     // Is the designated node balanced?
     fn balanced(&self, label: &str) -> bool {
         let child = &self.programs[label].children;
-        if child.len() == 0 {
+        if child.is_empty() {
             return true;    // No children -> Always balanced.
         } else {
             let wt_ref = self.programs[&child[0]].total;
@@ -119,6 +117,7 @@ impl Stack {
     }
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 pbga (66)
 xhth (57)
@@ -134,16 +133,30 @@ ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Stack;
+    fn parse(input: &str) -> Stack {Stack::new(input)}
+    fn part1(input: &Stack) -> impl std::fmt::Display {input.root().unwrap()}
+    fn part2(input: &Stack) -> impl std::fmt::Display {input.correction()}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let test: Stack = Stack::new(&TEST);
-    let input: Stack = Stack::new(&aocfetch::get_data(2017, 7).unwrap());
+    aoclib::main::<Day>(2017, 7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Unit tests on provided example.
-    assert_eq!(test.root().unwrap(), "tknk");
-    assert_eq!(test.correction(), 60);
+    #[test]
+    fn examples() {
+        let test: Stack = Stack::new(TEST);
 
-    // Solve for real input.
-    println!("Part 1: {}", &input.root().unwrap());
-    println!("Part 2: {}", &input.correction());
+        // Unit tests on provided example.
+        assert_eq!(test.root().unwrap(), "tknk");
+        assert_eq!(test.correction(), 60);
+    }
 }
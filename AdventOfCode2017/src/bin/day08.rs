@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 8
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 enum Compare {
@@ -18,7 +17,7 @@ struct Instruction {
     compare: Compare,
 }
 
-struct Program {
+pub struct Program {
     instructions: Vec<Instruction>,
 }
 
@@ -56,7 +55,7 @@ impl Instruction {
         let sign    = if words[1] == "inc" {1} else {-1};
         let offset  = words[2].parse::<i64>().unwrap() * sign;
         let reg_cmp = words[4].to_string();
-        let compare = Compare::new(&words[5], &words[6]).unwrap();
+        let compare = Compare::new(words[5], words[6]).unwrap();
         return Instruction { reg_mod, offset, reg_cmp, compare };
     }
 
@@ -97,22 +96,37 @@ fn part2(prog: &Program) -> i64
     return vmax;
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 b inc 5 if a > 1
 a inc 1 if b < 5
 c dec -10 if a >= 1
 c inc -20 if c == 10";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Program;
+    fn parse(input: &str) -> Program {Program::new(input)}
+    fn part1(input: &Program) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Program) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let test  = Program::new(&TEST);
-    let input = Program::new(&aocfetch::get_data(2017, 8).unwrap());
+    aoclib::main::<Day>(2017, 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Unit tests on provided example.
-    assert_eq!(part1(&test), 1);
-    assert_eq!(part2(&test), 10);
+    #[test]
+    fn examples() {
+        let test  = Program::new(TEST);
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+        // Unit tests on provided example.
+        assert_eq!(part1(&test), 1);
+        assert_eq!(part2(&test), 10);
+    }
 }
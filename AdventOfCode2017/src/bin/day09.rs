@@ -1,11 +1,9 @@
 /// Advent of Code 2017, Day 9
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Per problem description, a Thing is either a Garbage string <...>
 // or a Group {...} containing a comma-delimited list of Things.
-enum Thing {
+pub enum Thing {
     Garbage(String),
     Group(Things),
 }
@@ -63,28 +61,40 @@ fn part1(grp: &Thing) -> i64 { nesting(grp, 1) }
 
 fn part2(grp: &Thing) -> i64 { garbage(grp) }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Thing;
+    fn parse(input: &str) -> Thing {parse(input)}
+    fn part1(input: &Thing) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Thing) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = parse(&aocfetch::get_data(2017, 9).unwrap());
+    aoclib::main::<Day>(2017, 9);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(&parse("{}")), 1);
-    assert_eq!(part1(&parse("{{{}}}")), 6);
-    assert_eq!(part1(&parse("{{},{}}")), 5);
-    assert_eq!(part1(&parse("{{{},{},{{}}}}")), 16);
-    assert_eq!(part1(&parse("{<a>,<a>,<a>,<a>}")), 1);
-    assert_eq!(part1(&parse("{{<ab>},{<ab>},{<ab>},{<ab>}}")), 9);
-    assert_eq!(part1(&parse("{{<!!>},{<!!>},{<!!>},{<!!>}}")), 9);
-    assert_eq!(part1(&parse("{{<a!>},{<a!>},{<a!>},{<ab>}}")), 3);
-    assert_eq!(part2(&parse("<>")), 0);
-    assert_eq!(part2(&parse("<random characters>")), 17);
-    assert_eq!(part2(&parse("<<<<>")), 3);
-    assert_eq!(part2(&parse("<{!>}>")), 2);
-    assert_eq!(part2(&parse("<!!>")), 0);
-    assert_eq!(part2(&parse("<!!!>>")), 0);
-    assert_eq!(part2(&parse("<{o\"i!a,<{i<a>")), 10);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&parse("{}")), 1);
+        assert_eq!(part1(&parse("{{{}}}")), 6);
+        assert_eq!(part1(&parse("{{},{}}")), 5);
+        assert_eq!(part1(&parse("{{{},{},{{}}}}")), 16);
+        assert_eq!(part1(&parse("{<a>,<a>,<a>,<a>}")), 1);
+        assert_eq!(part1(&parse("{{<ab>},{<ab>},{<ab>},{<ab>}}")), 9);
+        assert_eq!(part1(&parse("{{<!!>},{<!!>},{<!!>},{<!!>}}")), 9);
+        assert_eq!(part1(&parse("{{<a!>},{<a!>},{<a!>},{<ab>}}")), 3);
+        assert_eq!(part2(&parse("<>")), 0);
+        assert_eq!(part2(&parse("<random characters>")), 17);
+        assert_eq!(part2(&parse("<<<<>")), 3);
+        assert_eq!(part2(&parse("<{!>}>")), 2);
+        assert_eq!(part2(&parse("<!!>")), 0);
+        assert_eq!(part2(&parse("<!!!>>")), 0);
+        assert_eq!(part2(&parse("<{o\"i!a,<{i<a>")), 10);
+    }
 }
@@ -1,8 +1,7 @@
 /// Advent of Code 2017, Day 10
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-#[path = "knot.rs"] mod knot;
+#[path = "../knot.rs"] mod knot;
 
 fn part1(size: usize, input: &str) -> i64 {
     knot::part1(size, input)
@@ -12,18 +11,30 @@ fn part2(input: &str) -> String {
     knot::hash_hex(input)
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(256, input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input.trim())}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 10).unwrap();
-
-    // Unit tests on provided examples.
-    assert_eq!(part1(5, "3,4,1,5"), 12);
-    assert_eq!(part2(""), "a2582a3a0e66e6e86e3812dcb672a272");
-    assert_eq!(part2("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
-    assert_eq!(part2("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
-    assert_eq!(part2("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(256, &input));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2017, 10);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(5, "3,4,1,5"), 12);
+        assert_eq!(part2(""), "a2582a3a0e66e6e86e3812dcb672a272");
+        assert_eq!(part2("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+        assert_eq!(part2("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
+        assert_eq!(part2("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 11
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Cardinal axes are U = NE and V = N.
 // https://www.redblobgames.com/grids/hexagons/#distances
 type Hex = (i64, i64);
@@ -20,7 +18,7 @@ fn parse(input: &str) -> Vec<Hex> {
             "sw" => {hex.0 -= 1; hex.1 += 0;},
             _ => {println!("Invalid input: {}", step);},
         }
-        hexes.push(hex.clone());
+        hexes.push(hex);
     }
     return hexes;
 }
@@ -36,20 +34,32 @@ fn part1(input: &str) -> i64 {
 
 fn part2(input: &str) -> i64 {
     let steps = parse(input);
-    return steps.iter().map(|x| distance(x)).max().unwrap();
+    return steps.iter().map(distance).max().unwrap();
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
 }
 
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 11).unwrap();
-
-    // Unit tests on provided examples.
-    assert_eq!(part1("ne,ne,ne"), 3);
-    assert_eq!(part1("ne,ne,sw,sw"), 0);
-    assert_eq!(part1("ne,ne,s,s"), 2);
-    assert_eq!(part1("se,sw,se,sw,sw"), 3);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::main::<Day>(2017, 11);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1("ne,ne,ne"), 3);
+        assert_eq!(part1("ne,ne,sw,sw"), 0);
+        assert_eq!(part1("ne,ne,s,s"), 2);
+        assert_eq!(part1("se,sw,se,sw,sw"), 3);
+    }
 }
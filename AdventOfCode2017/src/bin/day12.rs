@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 12
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -29,8 +28,8 @@ impl Graph {
         let mut visit: HashSet<i64> = HashSet::new();
         visit.insert(from);
         // Breadth first search...
-        while queue.len() > 0 {
-            let node = queue.pop().unwrap();
+        while let Some(node) = queue.pop() {
+            
             for next in self.nodes[&node].iter() {
                 if !visit.contains(next) {
                     queue.push(*next);
@@ -53,7 +52,7 @@ fn part2(input: &str) -> usize {
     let mut pending: HashSet<i64> = graph.nodes.keys().cloned().collect();
     // Count the number of disconnected subsets.
     let mut count: usize = 0;
-    while pending.len() > 0 {
+    while !pending.is_empty() {
         count += 1;
         // Pick an arbitrary root and find all connected nodes.
         let root = pending.iter().next().unwrap();
@@ -64,6 +63,7 @@ fn part2(input: &str) -> usize {
     return count
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 0 <-> 2
 1 <-> 1
@@ -73,14 +73,26 @@ const TEST: &str = "\
 5 <-> 6
 6 <-> 4, 5";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 12).unwrap();
+    aoclib::main::<Day>(2017, 12);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST), 6);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 6);
+    }
 }
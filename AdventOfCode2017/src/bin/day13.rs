@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 13
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 struct Firewall {
@@ -32,7 +31,7 @@ impl Firewall {
 
     fn detected(&self, t0: u64) -> bool {
         for depth in 0..self.max_depth {
-            if let Some(_) = self.patrol(depth, t0 + depth) {
+            if self.patrol(depth, t0 + depth).is_some() {
                 return true;
             }
         }
@@ -63,17 +62,30 @@ fn part2(input: &str) -> u64 {
     return 0;
 }
 
+#[cfg(test)]
 const TEST: &str = "0: 3\n1: 2\n4: 4\n6: 4";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 13).unwrap();
+    aoclib::main::<Day>(2017, 13);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST), 24);
-    assert_eq!(part2(TEST), 10);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 24);
+        assert_eq!(part2(TEST), 10);
+    }
 }
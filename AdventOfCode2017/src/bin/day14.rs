@@ -1,8 +1,7 @@
 /// Advent of Code 2017, Day 14
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-#[path = "knot.rs"] mod knot;
+#[path = "../knot.rs"] mod knot;
 use std::collections::HashSet;
 
 type Tile = (i64, i64);
@@ -35,18 +34,18 @@ fn make_grid(key: &str) -> Grid {
 // Find all tiles reachable from a given starting point.
 fn reachable(grid: &Grid, from: &Tile) -> Grid {
     // Set initial state.
-    let mut queue: Vec<Tile> = vec![from.clone()];
+    let mut queue: Vec<Tile> = vec![*from];
     let mut visit: HashSet<Tile> = HashSet::new();
-    visit.insert(from.clone());
+    visit.insert(*from);
     // Breadth first search...
-    while queue.len() > 0 {
-        let node = queue.pop().unwrap();
+    while let Some(node) = queue.pop() {
+        
         let adj = vec![(node.0-1, node.1),
                        (node.0+1, node.1),
                        (node.0, node.1-1),
                        (node.0, node.1+1)];
         for next in adj.into_iter() {
-            if grid.contains(&next) && visit.insert(next.clone()) {
+            if grid.contains(&next) && visit.insert(next) {
                 queue.push(next);
             }
         }
@@ -65,24 +64,36 @@ fn part2(key: &str) -> usize {
     let mut count = 0usize;
     let mut pending = make_grid(key.trim());
     // Pick a tile at random and remove all connected tiles.
-    while pending.len() > 0 {
+    while !pending.is_empty() {
         count += 1; // Count the next region.
         let root = pending.iter().next().unwrap();
-        let conn = reachable(&pending, &root);
-        for c in conn.iter() {pending.remove(&c);}
+        let conn = reachable(&pending, root);
+        for c in conn.iter() {pending.remove(c);}
     }
     return count;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 14).unwrap();
+    aoclib::main::<Day>(2017, 14);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1("flqrgnkx"), 8108);
-    assert_eq!(part2("flqrgnkx"), 1242);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("flqrgnkx"), 8108);
+        assert_eq!(part2("flqrgnkx"), 1242);
+    }
 }
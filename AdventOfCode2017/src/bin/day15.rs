@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 15
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Scale factors for the two generators.
 const SCALE_A: u64 = 16807;
 const SCALE_B: u64 = 48271;
@@ -62,15 +60,27 @@ fn part2(seed_a: u64, seed_b: u64) -> usize {
     return count
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (u64, u64);
+    fn parse(input: &str) -> (u64, u64) {parse(input)}
+    fn part1(input: &(u64, u64)) -> impl std::fmt::Display {part1(input.0, input.1)}
+    fn part2(input: &(u64, u64)) -> impl std::fmt::Display {part2(input.0, input.1)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = parse(&aocfetch::get_data(2017, 15).unwrap());
+    aoclib::main::<Day>(2017, 15);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(65, 8921), 588);
-    assert_eq!(part2(65, 8921), 309);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.0, input.1));
-    println!("Part 2: {}", part2(input.0, input.1));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(65, 8921), 588);
+        assert_eq!(part2(65, 8921), 309);
+    }
 }
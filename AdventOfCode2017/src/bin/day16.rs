@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 16
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn first_char(input: &str) -> char {
     input.chars().next().unwrap()
 }
@@ -32,7 +30,7 @@ fn parse_moves(input: &str) -> Vec<Move> {
     input.trim().split(',').filter_map(Move::new).collect()
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Eq, Hash, PartialEq)]
 struct Dance {
     labels: Vec<char>,
 }
@@ -75,40 +73,34 @@ impl Dance {
     }
 }
 
-fn dance(size: usize, input: &str, count: usize) -> String{
-    // Parse moves and set initial state.
+fn dance(size: usize, input: &str, count: usize) -> String {
+    // Parse moves, then repeat the dance, skipping ahead once it repeats.
     let moves = parse_moves(input);
-    let init  = Dance::new(size);
-    let mut state = init.clone();
-    let mut iter  = 0usize;
-    // Iterate until we reach the end or loop back on the initial state.
-    while iter < count {
-        // Execute one iteration of the dance.
-        for m in moves.iter() { state = state.apply(m); }
-        iter += 1;
-        // Stop early if we reach the initial state.
-        if state == init { break; }
-    }
-    // If we stopped early, iter is the repeat interval. Skip another N loops.
-    let skip = (count - iter) / iter;
-    iter += iter * skip;
-    // Iterate until we reach the end.
-    while iter < count {
-        for m in moves.iter() { state = state.apply(m); }
-        iter += 1;
-    }
-    return state.to_string();
+    let step = |state: &Dance| moves.iter().fold(state.clone(), |s, m| s.apply(m));
+    return aoclib::cycle::nth_state(Dance::new(size), step, count).to_string();
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {dance(16, input, 1)}
+    fn part2(input: &String) -> impl std::fmt::Display {dance(16, input, 1000000000)}
 }
 
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 16).unwrap();
+    aoclib::main::<Day>(2017, 16);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(dance(5, "s1,x3/4,pe/b", 1), "baedc");
-    assert_eq!(dance(5, "s1,x3/4,pe/b", 2), "ceadb");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", dance(16, &input, 1));
-    println!("Part 2: {}", dance(16, &input, 1000000000));
+    #[test]
+    fn examples() {
+        assert_eq!(dance(5, "s1,x3/4,pe/b", 1), "baedc");
+        assert_eq!(dance(5, "s1,x3/4,pe/b", 2), "ceadb");
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 17
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // One node in a singly-linked list.
 struct Node {
     value: u64,
@@ -63,22 +61,33 @@ fn part2(step: usize, size: u64) -> u64 {
     return value;
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = usize;
+    fn parse(input: &str) -> usize {input.trim().parse().unwrap()}
+    fn part1(input: &usize) -> impl std::fmt::Display {part1(*input, 2017)}
+    fn part2(input: &usize) -> impl std::fmt::Display {part2(*input, 50000000)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 17).unwrap();
-    let step: usize = input.trim().parse().unwrap();
+    aoclib::main::<Day>(2017, 17);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(3, 9), 5);
-    assert_eq!(part1(3, 2017), 638);
-    assert_eq!(part2(3, 1), 1);
-    assert_eq!(part2(3, 2), 2);
-    assert_eq!(part2(3, 3), 2);
-    assert_eq!(part2(3, 4), 2);
-    assert_eq!(part2(3, 5), 5);
-    assert_eq!(part2(3, 9), 9);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(step, 2017));
-    println!("Part 2: {}", part2(step, 50000000));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(3, 9), 5);
+        assert_eq!(part1(3, 2017), 638);
+        assert_eq!(part2(3, 1), 1);
+        assert_eq!(part2(3, 2), 2);
+        assert_eq!(part2(3, 3), 2);
+        assert_eq!(part2(3, 4), 2);
+        assert_eq!(part2(3, 5), 5);
+        assert_eq!(part2(3, 9), 9);
+    }
 }
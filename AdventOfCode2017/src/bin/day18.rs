@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 18
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::VecDeque;
 
 enum Opcode {
@@ -119,11 +118,11 @@ impl Program {
                 self.line += 1;
                 (true, false)},
             Opcode::ModI(x, y) => {
-                self.regs[x] = self.regs[x] % y;
+                self.regs[x] %= y;
                 self.line += 1;
                 (true, false)},
             Opcode::ModR(x, y) => {
-                self.regs[x] = self.regs[x] % self.regs[y];
+                self.regs[x] %= self.regs[y];
                 self.line += 1;
                 (true, false)},
             Opcode::JgzI(x, y) => {
@@ -159,6 +158,7 @@ fn part2(input: &str) -> usize {
     return prog1.sent
 }
 
+#[cfg(test)]
 const TEST1: &str = "\
 set a 1
 add a 2
@@ -171,6 +171,7 @@ jgz a -1
 set a 1
 jgz a -2";
 
+#[cfg(test)]
 const TEST2: &str = "\
 snd 1
 snd 2
@@ -180,15 +181,27 @@ rcv b
 rcv c
 rcv d";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 18).unwrap();
+    aoclib::main::<Day>(2017, 18);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST1), 4);
-    assert_eq!(part2(TEST2), 3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST1), 4);
+        assert_eq!(part2(TEST2), 3);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 19
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type Rc = (i64, i64);
@@ -64,6 +63,7 @@ fn solve(input: &str) -> (String, usize) {
     return (path, plen);
 }
 
+#[cfg(test)]
 const TEST: &str = concat![
     "     |          \n",
     "     |  +--+    \n",
@@ -72,16 +72,27 @@ const TEST: &str = concat![
     "     |  |  |  D \n",
     "     +B-+  +--+ \n"];
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (String, usize);
+    fn parse(input: &str) -> (String, usize) {solve(input)}
+    fn part1(input: &(String, usize)) -> impl std::fmt::Display {input.0.clone()}
+    fn part2(input: &(String, usize)) -> impl std::fmt::Display {input.1}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 19).unwrap();
+    aoclib::main::<Day>(2017, 19);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(solve(TEST).0, "ABCDEF");
-    assert_eq!(solve(TEST).1, 38);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    let (part1, part2) = solve(&input);
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    #[test]
+    fn examples() {
+        assert_eq!(solve(TEST).0, "ABCDEF");
+        assert_eq!(solve(TEST).1, 38);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 20
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
@@ -85,25 +84,39 @@ fn part2(input: &str) -> usize {
     return particles.len()
 }
 
+#[cfg(test)]
 const TEST1: &str = "\
 p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
 p=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>";
 
+#[cfg(test)]
 const TEST2: &str = "\
 p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
 p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
 p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
 p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 20).unwrap();
+    aoclib::main::<Day>(2017, 20);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST1), 0);
-    assert_eq!(part2(TEST2), 1);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST1), 0);
+        assert_eq!(part2(TEST2), 1);
+    }
 }
@@ -1,84 +1,21 @@
 /// Advent of Code 2017, Day 21
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::bits::BitGrid;
 use std::collections::HashMap;
 
-// Largest cell pattern of interest is 4x4 -> fits in u16.
-type Mask = u16;
-
-// Count the number of '1' bits in a given mask.
-fn count_bits(mask: &Mask) -> usize {
-    (0..16).map(|b| (mask>>b & 1) as usize).sum()
-}
+// Each cell pattern is keyed by its bit-mask in reading order.
+// (Largest cell pattern of interest is 4x4 -> 16 bits.)
+type Mask = u64;
 
 // A single 2x2, 3x3, or 4x4 "cell" for table lookup.
-// Bit mask reading order (0/1/2/3 in first row, 4/5/6/7 in second...)
-// Native representation is a bit-mask in reading order (
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct Cell<const N: usize> {
-    mask: Mask,
-}
-
-impl<const N: usize> Cell<N> {
-    fn from_str(input: &str) -> Cell<N> {
-        assert_eq!(input.len(), N*N + N - 1);
-        let mut mask: Mask = 0;
-        for (r,row) in input.split('/').enumerate() {
-            for (c,col) in row.chars().enumerate() {
-                if col == '#' { mask |= Self::rc(r, c); }
-            }
-        }
-        return Cell {mask};
-    }
-
-    fn from_bits(input: &[u16]) -> Cell<N> {
-        assert_eq!(input.len(), N*N);
-        let mut mask: Mask = 0;
-        for (b, v) in input.iter().enumerate() {
-            if v > &0 { mask |= 1 << b; }
-        }
-        return Cell {mask};
-    }
-
-    fn bit(&self, r: usize, c: usize) -> Mask {
-        self.mask & Self::rc(r, c)
-    }
-
-    fn rc(r: usize, c: usize) -> Mask {
-        assert!(r < N && c < N);
-        (1 as Mask) << (N*r + c)
-    }
+type Cell<const N: usize> = BitGrid<N>;
 
-    fn reshape(&self, newrc: impl Fn(usize,usize) -> Mask) -> Cell<N> {
-        let mut mask: Mask = 0;
-        for r in 0..N {
-            for c in 0..N {
-                if self.bit(r,c) > 0 { mask |= newrc(r,c); }
-            }
-        }
-        return Cell {mask};
-    }
-
-    fn hflip(&self) -> Cell<N> {
-        self.reshape(|r,c: usize| Self::rc(r, N-1-c))
-    }
-
-    fn transpose(&self) -> Cell<N> {
-        self.reshape(|r,c: usize| Self::rc(c, r))
-    }
-
-    fn symmetry(&self) -> [Cell<N>;8] {
-        let a = self.hflip();
-        let b = a.transpose();
-        let c = b.hflip();
-        let d = c.transpose();
-        let e = d.hflip();
-        let f = e.transpose();
-        let g = f.hflip();
-        assert_eq!(self, &g.transpose());
-        return [self.clone(), a, b, c, d, e, f, g];
-    }
+// Parse a cell pattern, e.g., ".#./..#/###".
+fn cell<const N: usize>(input: &str) -> Cell<N> {
+    assert_eq!(input.len(), N*N + N - 1);
+    let rows: Vec<&[u8]> = input.split('/').map(str::as_bytes).collect();
+    Cell::from_fn(|r, c| rows[r][c] == b'#')
 }
 
 // Rule-based fractal generation operating on counts of each cell type.
@@ -108,79 +45,44 @@ impl Rules {
             let lr: Vec<&str> = line.split(" => ").collect();
             assert_eq!(lr.len(), 2);
             if lr[0].len() == 5 {
-                let l: Cell<2> = Cell::from_str(lr[0]);
-                let r: Cell<3> = Cell::from_str(lr[1]);
-                for s in l.symmetry() { table2.insert(s, r); }
+                let l: Cell<2> = cell(lr[0]);
+                let r: Cell<3> = cell(lr[1]);
+                for s in l.symmetries() { table2.insert(s, r); }
             } else {
-                let l: Cell<3> = Cell::from_str(lr[0]);
-                let r: Cell<4> = Cell::from_str(lr[1]);
-                for s in l.symmetry() { table3.insert(s, r); }
+                let l: Cell<3> = cell(lr[0]);
+                let r: Cell<4> = cell(lr[1]);
+                for s in l.symmetries() { table3.insert(s, r); }
             }
         }
 
         // Rules 1A (3->4) and 1C (2->3) are a direct 1:1 lookup.
         for (before, after) in table2.iter() {
-            let tmp = MaskCount::from([(after.mask, 1)]);
-            rules.rule_1c.insert(before.mask, tmp);
+            let tmp = MaskCount::from([(after.bits(), 1)]);
+            rules.rule_1c.insert(before.bits(), tmp);
         }
 
         for (before, after) in table3.iter() {
-            let tmp = MaskCount::from([(after.mask, 1)]);
-            rules.rule_1a.insert(before.mask, tmp);
+            let tmp = MaskCount::from([(after.bits(), 1)]);
+            rules.rule_1a.insert(before.bits(), tmp);
         }
 
-        // Rule 1C (4->2) requires additional parsing.
+        // Rule 1B (4->2) requires additional parsing.
         for in4 in table3.values() {
-            // Table lookup for each input quadrant.
-            let nw: Cell<3> = table2[&Cell::from_bits(&[
-                in4.bit(0,0), in4.bit(0,1),
-                in4.bit(1,0), in4.bit(1,1)])];
-            let ne: Cell<3> = table2[&Cell::from_bits(&[
-                in4.bit(0,2), in4.bit(0,3),
-                in4.bit(1,2), in4.bit(1,3)])];
-            let sw: Cell<3> = table2[&Cell::from_bits(&[
-                in4.bit(2,0), in4.bit(2,1),
-                in4.bit(3,0), in4.bit(3,1)])];
-            let se: Cell<3> = table2[&Cell::from_bits(&[
-                in4.bit(2,2), in4.bit(2,3),
-                in4.bit(3,2), in4.bit(3,3)])];
+            // Table lookup for each 2x2 input quadrant (NW, NE, SW, SE).
+            let quads: Vec<Cell<3>> = (0..4).map(|q| {
+                let (qr, qc) = (2 * (q / 2), 2 * (q % 2));
+                table2[&Cell::from_fn(|r, c| in4.get(qr + r, qc + c))]
+            }).collect();
             // Recombine 3x3 quadrants into nine 2x2 blocks.
-            let out_quads: [Cell<2>;9] = [
-                Cell::from_bits(&[  // Northwest
-                    nw.bit(0,0), nw.bit(0,1),
-                    nw.bit(1,0), nw.bit(1,1)]),
-                Cell::from_bits(&[  // North
-                    nw.bit(0,2), ne.bit(0,0),
-                    nw.bit(1,2), ne.bit(1,0)]),
-                Cell::from_bits(&[  // Northeast
-                    ne.bit(0,1), ne.bit(0,2),
-                    ne.bit(1,1), ne.bit(1,2)]),
-                Cell::from_bits(&[  // West
-                    nw.bit(2,0), nw.bit(2,1),
-                    sw.bit(0,0), sw.bit(0,1)]),
-                Cell::from_bits(&[  // Center
-                    nw.bit(2,2), ne.bit(2,0),
-                    sw.bit(0,2), se.bit(0,0)]),
-                Cell::from_bits(&[  // East
-                    ne.bit(2,1), ne.bit(2,2),
-                    se.bit(0,1), se.bit(0,2)]),
-                Cell::from_bits(&[  // Southwest
-                    sw.bit(1,0), sw.bit(1,1),
-                    sw.bit(2,0), sw.bit(2,1)]),
-                Cell::from_bits(&[  // South
-                    sw.bit(1,2), se.bit(1,0),
-                    sw.bit(2,2), se.bit(2,0)]),
-                Cell::from_bits(&[  // Southeast
-                    se.bit(1,1), se.bit(1,2),
-                    se.bit(2,1), se.bit(2,2)]),
-            ];
+            let out6: Cell<6> = Cell::from_fn(|r, c| quads[2*(r/3) + c/3].get(r%3, c%3));
             // Insert each one into the output table.
             let mut result = MaskCount::new();
-            for cell in out_quads {
-                let old_count = result.get(&cell.mask).unwrap_or(&0);
-                result.insert(cell.mask, old_count + 1);
+            for b in 0..9 {
+                let (br, bc) = (2 * (b / 3), 2 * (b % 3));
+                let cell: Cell<2> = Cell::from_fn(|r, c| out6.get(br + r, bc + c));
+                *result.entry(cell.bits()).or_insert(0) += 1;
             }
-            rules.rule_1b.insert(in4.mask, result);
+            rules.rule_1b.insert(in4.bits(), result);
         }
 
         // Rule-3 chains together all three of the rules.
@@ -225,15 +127,15 @@ struct Fractal {
 impl Fractal {
     fn new() -> Fractal {
         // Initial state is a specific 3x3 "glider" pattern.
-        let glider: Cell<3> = Cell::from_str(".#./..#/###");
-        let count = HashMap::from([(glider.mask, 1usize)]);
+        let glider: Cell<3> = cell(".#./..#/###");
+        let count = HashMap::from([(glider.bits(), 1usize)]);
         Fractal { step: 0, count: count }
     }
 
     fn count(&self) -> usize {
         let mut total = 0usize;
         for (mask, count) in self.count.iter() {
-            total += count * count_bits(mask);
+            total += count * mask.count_ones() as usize;
         }
         return total;
     }
@@ -277,20 +179,33 @@ fn part2(input: &str) -> usize {
     run(input, 18)
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 ../.# => ##./#../...
 .#./..#/### => #..#/..../..../#..#";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 21).unwrap();
+    aoclib::main::<Day>(2017, 21);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(run(TEST, 0), 5);
-    assert_eq!(run(TEST, 1), 4);
-    assert_eq!(run(TEST, 2), 12);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(run(TEST, 0), 5);
+        assert_eq!(run(TEST, 1), 4);
+        assert_eq!(run(TEST, 2), 12);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 22
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type Rc = (i64, i64);
@@ -80,22 +79,35 @@ fn part2(input: &str, steps: usize) -> usize {
     return grid.count;
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 ..#
 #..
 ...";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input, 10000)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input, 10000000)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 22).unwrap();
+    aoclib::main::<Day>(2017, 22);
+}
 
-    // Unit tests on provided examples.
-    assert_eq!(part1(TEST, 70), 41);
-    assert_eq!(part1(TEST, 10000), 5587);
-    assert_eq!(part2(TEST, 100), 26);
-    assert_eq!(part2(TEST, 10000000), 2511944);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input, 10000));
-    println!("Part 2: {}", part2(&input, 10000000));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST, 70), 41);
+        assert_eq!(part1(TEST, 10000), 5587);
+        assert_eq!(part2(TEST, 100), 26);
+        assert_eq!(part2(TEST, 10000000), 2511944);
+    }
 }
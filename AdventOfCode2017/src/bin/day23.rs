@@ -1,8 +1,6 @@
 /// Advent of Code 2017, Day 23
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 enum Opcode {
     SetI(usize, i64),
     SetR(usize, usize),
@@ -112,17 +110,29 @@ fn part2(input: &str) -> i64 {
     return prog.regs[7];
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 23).unwrap();
+    aoclib::main::<Day>(2017, 23);
+}
 
-    // Unit tests for the is_prime function.
-    assert_eq!(is_prime(17), true);
-    assert_eq!(is_prime(18), false);
-    assert_eq!(is_prime(19), true);
-    assert_eq!(is_prime(34), false);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(is_prime(17), true);
+        assert_eq!(is_prime(18), false);
+        assert_eq!(is_prime(19), true);
+        assert_eq!(is_prime(34), false);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2017, Day 24
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Segment = (u64, u64);      // Type for each end of segment
@@ -82,6 +81,7 @@ fn stronk(input: &Segments) -> (u64, u64) {
     return (max_str, max_long.1);
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 0/2
 2/2
@@ -92,15 +92,26 @@ const TEST: &str = "\
 10/1
 9/10";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (u64, u64);
+    fn parse(input: &str) -> (u64, u64) {stronk(&parse(input))}
+    fn part1(input: &(u64, u64)) -> impl std::fmt::Display {input.0}
+    fn part2(input: &(u64, u64)) -> impl std::fmt::Display {input.1}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 24).unwrap();
+    aoclib::main::<Day>(2017, 24);
+}
 
-    // Unit tests based on the provided examples.
-    assert_eq!(stronk(&parse(TEST)), (31, 19));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    let (part1, part2) = stronk(&parse(&input));
-    println!("Part 1: {}", &part1);
-    println!("Part 2: {}", &part2);
+    #[test]
+    fn examples() {
+        assert_eq!(stronk(&parse(TEST)), (31, 19));
+    }
 }
@@ -1,12 +1,28 @@
 /// Advent of Code 2017, Day 25
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::parse::{self, ParseError};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
 type Tape = HashSet<i64>;
 
+// Each line has one field of interest.  Return it as a substring of the
+// input, so that errors can report their location.
+fn scan<'a>(input: &str, line: &'a str, pattern: &str) -> parse::Result<&'a str> {
+    let fields = parse::scan_fields(line.trim(), pattern)
+        .map_err(|e| e.within(input, line.trim()))?;
+    return Ok(fields[0]);
+}
+
+// Check that a block of the input has the expected number of lines.
+fn split<'a>(input: &str, block: &'a str, count: usize) -> parse::Result<Vec<&'a str>> {
+    let lines: Vec<&str> = block.lines().collect();
+    if lines.len() == count {return Ok(lines);}
+    let msg = format!("Expected {} lines, found {}", count, lines.len());
+    return Err(ParseError::new(input, block, msg));
+}
+
 struct Action {
     write: bool,    // Write 1 or 0?
     right: bool,    // Move left or right?
@@ -14,19 +30,24 @@ struct Action {
 }
 
 impl Action {
-    fn new(input: &[&str]) -> Action
+    fn new(input: &str, lines: &[&str]) -> parse::Result<Action>
     {
         // Parse the parameter we need from each line.
-        assert_eq!(input.len(), 4);
-        let write = input[1].chars().rev().nth(1).unwrap() == '1';
-        let right = input[2].chars().rev().nth(2).unwrap() == 'h';
-        let state = input[3].chars().rev().nth(1).unwrap();
-        Action { write, right, state }
+        let write = scan(input, lines[1], "- Write the value {}.")?;
+        let write = parse::field::<u8>(input, write)? == 1;
+        let right = match scan(input, lines[2], "- Move one slot to the {}.")? {
+            "left"  => false,
+            "right" => true,
+            s => return Err(ParseError::new(input, s, "Expected left or right")),
+        };
+        let state = scan(input, lines[3], "- Continue with state {}.")?;
+        let state = parse::field(input, state)?;
+        Ok(Action { write, right, state })
     }
 
     fn apply(&self, posn: &mut i64, tape: &mut Tape) -> char {
         if self.write {     // Write a '1' or a '0'?
-            tape.insert(posn.clone());
+            tape.insert(*posn);
         } else {
             tape.remove(posn);
         }
@@ -42,14 +63,14 @@ struct State {
 }
 
 impl State {
-    fn new(input: &[&str]) -> State
+    fn new(input: &str, block: &str) -> parse::Result<State>
     {
         // Read the label, then both actions.
-        assert_eq!(input.len(), 9);
-        let label = input[0].chars().rev().nth(1).unwrap();
-        let action0 = Action::new(&input[1..5]);
-        let action1 = Action::new(&input[5..9]);
-        State { label, action0, action1 }
+        let lines = split(input, block, 9)?;
+        let label = parse::field(input, scan(input, lines[0], "In state {}:")?)?;
+        let action0 = Action::new(input, &lines[1..5])?;
+        let action1 = Action::new(input, &lines[5..9])?;
+        Ok(State { label, action0, action1 })
     }
 
     fn apply(&self, posn: &mut i64, tape: &mut Tape) -> char {
@@ -68,19 +89,21 @@ struct Turing {
 }
 
 impl Turing {
-    fn new(input: &str) -> Turing {
-        // Split by lines.  Header is 2 lines, then 10 per state.
-        let lines: Vec<&str> = input.lines().collect();
-        let num_states = (lines.len() - 2) / 10;
-        let init: char = lines[0].chars().rev().nth(1).unwrap();
-        let steps: usize = lines[1].split(' ')
-            .nth(5).unwrap()        // Get the Nth word
-            .parse().unwrap();      // Parse as an integer
-        let states = (0..num_states)
-            .map(|n| State::new(&lines[10*n+3 .. 10*n+12]))
-            .map(|s| (s.label, s))  // Key + Value
-            .collect();             // Construct dictionary
-        Turing { init, steps, states }
+    fn new(input: &str) -> parse::Result<Turing> {
+        // Split into blocks.  Header is 2 lines, then 9 per state.
+        let blocks = parse::blocks(input);
+        let header = split(input, blocks.first().unwrap_or(&input), 2)?;
+        let init = scan(input, header[0], "Begin in state {}.")?;
+        let steps = scan(input, header[1], "Perform a diagnostic checksum after {} steps.")?;
+        let states = blocks[1..].iter()
+            .map(|block| State::new(input, block))
+            .map(|s| s.map(|s| (s.label, s)))   // Key + Value
+            .collect::<parse::Result<_>>()?;    // Construct dictionary
+        Ok(Turing {
+            init: parse::field(input, init)?,
+            steps: parse::field(input, steps)?,
+            states,
+        })
     }
 
     fn run(&self) -> Tape {
@@ -95,10 +118,11 @@ impl Turing {
 }
 
 fn part1(input: &str) -> usize {
-    let program = Turing::new(input);
+    let program = Turing::new(input).unwrap_or_else(|e| panic!("{}", e));
     program.run().len() // Count the number of '1's
 }
 
+#[cfg(test)]
 const TEST: &str = "\
 Begin in state A.
 Perform a diagnostic checksum after 6 steps.
@@ -123,13 +147,25 @@ In state B:
     - Move one slot to the right.
     - Continue with state A.";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+}
+
 fn main() {
-    // Fetch problem input from server.
-    let input = aocfetch::get_data(2017, 25).unwrap();
+    aoclib::main::<Day>(2017, 25);
+}
 
-    // Unit tests based on the provided example.
-    assert_eq!(part1(TEST), 3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(TEST), 3);
+    }
 }
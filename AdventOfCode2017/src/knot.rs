@@ -68,7 +68,7 @@ impl Knot {
 // Convert psuedo-ASCII sequence and add fixed suffix.
 fn convert(input: &str) -> Vec<u8> {
     let mut seq: Vec<u8> = input.chars().map(|x| x as u8).collect();
-    for n in vec![17, 31, 73, 47, 23] {seq.push(n);}
+    for n in [17, 31, 73, 47, 23] {seq.push(n);}
     return seq
 }
 
@@ -1,8 +1,7 @@
 /// Commonly-used library functions for my Advent of Code solutions
 /// Copyright 2021 by Alex Utter
 
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::fs;
 use std::str::FromStr;
 
 /// Count the number of true items in a list/vector/etc.
@@ -15,43 +14,41 @@ pub fn count_true(iter: impl Iterator<Item=bool>) -> usize {
     count
 }
 
-/// Read a file with one integer per line.
+/// Read an entire file as a single string.
 #[allow(dead_code)]
-pub fn read_integers(filename: &str) -> Vec<i64>
+pub fn read_file(filename: &str) -> String
 {
-    let file = File::open(filename).unwrap();
-    let lines = io::BufReader::new(file).lines();
-    lines.filter_map(io::Result::ok)
+    fs::read_to_string(filename).unwrap()
+}
+
+/// Parse a string with one integer per line.
+#[allow(dead_code)]
+pub fn split_integers(input: &str) -> Vec<i64>
+{
+    input.lines()
          .filter_map(|line| line.trim().parse().ok())
          .collect()
 }
 
-/// Read a file with one String per line.
+/// Split a string into lines, one String per line.
 #[allow(dead_code)]
-pub fn read_strings(filename: &str) -> Vec<String>
+pub fn split_strings(input: &str) -> Vec<String>
 {
-    let file = File::open(filename).unwrap();
-    let lines = io::BufReader::new(file).lines();
-    lines.filter_map(io::Result::ok)
-         .collect()
+    input.lines().map(String::from).collect()
 }
 
-/// Read a file where blank lines delimit line-groups.
+/// Read a file with one integer per line.
 #[allow(dead_code)]
-pub fn group_strings(lines:&Vec<String>) -> Vec<Vec<String>>
+pub fn read_integers(filename: &str) -> Vec<i64>
 {
-    let mut list = vec![];
-    let mut next = Vec::<String>::new();
-    for line in lines {                     // Read one line at a time.
-        if !line.is_empty() {               // Content line?
-            next.push(line.clone());        // Add next line to group
-        } else if !next.is_empty() {        // Empty line / delimiter
-            list.push(next);                // Add group to output
-            next = Vec::<String>::new();    // New empty group
-        }
-    }
-    list.push(next);                        // Add final group
-    return list
+    split_integers(&read_file(filename))
+}
+
+/// Read a file with one String per line.
+#[allow(dead_code)]
+pub fn read_strings(filename: &str) -> Vec<String>
+{
+    split_strings(&read_file(filename))
 }
 
 /// Cumulative sum of a vector of integers.
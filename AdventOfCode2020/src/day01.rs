@@ -3,30 +3,24 @@
 
 #[path = "common.rs"] mod common;
 
-pub fn solve() {
-    // Simple example from problem statement.
-    let test_i = [1721, 979, 366, 299, 675, 1456].to_vec();
-    let test_o = find_pair(2020, &test_i);
-    print_product2("Test", test_o);
-
-    // Part 1 and Part 2 solutions:
-    let input = common::read_integers("input/input01.txt");
-
-    let soln1 = find_pair(2020, &input);
-    print_product2("Part1", soln1);
-
-    let soln2 = find_triplet(2020, &input);
-    print_product3("Part1", soln2);
-}
-
-fn print_product2(lbl: &str, xy: (i64, i64)) {
-    println!("{}: {} * {} = {}",
-        lbl, xy.0, xy.1, xy.0 * xy.1)
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<i64>;
+    fn parse(input: &str) -> Vec<i64> {common::split_integers(input)}
+    fn part1(input: &Vec<i64>) -> impl std::fmt::Display {
+        let xy = find_pair(2020, input);
+        xy.0 * xy.1
+    }
+    fn part2(input: &Vec<i64>) -> impl std::fmt::Display {
+        let xyz = find_triplet(2020, input);
+        xyz.0 * xyz.1 * xyz.2
+    }
 }
 
-fn print_product3(lbl: &str, xyz: (i64, i64, i64)) {
-    println!("{}: {} * {} * {} = {}",
-        lbl, xyz.0, xyz.1, xyz.2, xyz.0 * xyz.1 * xyz.2)
+pub fn solve() {
+    aoclib::print::<Day>(&common::read_file("input/input01.txt"));
 }
 
 // Find a pair of numbers that add up to "target".
@@ -54,3 +48,16 @@ fn find_triplet(target:i64, list:&Vec<i64>) -> (i64, i64, i64) {
     }
     return (0,0,0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Simple example from problem statement.
+        let test_i = [1721, 979, 366, 299, 675, 1456].to_vec();
+        assert_eq!(find_pair(2020, &test_i), (1721, 299));
+        assert_eq!(find_triplet(2020, &test_i), (979, 366, 675));
+    }
+}
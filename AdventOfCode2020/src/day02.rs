@@ -3,37 +3,29 @@
 
 #[path = "common.rs"] mod common;
 
-pub fn solve() {
-    // Simple example from problem statement.
-    let test_i = vec![
-        String::from("1-3 a: abcde"),
-        String::from("1-3 b: cdefg"),
-        String::from("2-9 c: ccccccccc"),
-    ];
-    let test1 = count_valid_passwords1(&test_i);
-    println!("Test 1: {} valid passwords.", test1);
+/// Solution for the unified runner.
+pub struct Day;
 
-    // Part 1: Check each password from input file.
-    let input = common::read_strings("input/input02.txt");
-    let part1 = count_valid_passwords1(&input);
-    println!("Part 1: {} valid passwords.", part1);
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {count_valid_passwords1(input)}
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {count_valid_passwords2(input)}
+}
 
-    // Part 2: Same with the new rules.
-    let test2 = count_valid_passwords2(&test_i);
-    println!("Test 2: {} valid passwords.", test2);
-    let part2 = count_valid_passwords2(&input);
-    println!("Part 2: {} valid passwords.", part2);
+pub fn solve() {
+    aoclib::print::<Day>(&common::read_file("input/input02.txt"));
 }
 
 /// Count valid passwords using Part-1 rule
 fn count_valid_passwords1(list: &Vec<String>) -> usize {
-    let valid = list.iter().map(|x| password_is_valid1(x));
+    let valid = list.iter().map(password_is_valid1);
     common::count_true(valid)
 }
 
 /// Count valid passwords using Part-2 rule
 fn count_valid_passwords2(list: &Vec<String>) -> usize {
-    let valid = list.iter().map(|x| password_is_valid2(x));
+    let valid = list.iter().map(password_is_valid2);
     common::count_true(valid)
 }
 
@@ -50,8 +42,8 @@ fn password_is_valid1(line: &String) -> bool {
 /// Test a single Part-2 password rule
 fn password_is_valid2(line: &String) -> bool {
     if let Some(rule) = rule_parse(line) {
-        let char1 = rule.3.chars().nth((rule.0-1) as usize).unwrap();
-        let char2 = rule.3.chars().nth((rule.1-1) as usize).unwrap();
+        let char1 = rule.3.chars().nth(rule.0-1).unwrap();
+        let char2 = rule.3.chars().nth(rule.1-1).unwrap();
         return (char1 == rule.2 && char2 != rule.2)
             || (char1 != rule.2 && char2 == rule.2)
     } else {
@@ -61,13 +53,13 @@ fn password_is_valid2(line: &String) -> bool {
 
 /// Parser for password-rule strings
 struct Rule<'a>(usize, usize, char, &'a str);
-fn rule_parse(line: &String) -> Option<Rule> {
+fn rule_parse(line: &String) -> Option<Rule<'_>> {
     // Typical line: "1-3 b: cdefg"
     // Tokenized:     0 1 2  44444
     let vec: Vec<&str> = line.split(&[' ','-',':'][..]).collect();
     if vec.len() < 5 {
         return None     // Invalid input string (not enough parts)
-    } else if let Some(cref) = vec[2].chars().nth(0) {
+    } else if let Some(cref) = vec[2].chars().next() {
         let cmin:usize = vec[0].parse().unwrap();
         let cmax:usize = vec[1].parse().unwrap();
         return Some(Rule(cmin, cmax, cref, vec[4]))
@@ -80,3 +72,22 @@ fn rule_parse(line: &String) -> Option<Rule> {
 fn count_chars(r:char, s:&str) -> usize {
     common::count_true(s.chars().map(|c| c==r))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Simple example from problem statement.
+        let test_i = vec![
+            String::from("1-3 a: abcde"),
+            String::from("1-3 b: cdefg"),
+            String::from("2-9 c: ccccccccc"),
+        ];
+        assert_eq!(count_valid_passwords1(&test_i), 2);
+
+        // Part 2: Same with the new rules.
+        assert_eq!(count_valid_passwords2(&test_i), 1);
+    }
+}
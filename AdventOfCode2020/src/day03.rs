@@ -4,7 +4,7 @@
 use std::convert::TryFrom;
 #[path = "common.rs"] mod common;
 
-struct TreeMap {
+pub struct TreeMap {
     cols: usize,
     rows: usize,
     tree: Vec<bool>,
@@ -47,50 +47,59 @@ impl TreeMap {
     }
 }
 
-fn solve_part2(lbl: &str, tm: TreeMap) {
-    let a = tm.count_trees(1, 1);
-    let b = tm.count_trees(3, 1);
-    let c = tm.count_trees(5, 1);
-    let d = tm.count_trees(7, 1);
-    let e = tm.count_trees(1, 2);
-    println!("{}: {} * {} * {} * {} * {} = {}",
-        lbl, a, b, c, d, e, a*b*c*d*e);
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = TreeMap;
+    fn parse(input: &str) -> TreeMap {
+        TreeMap::try_from(&common::split_strings(input)).unwrap()
+    }
+    fn part1(input: &TreeMap) -> impl std::fmt::Display {
+        // Number of trees at slope = 1 down, 3 right.
+        input.count_trees(3, 1)
+    }
+    fn part2(input: &TreeMap) -> impl std::fmt::Display {
+        // Product of the various slopes.
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter()
+            .map(|(dc, dr)| input.count_trees(*dc, *dr))
+            .product::<usize>()
+    }
 }
 
 pub fn solve() {
-    // Define the test map from the problem statement.
-    let test_str = vec![
-        String::from("..##......."),
-        String::from("#...#...#.."),
-        String::from(".#....#..#."),
-        String::from("..#.#...#.#"),
-        String::from(".#...##..#."),
-        String::from("..#.##....."),
-        String::from(".#.#.#....#"),
-        String::from(".#........#"),
-        String::from("#.##...#..."),
-        String::from("#...##....#"),
-        String::from(".#..#...#.#"),
-    ];
+    aoclib::print::<Day>(&common::read_file("input/input03.txt"));
+}
 
-    // Parse the example map:
-    if let Ok(test_map) = TreeMap::try_from(&test_str) {
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Define the test map from the problem statement.
+        let test_str = vec![
+            String::from("..##......."),
+            String::from("#...#...#.."),
+            String::from(".#....#..#."),
+            String::from("..#.#...#.#"),
+            String::from(".#...##..#."),
+            String::from("..#.##....."),
+            String::from(".#.#.#....#"),
+            String::from(".#........#"),
+            String::from("#.##...#..."),
+            String::from("#...##....#"),
+            String::from(".#..#...#.#"),
+        ];
+
+        // Parse the example map:
+        let test_map = TreeMap::try_from(&test_str).unwrap();
         // Count trees at slope = 3 right, 1 down.
-        println!("Test 1: {}", test_map.count_trees(1,3));
+        assert_eq!(test_map.count_trees(3, 1), 7);
         // Count trees at each other slope.
-        solve_part2("Test 2", test_map);
-    } else {
-        println!("Test parse error.");
-    }
-
-    // Now read the full-size input...
-    let input_str = common::read_strings("input/input03.txt");
-    if let Ok(input_map) = TreeMap::try_from(&input_str) {
-        // Part 1: Number of trees at slope = 1 down, 3 right.
-        println!("Part 1: {}", input_map.count_trees(3, 1));
-        // Part 2: Product of the various slopes.
-        solve_part2("Part 2", input_map);
-    } else {
-        println!("Input parse error.");
+        assert_eq!(test_map.count_trees(1, 1), 2);
+        assert_eq!(test_map.count_trees(5, 1), 3);
+        assert_eq!(test_map.count_trees(7, 1), 4);
+        assert_eq!(test_map.count_trees(1, 2), 2);
     }
 }
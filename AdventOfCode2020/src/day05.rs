@@ -4,7 +4,7 @@
 use std::convert::TryFrom;
 #[path = "common.rs"] mod common;
 
-struct Seat(u8, u8);
+pub struct Seat(u8, u8);
 
 impl Seat {
     fn char2idx(x:char, val:u8) -> u8 {
@@ -36,37 +36,58 @@ impl TryFrom<&String> for Seat {
     }
 }
 
-pub fn solve() {
-    // Test cases from the problem statement.
-    let test1a = Seat::try_from(&String::from("BFFFBBFRRR")).unwrap();
-    let test1b = Seat::try_from(&String::from("FFFBBBFRRR")).unwrap();
-    let test1c = Seat::try_from(&String::from("BBFFBBFRLL")).unwrap();
-    assert_eq!(test1a.id(), 567);
-    assert_eq!(test1b.id(), 119);
-    assert_eq!(test1c.id(), 820);
-
-    // Load input and keep valid passes.
-    let input:Vec<String> = common::read_strings("input/input05.txt");
-    let passes:Vec<Seat> = input.iter()
-        .map(|x| Seat::try_from(x))
+/// Load input and keep valid passes.
+fn read_passes(input: &str) -> Vec<Seat> {
+    common::split_strings(input).iter()
+        .map(Seat::try_from)
         .filter_map(|x| x.ok())
-        .collect();
-    println!("Loaded {} boarding passes.", passes.len());
+        .collect()
+}
 
-    // What's the highest numbered boarding pass?
-    let mut idvec:Vec<u64> = passes.iter().map(|x| x.id()).collect();
-    if let Some(max_id) = idvec.iter().max() {
-        println!("Part 1: Highest ID {}", max_id);
-    }
+/// What's the highest numbered boarding pass?
+fn part1(passes: &Vec<Seat>) -> u64 {
+    passes.iter().map(|x| x.id()).max().unwrap_or(0)
+}
 
-    // Find the missing pass location, which is the only
-    // one where the ID vector increments by +2.
+/// Find the missing pass location, which is the only
+/// one where the ID vector increments by +2.
+fn part2(passes: &Vec<Seat>) -> u64 {
+    let mut idvec:Vec<u64> = passes.iter().map(|x| x.id()).collect();
     idvec.sort();
     let mut prev = idvec[0];
     for id in idvec.iter() {
-        if *id == prev+2 {
-            println!("Part 2: My seat ID is {}.", prev+1);
-        }
+        if *id == prev+2 {return prev+1;}
         prev = *id;
     }
+    0
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<Seat>;
+    fn parse(input: &str) -> Vec<Seat> {read_passes(input)}
+    fn part1(input: &Vec<Seat>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<Seat>) -> impl std::fmt::Display {part2(input)}
+}
+
+pub fn solve() {
+    aoclib::print::<Day>(&common::read_file("input/input05.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Test cases from the problem statement.
+        let test1a = Seat::try_from(&String::from("BFFFBBFRRR")).unwrap();
+        let test1b = Seat::try_from(&String::from("FFFBBBFRRR")).unwrap();
+        let test1c = Seat::try_from(&String::from("BBFFBBFRLL")).unwrap();
+        assert_eq!(test1a.id(), 567);
+        assert_eq!(test1b.id(), 119);
+        assert_eq!(test1c.id(), 820);
+    }
 }
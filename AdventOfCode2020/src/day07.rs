@@ -5,7 +5,7 @@ use std::collections::HashMap;
 #[path = "common.rs"] mod common;
 
 struct Rule(HashMap <String, usize>);   // Color -> #Bags that color
-struct Rules(HashMap <String, Rule>);   // Color -> Child rule(s)
+pub struct Rules(HashMap <String, Rule>);   // Color -> Child rule(s)
 
 fn make_color(x:&str, y:&str) -> String {
     String::from(x) + y
@@ -98,37 +98,50 @@ impl Rules {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Rules;
+    fn parse(input: &str) -> Rules {Rules::from(&common::split_strings(input))}
+    fn part1(input: &Rules) -> impl std::fmt::Display {input.can_contain_gold()}
+    fn part2(input: &Rules) -> impl std::fmt::Display {input.count_child_gold()}
+}
 
 /// Solve Part-1 and Part-2 of the problem statement.
 pub fn solve() {
-    // Parse the example input.
-    let test1 = Rules::from(&vec![
-        String::from("light red bags contain 1 bright white bag, 2 muted yellow bags."),
-        String::from("dark orange bags contain 3 bright white bags, 4 muted yellow bags."),
-        String::from("bright white bags contain 1 shiny gold bag."),
-        String::from("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags."),
-        String::from("shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags."),
-        String::from("dark olive bags contain 3 faded blue bags, 4 dotted black bags."),
-        String::from("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
-        String::from("faded blue bags contain no other bags."),
-        String::from("dotted black bags contain no other bags."),
-    ]);
-    let test2 = Rules::from(&vec![
-        String::from("shiny gold bags contain 2 dark red bags."),
-        String::from("dark red bags contain 2 dark orange bags."),
-        String::from("dark orange bags contain 2 dark yellow bags."),
-        String::from("dark yellow bags contain 2 dark green bags."),
-        String::from("dark green bags contain 2 dark blue bags."),
-        String::from("dark blue bags contain 2 dark violet bags."),
-        String::from("dark violet bags contain no other bags."),
-    ]);
-    println!("Test 1: {} colors can contain gold.", test1.can_contain_gold());
-    println!("Test 2: {}/32 nested bags.", test1.count_child_gold());
-    println!("Test 3: {}/126 nested bags.", test2.count_child_gold());
-
-    // Read and analyze the main input.
-    let input_str = common::read_strings("input/input07.txt");
-    let input = Rules::from(&input_str);
-    println!("Part 1: {} colors can contain gold.", input.can_contain_gold());
-    println!("Part 2: {} nested bags.", input.count_child_gold());
+    aoclib::print::<Day>(&common::read_file("input/input07.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Parse the example input.
+        let test1 = Rules::from(&vec![
+            String::from("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            String::from("dark orange bags contain 3 bright white bags, 4 muted yellow bags."),
+            String::from("bright white bags contain 1 shiny gold bag."),
+            String::from("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags."),
+            String::from("shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags."),
+            String::from("dark olive bags contain 3 faded blue bags, 4 dotted black bags."),
+            String::from("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
+            String::from("faded blue bags contain no other bags."),
+            String::from("dotted black bags contain no other bags."),
+        ]);
+        let test2 = Rules::from(&vec![
+            String::from("shiny gold bags contain 2 dark red bags."),
+            String::from("dark red bags contain 2 dark orange bags."),
+            String::from("dark orange bags contain 2 dark yellow bags."),
+            String::from("dark yellow bags contain 2 dark green bags."),
+            String::from("dark green bags contain 2 dark blue bags."),
+            String::from("dark blue bags contain 2 dark violet bags."),
+            String::from("dark violet bags contain no other bags."),
+        ]);
+        assert_eq!(test1.can_contain_gold(), 4);
+        assert_eq!(test1.count_child_gold(), 32);
+        assert_eq!(test2.count_child_gold(), 126);
+    }
 }
@@ -9,7 +9,7 @@ enum Opcode {
     Jmp(i64),
 }
 
-struct Program(Vec<Opcode>);
+pub struct Program(Vec<Opcode>);
 type CompiledProgram = Result<Program, String>;
 
 struct ProgState {
@@ -118,50 +118,41 @@ impl Program {
     }
 }
 
-/// Print Part1 solution (run program until repeat)
-fn print_part1(lbl: &str, prog: &CompiledProgram) {
-    match prog {
-        Ok(prog) => println!("{}: Result = {}", lbl, prog.run_until_repeat().acc),
-        Err(msg) => eprintln!("{}: Compile error @ {}", lbl, msg),
-    }
-}
+/// Solution for the unified runner.
+pub struct Day;
 
-/// Print Part1 solution (modify program to terminate normally)
-fn print_part2(lbl: &str, prog: &CompiledProgram) {
-    // Did we compile success
-    match prog {
-        Ok(prog) => {
-            if let Some(n) = prog.mutate_all() {
-                println!("{}: Result = {}", lbl, n)
-            } else {
-                eprintln!("{}: No mutation successful.", lbl)
-            }
-        },
-        Err(msg) => eprintln!("{}: Compile error @ {}", lbl, msg),
-    }
+impl aoclib::Solution for Day {
+    type Input = Program;
+    fn parse(input: &str) -> Program {Program::compile(&common::split_strings(input)).unwrap()}
+    fn part1(input: &Program) -> impl std::fmt::Display {input.run_until_repeat().acc}
+    fn part2(input: &Program) -> impl std::fmt::Display {input.mutate_all().unwrap()}
 }
 
 pub fn solve() {
-    let example = vec![
-        String::from("nop +0"),
-        String::from("acc +1"),
-        String::from("jmp +4"),
-        String::from("acc +3"),
-        String::from("jmp -3"),
-        String::from("acc -99"),
-        String::from("acc +1"),
-        String::from("jmp -4"),
-        String::from("acc +6"),
-    ];
-
-    // Compile and run the example program.
-    let test1 = Program::compile(&example);
-    print_part1("Test1", &test1);
-    print_part2("Test2", &test1);
-
-    // Compile and run the Part1 program.
-    let input = common::read_strings("input/input08.txt");
-    let prog1 = Program::compile(&input);
-    print_part1("Part1", &prog1);
-    print_part2("Part2", &prog1);
+    aoclib::print::<Day>(&common::read_file("input/input08.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example = vec![
+            String::from("nop +0"),
+            String::from("acc +1"),
+            String::from("jmp +4"),
+            String::from("acc +3"),
+            String::from("jmp -3"),
+            String::from("acc -99"),
+            String::from("acc +1"),
+            String::from("jmp -4"),
+            String::from("acc +6"),
+        ];
+
+        // Compile and run the example program.
+        let test1 = Program::compile(&example).unwrap();
+        assert_eq!(test1.run_until_repeat().acc, 5);
+        assert_eq!(test1.mutate_all(), Some(8));
+    }
 }
@@ -56,7 +56,7 @@ fn find_badinput(wind: usize, input: &Vec<i64>) -> Option<i64> {
 
 fn find_weakness(target: i64, input: &Vec<i64>) -> Option<i64> {
     // Use cumulative sum to quickly check every contiguous range.
-    let csum = common::cumsum(&input);
+    let csum = common::cumsum(input);
     for a in 0..csum.len()-1 {
         for b in a+1..csum.len() {
             if target == csum[b] - csum[a] {
@@ -70,22 +70,32 @@ fn find_weakness(target: i64, input: &Vec<i64>) -> Option<i64> {
     None
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<i64>;
+    fn parse(input: &str) -> Vec<i64> {common::split_integers(input)}
+    fn part1(input: &Vec<i64>) -> impl std::fmt::Display {find_badinput(25, input).unwrap()}
+    fn part2(input: &Vec<i64>) -> impl std::fmt::Display {
+        let target = find_badinput(25, input).unwrap();
+        find_weakness(target, input).unwrap()
+    }
+}
+
 pub fn solve() {
-    // Solve the 5-number example:
-    let example:Vec<i64> = vec![35,20,15,25,47,40,62,55,65,95,102,117,150,182,127,219,299,277,309,576];
-    assert_eq!(find_badinput(5, &example), Some(127));
-    assert_eq!(find_weakness(127, &example), Some(62));
+    aoclib::print::<Day>(&common::read_file("input/input09.txt"));
+}
 
-    // Solve the main problem:
-    let input = common::read_integers("input/input09.txt");
-    if let Some(x) = find_badinput(25, &input) {
-        println!("Part1: BadInput = {}", x);
-        if let Some(y) = find_weakness(x, &input) {
-            println!("Part2: Weakness = {}", y);
-        } else {
-            eprintln!("Part2: No solution.");
-        }
-    } else {
-        eprintln!("Part1: No solution.");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Solve the 5-number example:
+        let example:Vec<i64> = vec![35,20,15,25,47,40,62,55,65,95,102,117,150,182,127,219,299,277,309,576];
+        assert_eq!(find_badinput(5, &example), Some(127));
+        assert_eq!(find_weakness(127, &example), Some(62));
     }
 }
@@ -14,9 +14,9 @@ fn part1(list: &Vec<usize>) -> u64 {
     let mut count3 = 1u64;  // Final output always +3
     for x in seq {
         match x - prev {
-            1 => Some(count1 += 1),
-            3 => Some(count3 += 1),
-            _ => None,
+            1 => count1 += 1,
+            3 => count3 += 1,
+            _ => (),
         };
         prev = x;
     }
@@ -42,22 +42,38 @@ fn part2(list: &Vec<usize>) -> u64 {
     memo[max]
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<usize>;
+    fn parse(input: &str) -> Vec<usize> {
+        common::split_integers(input).iter().map(|x| *x as usize).collect()
+    }
+    fn part1(input: &Vec<usize>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<usize>) -> impl std::fmt::Display {part2(input)}
+}
+
 pub fn solve() {
-    let example1:Vec<usize> = vec![
-        16,10,15,5,1,11,7,19,6,12,4];
-    let example2:Vec<usize> = vec![
-        28,33,18,42,31,14,46,20,48,47,24,23,49,45,19,
-        38,39,11,1,32,25,35,8,17,7,9,4,2,34,10,3];
-
-    // Test against example inputs.
-    assert_eq!(part1(&example1), 35u64);
-    assert_eq!(part1(&example2), 220u64);
-    assert_eq!(part2(&example1), 8u64);
-    assert_eq!(part2(&example2), 19208u64);
-
-    // Read and solve main input
-    let input:Vec<usize> = common::read_integers("input/input10.txt")
-        .iter().map(|x| *x as usize).collect();
-    println!("Part1: {}", part1(&input));
-    println!("Part2: {}", part2(&input));
+    aoclib::print::<Day>(&common::read_file("input/input10.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example1:Vec<usize> = vec![
+            16,10,15,5,1,11,7,19,6,12,4];
+        let example2:Vec<usize> = vec![
+            28,33,18,42,31,14,46,20,48,47,24,23,49,45,19,
+            38,39,11,1,32,25,35,8,17,7,9,4,2,34,10,3];
+
+        // Test against example inputs.
+        assert_eq!(part1(&example1), 35u64);
+        assert_eq!(part1(&example2), 220u64);
+        assert_eq!(part2(&example1), 8u64);
+        assert_eq!(part2(&example2), 19208u64);
+    }
 }
@@ -4,7 +4,7 @@
 #[path = "common.rs"] mod common;
 
 /// Raster-scan numbered seat-map.
-struct RasterMap {
+pub struct RasterMap {
     nrows:  usize,
     ncols:  usize,
     seats:  Vec<Option<usize>>,
@@ -61,7 +61,7 @@ impl SeatMap {
         let mut graph:Vec<Vec<usize>> = Vec::new();
         for r in 0..map.nrows {
             for c in 0..map.ncols {
-                if let Some(_) = map.get(r,c) {
+                if map.get(r,c).is_some() {
                     // Check each of eight directions:
                     let mut adj:Vec<usize> = Vec::new();
                     let scan = |dr,dc| map.scan(sight, r, c, dr as usize, dc as usize);
@@ -139,8 +139,8 @@ impl<'a> SeatState<'a> {
 }
 
 /// Find terminal state from a given seating map.
-fn terminal_state(map: &SeatMap) -> SeatState {
-    let mut curr = SeatState::init(&map);
+fn terminal_state(map: &SeatMap) -> SeatState<'_> {
+    let mut curr = SeatState::init(map);
     loop {
         let next = curr.iterate();
         if curr.seat == next.seat {return curr;}
@@ -148,36 +148,47 @@ fn terminal_state(map: &SeatMap) -> SeatState {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = RasterMap;
+    fn parse(input: &str) -> RasterMap {RasterMap::new(&common::split_strings(input)).unwrap()}
+    fn part1(input: &RasterMap) -> impl std::fmt::Display {
+        terminal_state(&SeatMap::new(input, false)).count()
+    }
+    fn part2(input: &RasterMap) -> impl std::fmt::Display {
+        terminal_state(&SeatMap::new(input, true)).count()
+    }
+}
+
 pub fn solve() {
-    let example = vec![
-        String::from("L.LL.LL.LL"),
-        String::from("LLLLLLL.LL"),
-        String::from("L.L.L..L.."),
-        String::from("LLLL.LL.LL"),
-        String::from("L.LL.LL.LL"),
-        String::from("L.LLLLL.LL"),
-        String::from("..L.L....."),
-        String::from("LLLLLLLLLL"),
-        String::from("L.LLLLLL.L"),
-        String::from("L.LLLLL.LL"),
-    ];
-    let input = common::read_strings("input/input11.txt");
-
-    if let Some(map) = RasterMap::new(&example) {
+    aoclib::print::<Day>(&common::read_file("input/input11.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example = vec![
+            String::from("L.LL.LL.LL"),
+            String::from("LLLLLLL.LL"),
+            String::from("L.L.L..L.."),
+            String::from("LLLL.LL.LL"),
+            String::from("L.LL.LL.LL"),
+            String::from("L.LLLLL.LL"),
+            String::from("..L.L....."),
+            String::from("LLLLLLLLLL"),
+            String::from("L.LLLLLL.L"),
+            String::from("L.LLLLL.LL"),
+        ];
+
+        let map = RasterMap::new(&example).unwrap();
         let part1 = SeatMap::new(&map, false);
         let part2 = SeatMap::new(&map, true);
         assert_eq!(terminal_state(&part1).count(), 37usize);
         assert_eq!(terminal_state(&part2).count(), 26usize);
-    } else {
-        eprintln!("Error compiling example.");
-    }
-
-    if let Some(map) = RasterMap::new(&input) {
-        let part1 = SeatMap::new(&map, false);
-        let part2 = SeatMap::new(&map, true);
-        println!("Part1: {} occupied seats", terminal_state(&part1).count());
-        println!("Part2: {} occupied seats", terminal_state(&part2).count());
-    } else {
-        eprintln!("Error compiling input.");
     }
 }
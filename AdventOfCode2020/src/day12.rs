@@ -3,7 +3,7 @@
 
 #[path = "common.rs"] mod common;
 
-enum Command {
+pub enum Command {
     North(i64),
     South(i64),
     East(i64),
@@ -139,17 +139,33 @@ fn part2(cmd:&[Command]) -> i64 {
     ship.manhattan()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<Command>;
+    fn parse(input: &str) -> Vec<Command> {parse(&common::split_strings(input))}
+    fn part1(input: &Vec<Command>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<Command>) -> impl std::fmt::Display {part2(input)}
+}
+
 pub fn solve() {
-    let example = parse(&vec![
-        String::from("F10"),
-        String::from("N3"),
-        String::from("F7"),
-        String::from("R90"),
-        String::from("F11")]);
-    let input = parse(&common::read_strings("input/input12.txt"));
-
-    assert_eq!(25, part1(&example));
-    assert_eq!(286, part2(&example));
-    println!("Part1: {}", part1(&input));
-    println!("Part2: {}", part2(&input));
+    aoclib::print::<Day>(&common::read_file("input/input12.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example = parse(&[String::from("F10"),
+            String::from("N3"),
+            String::from("F7"),
+            String::from("R90"),
+            String::from("F11")]);
+
+        assert_eq!(25, part1(&example));
+        assert_eq!(286, part2(&example));
+    }
 }
@@ -1,9 +1,8 @@
 /// Day 13: https://adventofcode.com/2020/day/13
 /// Copyright 2021 by Alex Utter
 
-extern crate num_integer;
-use num_integer::lcm;
 #[path = "common.rs"] mod common;
+use aoclib::numtheory::crt;
 
 struct Bus {
     pos: u64,
@@ -52,38 +51,44 @@ fn part2(bus_str:&str) -> Option<u64> {
     // Parse the list of Bus-IDs by position.
     let bus_vec = Bus::parse(bus_str);
 
-    // Solution for first bus is trivial (t=0, k=id).
-    // Add each subsequent bus by:
-    //  * Calculating k' = LCM(k, new-id)
-    //  * Incrementing t0 by k until:
-    //      * We find a match --> Repeat with next bus
-    //      * t > k' --> No solution (abort)
-    let mut t = 0u64;
-    let mut k = 1u64;
-    for bus in bus_vec.iter() {
-        let kp = lcm::<u64>(k, bus.id);
-        while (t < kp) && ((t+bus.pos) % bus.id != 0) {
-            t += k;
-        }
-        if t >= kp {return None;}
-        k = kp;
-    }
-    Some(t)
+    // Each bus must depart "pos" minutes after t, i.e., t = -pos (mod id).
+    // Solve the resulting set of congruences using the Chinese remainder
+    // theorem, which also handles IDs that aren't mutually prime.
+    let pairs: Vec<(i64, i64)> = bus_vec.iter()
+        .map(|bus| (-(bus.pos as i64), bus.id as i64))
+        .collect();
+    crt(&pairs).map(|(t, _)| t as u64)
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {part1(&input[0], &input[1])}
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {part2(&input[1]).unwrap_or(0)}
 }
 
 pub fn solve() {
-    let example = vec![
-        String::from("939"),
-        String::from("7,13,x,x,59,x,31,19")];
-    let input = common::read_strings("input/input13.txt");
+    aoclib::print::<Day>(&common::read_file("input/input13.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(295,                 part1(&example[0], &example[1]));
-    assert_eq!(Some(3417),          part2(&"17,x,13,19"));
-    assert_eq!(Some(754018),        part2(&"67,7,59,61"));
-    assert_eq!(Some(779210),        part2(&"67,x,7,59,61"));
-    assert_eq!(Some(1261476),       part2(&"67,7,x,59,61"));
-    assert_eq!(Some(1202161486),    part2(&"1789,37,47,1889"));
-    assert_eq!(Some(1068781),       part2(&example[1]));
-    println!("Part1: {}", part1(&input[0], &input[1]));
-    println!("Part2: {}", part2(&input[1]).unwrap_or(0));
+    #[test]
+    fn examples() {
+        let example = [String::from("939"),
+            String::from("7,13,x,x,59,x,31,19")];
+
+        assert_eq!(295,                 part1(&example[0], &example[1]));
+        assert_eq!(Some(3417),          part2("17,x,13,19"));
+        assert_eq!(Some(754018),        part2("67,7,59,61"));
+        assert_eq!(Some(779210),        part2("67,x,7,59,61"));
+        assert_eq!(Some(1261476),       part2("67,7,x,59,61"));
+        assert_eq!(Some(1202161486),    part2("1789,37,47,1889"));
+        assert_eq!(Some(1068781),       part2(&example[1]));
+    }
 }
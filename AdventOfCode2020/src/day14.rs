@@ -71,7 +71,7 @@ impl Program {
         let vec:Vec<&str> = line.split(" = ")
             .flat_map(|x| x.split(&['[',']'][..])).collect();
         if self.debug {println!("VEC = {:#?}", vec);}
-        match (vec.get(0), vec.get(1), vec.get(3)) {
+        match (vec.first(), vec.get(1), vec.get(3)) {
             (Some(cmd), Some(arg), None)
                 if cmd == &"mask"   => self.set_mask(arg),
             (Some(cmd), Some(pos), Some(val))
@@ -91,34 +91,47 @@ impl Program {
 
     fn sum(&self) -> u64 {
         if self.debug {println!("{:#?}", self.mem);}
-        self.mem.values().map(|x| *x).sum()
+        self.mem.values().copied().sum()
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {Program::run(input, false).sum()}
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {Program::run(input, true).sum()}
+}
+
 pub fn solve() {
-    let example1 = vec![
-        String::from("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"),
-        String::from("mem[8] = 11"),
-        String::from("mem[7] = 101"),
-        String::from("mem[8] = 0"),
-    ];
-    let example2 = vec![
-        String::from("mask = 000000000000000000000000000000X1001X"),
-        String::from("mem[42] = 100"),
-        String::from("mask = 00000000000000000000000000000000X0XX"),
-        String::from("mem[26] = 1"),
-    ];
-    let input = common::read_strings("input/input14.txt");
-
-    let test1 = Program::run(&example1, false);
-    assert_eq!(165, test1.sum());
-
-    let test2 = Program::run(&example2, true);
-    assert_eq!(208, test2.sum());
-
-    let part1 = Program::run(&input, false);
-    println!("Part1: {}", part1.sum());
-
-    let part2 = Program::run(&input, true);
-    println!("Part1: {}", part2.sum());
+    aoclib::print::<Day>(&common::read_file("input/input14.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example1 = vec![
+            String::from("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"),
+            String::from("mem[8] = 11"),
+            String::from("mem[7] = 101"),
+            String::from("mem[8] = 0"),
+        ];
+        let example2 = vec![
+            String::from("mask = 000000000000000000000000000000X1001X"),
+            String::from("mem[42] = 100"),
+            String::from("mask = 00000000000000000000000000000000X0XX"),
+            String::from("mem[26] = 1"),
+        ];
+
+        let test1 = Program::run(&example1, false);
+        assert_eq!(165, test1.sum());
+
+        let test2 = Program::run(&example2, true);
+        assert_eq!(208, test2.sum());
+    }
 }
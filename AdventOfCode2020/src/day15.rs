@@ -49,49 +49,75 @@ impl History {
     }
 }
 
+// Input for this problem is a short list of numbers.
+const INPUT: &str = "19,0,5,1,10,13";
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(_input: &str) -> String {INPUT.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {History::new(input).iter(2020)}
+    fn part2(input: &String) -> impl std::fmt::Display {History::new(input).iter(30000000)}
+}
+
 pub fn solve() {
-    // Test each of the examples.
-    let mut example1 = History::new("0,3,6");
-    assert_eq!(example1.next(), 0); // Turn 4
-    assert_eq!(example1.next(), 3); // Turn 5
-    assert_eq!(example1.next(), 3); // Turn 6
-    assert_eq!(example1.next(), 1); // Turn 7
-    assert_eq!(example1.next(), 0); // Turn 8
-    assert_eq!(example1.next(), 4); // Turn 9
-    assert_eq!(example1.next(), 0); // Turn 10
-    assert_eq!(example1.iter(2020), 436);
-
-    let mut example2 = History::new("1,3,2");
-    assert_eq!(example2.iter(2020), 1);
-
-    let mut example3 = History::new("2,1,3");
-    assert_eq!(example3.iter(2020), 10);
-
-    let mut example4 = History::new("1,2,3");
-    assert_eq!(example4.iter(2020), 27);
-
-    let mut example5 = History::new("2,3,1");
-    assert_eq!(example5.iter(2020), 78);
-
-    let mut example6 = History::new("3,2,1");
-    assert_eq!(example6.iter(2020), 438);
-
-    let mut example7 = History::new("3,1,2");
-    assert_eq!(example7.iter(2020), 1836);
-
-    // Part-1 solution.
-    let mut input = History::new("19,0,5,1,10,13");
-    println!("Part1: {}", input.iter(2020));
-
-    // Extended tests.
-    assert_eq!(example1.iter(30000000), 175594);
-    assert_eq!(example2.iter(30000000), 2578);
-    assert_eq!(example3.iter(30000000), 3544142);
-    assert_eq!(example4.iter(30000000), 261214);
-    assert_eq!(example5.iter(30000000), 6895259);
-    assert_eq!(example6.iter(30000000), 18);
-    assert_eq!(example7.iter(30000000), 362);
-
-    // Part-2 solution.
-    println!("Part2: {}", input.iter(30000000));
+    aoclib::print::<Day>("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Test each of the examples.
+        let mut example1 = History::new("0,3,6");
+        assert_eq!(example1.next(), 0); // Turn 4
+        assert_eq!(example1.next(), 3); // Turn 5
+        assert_eq!(example1.next(), 3); // Turn 6
+        assert_eq!(example1.next(), 1); // Turn 7
+        assert_eq!(example1.next(), 0); // Turn 8
+        assert_eq!(example1.next(), 4); // Turn 9
+        assert_eq!(example1.next(), 0); // Turn 10
+        assert_eq!(example1.iter(2020), 436);
+
+        let mut example2 = History::new("1,3,2");
+        assert_eq!(example2.iter(2020), 1);
+
+        let mut example3 = History::new("2,1,3");
+        assert_eq!(example3.iter(2020), 10);
+
+        let mut example4 = History::new("1,2,3");
+        assert_eq!(example4.iter(2020), 27);
+
+        let mut example5 = History::new("2,3,1");
+        assert_eq!(example5.iter(2020), 78);
+
+        let mut example6 = History::new("3,2,1");
+        assert_eq!(example6.iter(2020), 438);
+
+        let mut example7 = History::new("3,1,2");
+        assert_eq!(example7.iter(2020), 1836);
+    }
+
+    #[test]
+    #[ignore = "slow, run with --ignored"]
+    fn extended() {
+        let mut example1 = History::new("0,3,6");
+        let mut example2 = History::new("1,3,2");
+        let mut example3 = History::new("2,1,3");
+        let mut example4 = History::new("1,2,3");
+        let mut example5 = History::new("2,3,1");
+        let mut example6 = History::new("3,2,1");
+        let mut example7 = History::new("3,1,2");
+        assert_eq!(example1.iter(30000000), 175594);
+        assert_eq!(example2.iter(30000000), 2578);
+        assert_eq!(example3.iter(30000000), 3544142);
+        assert_eq!(example4.iter(30000000), 261214);
+        assert_eq!(example5.iter(30000000), 6895259);
+        assert_eq!(example6.iter(30000000), 18);
+        assert_eq!(example7.iter(30000000), 362);
+    }
 }
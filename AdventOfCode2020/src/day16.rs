@@ -72,7 +72,7 @@ impl Field {
     /// Call check_tickets() for each field index, return legal ones.
     fn valid_indices(&self, tickets:&Vec<Ticket>) -> Vec<usize> {
         let mut indices = Vec::new();
-        if tickets.len() > 0 {
+        if !tickets.is_empty() {
             for n in 0..tickets[0].vals.len() {
                 if self.check_tickets(n, tickets) {indices.push(n);}
             }
@@ -126,7 +126,7 @@ impl Ticket {
 }
 
 /// "Problem" is a set of fields, your ticket, and nearby tickets.
-struct Problem {
+pub struct Problem {
     fields: Fields,
     ticket: Ticket,
     nearby: Vec<Ticket>,
@@ -134,8 +134,9 @@ struct Problem {
 
 impl Problem {
     /// Parse a full description into Fields and Tickets.
-    fn compile(lines: &Vec<String>) -> Result<Problem, String> {
-        let grp = common::group_strings(lines);
+    fn compile(input: &str) -> Result<Problem, String> {
+        let grp: Vec<Vec<String>> = aoclib::parse::blocks(input).iter()
+            .map(|b| common::split_strings(b)).collect();
         let fields = Problem::compile_fields(&grp[0])?;
         let ticket = Ticket::compile(&grp[1][1])?;
         let nearby = Problem::compile_tickets(&grp[2])?;
@@ -215,7 +216,7 @@ impl Permuter {
         // based on which indices match all nearby tickets.
         for (lbl,field) in problem.fields.iter() {
             let valid = field.valid_indices(&problem.nearby);
-            if verbose {common::print_list(&lbl, valid.iter().cloned());}
+            if verbose {common::print_list(lbl, valid.iter().cloned());}
             let set = HashSet::from_iter(valid.iter().cloned());
             new.unlock.insert(lbl.clone(), set);
         }
@@ -260,76 +261,76 @@ impl Permuter {
 
     /// Repeatedly lock down obvious possibilities.
     fn normalize(&mut self) {
-        loop {
-            if let Some((lbl,val)) = self.next_lockable() {
-                self.lock(lbl, val);
-            } else {break}
+        while let Some((lbl,val)) = self.next_lockable() {
+            self.lock(lbl, val);
         }
     }
 }
 
 /// Run example tests, then solve Part 1 and Part 2.
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Problem;
+    fn parse(input: &str) -> Problem {Problem::compile(input).unwrap()}
+    fn part1(input: &Problem) -> impl std::fmt::Display {input.scan_errors()}
+    fn part2(input: &Problem) -> impl std::fmt::Display {
+        // TODO: Solve general cases where permuter doesn't fully converge.
+        let valid = input.remove_errors();
+        let soln = Permuter::create(&valid, false);
+        valid.departure(&soln).unwrap()
+    }
+}
+
 pub fn solve() {
-    let example1:Vec<String> = vec![
-        String::from("class: 1-3 or 5-7"),
-        String::from("row: 6-11 or 33-44"),
-        String::from("seat: 13-40 or 45-50"),
-        String::from(""),
-        String::from("your ticket:"),
-        String::from("7,1,14"),
-        String::from(""),
-        String::from("nearby tickets:"),
-        String::from("7,3,47"),
-        String::from("40,4,50"),
-        String::from("55,2,20"),
-        String::from("38,6,12"),
-    ];
-
-    let example2:Vec<String> = vec![
-        String::from("class: 0-1 or 4-19"),
-        String::from("row: 0-5 or 8-19"),
-        String::from("seat: 0-13 or 16-19"),
-        String::from(""),
-        String::from("your ticket:"),
-        String::from("11,12,13"),
-        String::from(""),
-        String::from("nearby tickets:"),
-        String::from("3,9,18"),
-        String::from("15,1,5"),
-        String::from("5,14,9"),
-    ];
-
-    // Parse each example and confirm expected outputs.
-    if let Ok(test1) = Problem::compile(&example1) {
+    aoclib::print::<Day>(&common::read_file("input/input16.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example1:Vec<String> = vec![
+            String::from("class: 1-3 or 5-7"),
+            String::from("row: 6-11 or 33-44"),
+            String::from("seat: 13-40 or 45-50"),
+            String::from(""),
+            String::from("your ticket:"),
+            String::from("7,1,14"),
+            String::from(""),
+            String::from("nearby tickets:"),
+            String::from("7,3,47"),
+            String::from("40,4,50"),
+            String::from("55,2,20"),
+            String::from("38,6,12"),
+        ];
+
+        let example2:Vec<String> = vec![
+            String::from("class: 0-1 or 4-19"),
+            String::from("row: 0-5 or 8-19"),
+            String::from("seat: 0-13 or 16-19"),
+            String::from(""),
+            String::from("your ticket:"),
+            String::from("11,12,13"),
+            String::from(""),
+            String::from("nearby tickets:"),
+            String::from("3,9,18"),
+            String::from("15,1,5"),
+            String::from("5,14,9"),
+        ];
+
+        // Parse each example and confirm expected outputs.
+        let test1 = Problem::compile(&example1.join("\n")).unwrap();
         assert_eq!(test1.scan_errors(), 71);
-    } else {
-        eprintln!("Test1: Parse error!");
-    }
 
-    if let Ok(test2) = Problem::compile(&example2) {
-        let test2 = test2.remove_errors();
+        let test2 = Problem::compile(&example2.join("\n")).unwrap().remove_errors();
         let soln = Permuter::create(&test2, false);
         assert_eq!(soln.count_permutations(), 1u64);
         assert_eq!(soln.locked.get("row"),   Some(&0usize));
         assert_eq!(soln.locked.get("class"), Some(&1usize));
         assert_eq!(soln.locked.get("seat"),  Some(&2usize));
-    } else {
-        eprintln!("Test1: Parse error!");
-    }
-
-    // Parse and analyze the main input.
-    // TODO: Solve general cases where permuter doesn't fully converge.
-    let input = common::read_strings("input/input16.txt");
-    if let Ok(part1) = Problem::compile(&input) {
-        println!("Part1: {} scan errors", part1.scan_errors());
-        let part1 = part1.remove_errors();
-        let part2 = Permuter::create(&part1, false);
-        println!("Part2: Remaining permutations {}", part2.count_permutations());
-        match part1.departure(&part2) {
-            Ok(val) => println!("Part2: Product = {}", val),
-            Err(msg) => eprintln!("Part2: {}", msg),
-        }
-    } else {
-        eprintln!("Part1: Parse error!");
     }
 }
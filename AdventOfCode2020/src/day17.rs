@@ -25,7 +25,7 @@ impl Cube {
 
     /// Check whether the given coordinate is active.
     fn lookup(&self, xyz:&Coord) -> bool {
-        if let Some(_) = self.map.get(xyz) {true} else {false}
+        self.map.contains_key(xyz)
     }
 
     /// Count adjacent active coordinates.
@@ -96,34 +96,51 @@ impl Cube {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {
+        // 3D solution:
+        Cube::compile(input, false).iterate_n(6).count_active()
+    }
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {
+        // 4D solution:
+        Cube::compile(input, true).iterate_n(6).count_active()
+    }
+}
+
 pub fn solve() {
-    let example = vec![
-        String::from(".#."),
-        String::from("..#"),
-        String::from("###"),
-    ];
-
-    // Simulate the first six timesteps of the 3D example.
-    let test1 = Cube::compile(&example, false);
-    assert_eq!(test1.iterate_n(0).count_active(), 5usize);
-    assert_eq!(test1.iterate_n(1).count_active(), 11usize);
-    assert_eq!(test1.iterate_n(2).count_active(), 21usize);
-    assert_eq!(test1.iterate_n(3).count_active(), 38usize);
-    assert_eq!(test1.iterate_n(6).count_active(), 112usize);
-
-    // Simulate the first six timesteps of the 4D example.
-    let test2 = Cube::compile(&example, true);
-    assert_eq!(test2.iterate_n(0).count_active(), 5usize);
-    assert_eq!(test2.iterate_n(1).count_active(), 29usize);
-    assert_eq!(test2.iterate_n(2).count_active(), 60usize);
-    assert_eq!(test2.iterate_n(6).count_active(), 848usize);
-
-    // Part-1 3D solution:
-    let input = common::read_strings("input/input17.txt");
-    let cube1 = Cube::compile(&input, false);
-    println!("Part1: {} active cubes.", cube1.iterate_n(6).count_active());
-
-    // Part-2 4D solution:
-    let cube2 = Cube::compile(&input, true);
-    println!("Part2: {} active cubes.", cube2.iterate_n(6).count_active());
+    aoclib::print::<Day>(&common::read_file("input/input17.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example = vec![
+            String::from(".#."),
+            String::from("..#"),
+            String::from("###"),
+        ];
+
+        // Simulate the first six timesteps of the 3D example.
+        let test1 = Cube::compile(&example, false);
+        assert_eq!(test1.iterate_n(0).count_active(), 5usize);
+        assert_eq!(test1.iterate_n(1).count_active(), 11usize);
+        assert_eq!(test1.iterate_n(2).count_active(), 21usize);
+        assert_eq!(test1.iterate_n(3).count_active(), 38usize);
+        assert_eq!(test1.iterate_n(6).count_active(), 112usize);
+
+        // Simulate the first six timesteps of the 4D example.
+        let test2 = Cube::compile(&example, true);
+        assert_eq!(test2.iterate_n(0).count_active(), 5usize);
+        assert_eq!(test2.iterate_n(1).count_active(), 29usize);
+        assert_eq!(test2.iterate_n(2).count_active(), 60usize);
+        assert_eq!(test2.iterate_n(6).count_active(), 848usize);
+    }
 }
@@ -60,7 +60,7 @@ enum Expression {
 
 impl Expression {
     pub fn new(s:&String, adv:bool) -> Option<Expression> {
-        if let Some(tokens) = parse_tokens(&s) {
+        if let Some(tokens) = parse_tokens(s) {
             Self::compile(&tokens, 0, tokens.len(), adv)
         } else {None}
     }
@@ -153,47 +153,54 @@ impl Expression {
     }
 }
 
-fn test_eval(refa:i64, refb:i64, x:&str) {
-    let s = String::from(x);
-    let expr_beg = Expression::new(&s, false);
-    let expr_adv = Expression::new(&s, true);
-    if let (Some(beg),Some(adv)) = (expr_beg, expr_adv) {
-        let x = beg.eval();
-        let y = adv.eval();
-        if x != refa {eprintln!("Test value mismatch {} /= {}", x, refa)};
-        if y != refb {eprintln!("Test value mismatch {} /= {}", y, refb)};
+/// Compile and evaluate each line, returning the total.
+fn sum_all(input: &Vec<String>, adv: bool) -> Option<i64> {
+    let expr:Vec<Expression> = input.iter()
+        .filter_map(|x| Expression::new(x,adv)).collect();
+    if expr.len() == input.len() {
+        Some(expr.iter().map(|x| x.eval()).sum())
     } else {
-        eprintln!("Test compile error: {}", x);
+        None    // Compile error
+    }
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {
+        // Basic arithmetic rules.
+        sum_all(input, false).unwrap()
+    }
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {
+        // Advanced arithmetic rules.
+        sum_all(input, true).unwrap()
     }
 }
 
 pub fn solve() {
-    // Unit tests:
-    test_eval(71,    231,    "1 + 2 * 3 + 4 * 5 + 6");
-    test_eval(51,    51,     "1 + (2 * 3) + (4 * (5 + 6))");
-    test_eval(26,    46,     "2 * 3 + (4 * 5)");
-    test_eval(437,   1445,   "5 + (8 * 3 + 9 + 3 * 4 * 3)");
-    test_eval(12240, 669060, "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))");
-    test_eval(13632, 23340,  "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2");
-
-    // Part 1: Basic arithmetic rules.
-    let input = common::read_strings("input/input18.txt");
-    let expr_beg:Vec<Expression> = input.iter()
-        .filter_map(|x| Expression::new(x,false)).collect();
-    if expr_beg.len() == input.len() {
-        let sum:i64 = expr_beg.iter().map(|x| x.eval()).sum();
-        println!("Part1: Sum = {}", sum);
-    } else {
-        eprintln!("Part1: Compile error");
+    aoclib::print::<Day>(&common::read_file("input/input18.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_eval(refa:i64, refb:i64, x:&str) {
+        let s = String::from(x);
+        assert_eq!(Expression::new(&s, false).unwrap().eval(), refa);
+        assert_eq!(Expression::new(&s, true).unwrap().eval(), refb);
     }
 
-    // Part 2: Advanced arithmetic rules.
-    let expr_adv:Vec<Expression> = input.iter()
-        .filter_map(|x| Expression::new(x,true)).collect();
-    if expr_adv.len() == input.len() {
-        let sum:i64 = expr_adv.iter().map(|x| x.eval()).sum();
-        println!("Part2: Sum = {}", sum);
-    } else {
-        eprintln!("Part2: Compile error");
+    #[test]
+    fn examples() {
+        test_eval(71,    231,    "1 + 2 * 3 + 4 * 5 + 6");
+        test_eval(51,    51,     "1 + (2 * 3) + (4 * (5 + 6))");
+        test_eval(26,    46,     "2 * 3 + (4 * 5)");
+        test_eval(437,   1445,   "5 + (8 * 3 + 9 + 3 * 4 * 3)");
+        test_eval(12240, 669060, "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))");
+        test_eval(13632, 23340,  "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2");
     }
 }
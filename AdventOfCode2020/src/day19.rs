@@ -16,9 +16,7 @@ impl Rule {
     fn parse(rule:&str) -> Option<Rule> {
         if rule.contains('"') {
             let s:Vec<char> = rule.trim().chars().collect();
-            if let Some(c) = s.get(1) {
-                Some(Rule::Simplex(*c))
-            } else {None}
+            s.get(1).map(|c| Rule::Simplex(*c))
         } else if rule.contains('|') {
             if let Some((a,b)) = common::split2(rule, "|") {
                 let a = common::parse_vec::<usize>(&String::from(a), ' ');
@@ -26,7 +24,7 @@ impl Rule {
                 Some(Rule::EitherOr(a, b))
             } else {None}
         } else {
-            let a = common::parse_vec::<usize>(&rule, ' ');
+            let a = common::parse_vec::<usize>(rule, ' ');
             Some(Rule::Sequence(a))
         }
     }
@@ -67,7 +65,7 @@ impl<'a> RuleChecker<'a> {
     fn check(&mut self) -> bool {
         let len = self.rcvd.len();
         let to  = self.check_one(0, 0);
-        to.iter().any(|x| *x == len)
+        to.contains(&len)
     }
 
     // Helper functions for check_one(), see below.
@@ -97,10 +95,10 @@ impl<'a> RuleChecker<'a> {
             Rule::Simplex(c) =>
                 self.check_char(c, from),
             Rule::Sequence(vec) =>
-                self.check_seq(&vec, from).iter().cloned().collect(),
+                self.check_seq(vec, from).iter().cloned().collect(),
             Rule::EitherOr(vec1, vec2) => {
-                let a = self.check_seq(&vec1, from);
-                let b = self.check_seq(&vec2, from);
+                let a = self.check_seq(vec1, from);
+                let b = self.check_seq(vec2, from);
                 a.union(&b).cloned().collect()
             },
         }
@@ -122,9 +120,10 @@ impl<'a> RuleChecker<'a> {
     }
 }
 
-fn count_matches(raw:&Vec<String>, mutate:bool) -> usize {
+fn count_matches(raw:&str, mutate:bool) -> usize {
     // Split rules and received messages.
-    let grp   = common::group_strings(&raw);
+    let grp: Vec<Vec<String>> = aoclib::parse::blocks(raw).iter()
+        .map(|b| common::split_strings(b)).collect();
     let rules = parse_rules(&grp[0]);
     let rxvec = &grp[1];
 
@@ -138,24 +137,41 @@ fn count_matches(raw:&Vec<String>, mutate:bool) -> usize {
         // Test each string.
         let mut count = 0usize;
         for rx in rxvec.iter() {
-            let chk = RuleChecker::create(&rx, &rules).check();
+            let chk = RuleChecker::create(rx, &rules).check();
             if chk {count += 1usize;}
         }
         count
     } else {0usize}
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {count_matches(input, false)}
+    fn part2(input: &String) -> impl std::fmt::Display {count_matches(input, true)}
+}
+
 pub fn solve() {
-    // Read each input file.
-    let example1 = common::read_strings("input/test19a.txt");
-    let example2 = common::read_strings("input/test19b.txt");
-    let example3 = common::read_strings("input/test19c.txt");
-    let input    = common::read_strings("input/input19.txt");
-
-    assert_eq!(2, count_matches(&example1, false));
-    assert_eq!(2, count_matches(&example2, false));
-    assert_eq!(3, count_matches(&example3, false));
-    assert_eq!(12, count_matches(&example3, true));
-    println!("Part1: {} matches", count_matches(&input, false));
-    println!("Part2: {} matches", count_matches(&input, true));
+    aoclib::print::<Day>(&common::read_file("input/input19.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Read each input file.
+        let example1 = common::read_file("input/test19a.txt");
+        let example2 = common::read_file("input/test19b.txt");
+        let example3 = common::read_file("input/test19c.txt");
+
+        assert_eq!(2, count_matches(&example1, false));
+        assert_eq!(2, count_matches(&example2, false));
+        assert_eq!(3, count_matches(&example3, false));
+        assert_eq!(12, count_matches(&example3, true));
+    }
 }
@@ -259,12 +259,10 @@ impl Tile {
         let n1 = n0.rotate();
         let n2 = n1.rotate();
         let n3 = n2.rotate();
-        let dcount = vec![
-            n0.mirror(), n0,
+        let dcount = [n0.mirror(), n0,
             n1.mirror(), n1,
             n2.mirror(), n2,
-            n3.mirror(), n3,
-        ];
+            n3.mirror(), n3];
         dcount.iter().map(|n| n.count_dragons_simple()).max().unwrap()
     }
 
@@ -277,7 +275,7 @@ impl Tile {
     fn remove_borders(&self, grid:usize) -> Tile {
         // Iterate over the grid.
         let mut matrix:Matrix = Vec::new();
-        let is_grid = |x:usize| ((x % grid) == 0);
+        let is_grid = |x:usize| (x % grid) == 0;
         for r in 0..self.nrows {
             if is_grid(r) {continue;}
             let mut row:Vec<char> = Vec::new();
@@ -299,16 +297,17 @@ impl Tile {
 }
 
 /// A set of image-tiles.
-struct TileSet {
+pub struct TileSet {
     tiles: HashMap<usize,Tile>,         // Map of all tiles by index
     edges: HashMap<usize,Vec<usize>>,   // Indices matching a given edge-hash
 }
 
 impl TileSet {
     /// Parse problem description into a set of tiles.
-    fn new(lines:&Vec<String>) -> TileSet {
+    fn new(input:&str) -> TileSet {
         // Break input into individual tiles.
-        let input = common::group_strings(lines);
+        let input: Vec<Vec<String>> = aoclib::parse::blocks(input).iter()
+            .map(|b| common::split_strings(b)).collect();
         let mut tiles: HashMap<usize,Tile> = HashMap::new();
         let mut edges: HashMap<usize,Vec<usize>> = HashMap::new();
         for grp in input.iter() {
@@ -429,18 +428,35 @@ impl TileSet {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = TileSet;
+    fn parse(input: &str) -> TileSet {TileSet::new(input)}
+    fn part1(input: &TileSet) -> impl std::fmt::Display {input.cproduct()}
+    fn part2(input: &TileSet) -> impl std::fmt::Display {input.solve().remove_borders(9).roughness()}
+}
+
 pub fn solve() {
-    // Part 1: Identify corners.
-    let test1 = TileSet::new(&common::read_strings("input/test20.txt"));
-    let input = TileSet::new(&common::read_strings("input/input20.txt"));
+    aoclib::print::<Day>(&common::read_file("input/input20.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Test1: {}", test1.cproduct());
-    println!("Part1: {}", input.cproduct());
+    #[test]
+    fn examples() {
+        // Part 1: Identify corners.
+        let test1 = TileSet::new(&common::read_file("input/test20.txt"));
 
-    // Part 2: Solve each puzzle and look for dragons.
-    let solve1 = test1.solve().remove_borders(9);
-    let solve2 = input.solve().remove_borders(9);
+        assert_eq!(test1.cproduct(), 20899048083289);
 
-    println!("Test2: {} -> {}", solve1.count_dragons_any(), solve1.roughness());
-    println!("Part2: {} -> {}", solve2.count_dragons_any(), solve2.roughness());
+        // Part 2: Solve each puzzle and look for dragons.
+        let solve1 = test1.solve().remove_borders(9);
+
+        assert_eq!(solve1.count_dragons_any(), 2);
+        assert_eq!(solve1.roughness(), 273);
+    }
 }
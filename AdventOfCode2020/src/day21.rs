@@ -146,20 +146,36 @@ impl<'a> Problem<'a> {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_strings(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {Problem::parse(input).part1()}
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {Problem::parse(input).part2()}
+}
+
 pub fn solve() {
-    let str_example = vec![
-        String::from("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"),
-        String::from("trh fvjkl sbzzf mxmxvkd (contains dairy)"),
-        String::from("sqjhc fvjkl (contains soy)"),
-        String::from("sqjhc mxmxvkd sbzzf (contains fish)"),
-    ];
-    let str_input = common::read_strings("input/input21.txt");
-
-    let example = Problem::parse(&str_example);
-    let input = Problem::parse(&str_input);
-
-    assert_eq!(example.part1(), 5usize);
-    assert_eq!(example.part2(), String::from("mxmxvkd,sqjhc,fvjkl"));
-    println!("Part1: {}", input.part1());
-    println!("Part2: {}", input.part2());
+    aoclib::print::<Day>(&common::read_file("input/input21.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let str_example = vec![
+            String::from("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"),
+            String::from("trh fvjkl sbzzf mxmxvkd (contains dairy)"),
+            String::from("sqjhc fvjkl (contains soy)"),
+            String::from("sqjhc mxmxvkd sbzzf (contains fish)"),
+        ];
+
+        let example = Problem::parse(&str_example);
+
+        assert_eq!(example.part1(), 5usize);
+        assert_eq!(example.part2(), String::from("mxmxvkd,sqjhc,fvjkl"));
+    }
 }
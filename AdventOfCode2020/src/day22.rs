@@ -42,7 +42,7 @@ impl Deck {
 }
 
 #[derive(Clone)]
-struct Game {
+pub struct Game {
     deck1: Deck,
     deck2: Deck,
 }
@@ -58,9 +58,10 @@ type History = HashSet<u64>;
 
 impl Game {
     /// Read initial state of two decks from problem description.
-    fn new(lines: &Vec<String>) -> Game {
+    fn new(input: &str) -> Game {
         // Split deck descriptions by the blank line.
-        let groups = common::group_strings(lines);
+        let groups: Vec<Vec<String>> = aoclib::parse::blocks(input).iter()
+            .map(|b| common::split_strings(b)).collect();
         Game {
             deck1: Deck::new(&groups[0]),
             deck2: Deck::new(&groups[1]),
@@ -182,15 +183,31 @@ impl Game {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Game;
+    fn parse(input: &str) -> Game {Game::new(input)}
+    fn part1(input: &Game) -> impl std::fmt::Display {input.part1()}
+    fn part2(input: &Game) -> impl std::fmt::Display {input.part2()}
+}
+
 pub fn solve() {
-    let example1 = Game::new(&common::read_strings("input/test22a.txt"));
-    let example2 = Game::new(&common::read_strings("input/test22b.txt"));
-    let input = Game::new(&common::read_strings("input/input22.txt"));
+    aoclib::print::<Day>(&common::read_file("input/input22.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(example1.part1(), 306);
-    assert_eq!(example1.part2(), 291);
-    assert_eq!(example2.part2(), 105);
+    #[test]
+    fn examples() {
+        let example1 = Game::new(&common::read_file("input/test22a.txt"));
+        let example2 = Game::new(&common::read_file("input/test22b.txt"));
 
-    println!("Part1: {}", input.part1());
-    println!("Part2: {}", input.part2());
+        assert_eq!(example1.part1(), 306);
+        assert_eq!(example1.part2(), 291);
+        assert_eq!(example2.part2(), 105);
+    }
 }
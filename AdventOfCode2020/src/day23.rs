@@ -86,32 +86,55 @@ impl Cups {
     }
 }
 
+// Input for this problem is a single short string.
+const INPUT: &str = "716892543";
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(_input: &str) -> String {INPUT.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {
+        let mut cups = Cups::new(input, 9);
+        cups.iter_n(100);
+        cups.to_string()
+    }
+    fn part2(input: &String) -> impl std::fmt::Display {
+        let mut cups = Cups::new(input, 1_000_000);
+        cups.iter_n(10_000_000);
+        cups.product()
+    }
+}
+
 pub fn solve() {
-    let mut test1  = Cups::new("389125467", 9);
-    assert_eq!(test1.to_string(), "25467389");                      // Step 0
-    test1.iter_1(); assert_eq!(test1.to_string(), "54673289");      // Step 1
-    test1.iter_1(); assert_eq!(test1.to_string(), "32546789");      // Step 2
-    test1.iter_1(); assert_eq!(test1.to_string(), "34672589");      // Step 3
-    test1.iter_1(); assert_eq!(test1.to_string(), "32584679");      // Step 4
-    test1.iter_1(); assert_eq!(test1.to_string(), "36792584");      // Step 5
-    test1.iter_1(); assert_eq!(test1.to_string(), "93672584");      // Step 6
-    test1.iter_1(); assert_eq!(test1.to_string(), "92583674");      // Step 7
-    test1.iter_1(); assert_eq!(test1.to_string(), "58392674");      // Step 8
-    test1.iter_1(); assert_eq!(test1.to_string(), "83926574");      // Step 9
-    test1.iter_1(); assert_eq!(test1.to_string(), "92658374");      // Step 10
-    assert_eq!(test1.product(), 18);
-    test1.iter_n(90); assert_eq!(test1.to_string(), "67384529");    // Step 100
-    assert_eq!(test1.product(), 42);
+    aoclib::print::<Day>("");
+}
 
-    let mut test2  = Cups::new("389125467", 1_000_000);
-    test2.iter_n(10_000_000);
-    assert_eq!(test2.product(), 149245887792u64);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut input1 = Cups::new("716892543", 9);
-    input1.iter_n(100);
-    println!("Part1: {}", input1.to_string());
+    #[test]
+    fn examples() {
+        let mut test1  = Cups::new("389125467", 9);
+        assert_eq!(test1.to_string(), "25467389");                      // Step 0
+        test1.iter_1(); assert_eq!(test1.to_string(), "54673289");      // Step 1
+        test1.iter_1(); assert_eq!(test1.to_string(), "32546789");      // Step 2
+        test1.iter_1(); assert_eq!(test1.to_string(), "34672589");      // Step 3
+        test1.iter_1(); assert_eq!(test1.to_string(), "32584679");      // Step 4
+        test1.iter_1(); assert_eq!(test1.to_string(), "36792584");      // Step 5
+        test1.iter_1(); assert_eq!(test1.to_string(), "93672584");      // Step 6
+        test1.iter_1(); assert_eq!(test1.to_string(), "92583674");      // Step 7
+        test1.iter_1(); assert_eq!(test1.to_string(), "58392674");      // Step 8
+        test1.iter_1(); assert_eq!(test1.to_string(), "83926574");      // Step 9
+        test1.iter_1(); assert_eq!(test1.to_string(), "92658374");      // Step 10
+        assert_eq!(test1.product(), 18);
+        test1.iter_n(90); assert_eq!(test1.to_string(), "67384529");    // Step 100
+        assert_eq!(test1.product(), 42);
 
-    let mut input2 = Cups::new("716892543", 1_000_000);
-    input2.iter_n(10_000_000);
-    println!("Part2: {}", input2.product());
+        let mut test2  = Cups::new("389125467", 1_000_000);
+        test2.iter_n(10_000_000);
+        assert_eq!(test2.product(), 149245887792u64);
+    }
 }
@@ -2,51 +2,48 @@
 /// Copyright 2021 by Alex Utter
 
 #[path = "common.rs"] mod common;
+use aoclib::numtheory::{discrete_log, pow_mod};
 
-#[derive(Clone)]
-struct Key {
-    subj:   u64,
-    key:    u64,
-    iter:   usize,
+const MODULUS: i64 = 20201227;
+
+// Find the loop size that transforms subject 7 into a public key.
+fn loop_size(key: u64) -> u64 {
+    discrete_log(7, key as i64, MODULUS).unwrap()
 }
 
-impl Key {
-    fn new(subj:u64) -> Key {
-        Key {subj:subj, key:1, iter:0}
-    }
+// Transform one public key using the other's loop size.
+fn encryption_key(pub1: u64, pub2: u64) -> u64 {
+    pow_mod(pub2 as i64, loop_size(pub1), MODULUS) as u64
+}
 
-    fn iter(&mut self, n:usize) {
-        for _ in 0..n {
-            self.key = (self.key * self.subj) % 20201227u64;
-            self.iter += 1;
-        }
-    }
+// Input for this problem is a pair of public keys.
+const INPUT: (u64, u64) = (10212254, 12577395);
 
-    fn solve(val:u64) -> Key {
-        let mut key = Key::new(7);
-        while key.key != val {key.iter(1);}
-        key
-    }
+/// Solution for the unified runner.
+pub struct Day;
 
-    fn merge(pub1:&Key, pub2:&Key) -> Key {
-        let mut key = Key::new(pub2.key);
-        key.iter(pub1.iter);
-        key
+impl aoclib::Solution for Day {
+    type Input = (u64, u64);
+    fn parse(_input: &str) -> (u64, u64) {INPUT}
+    fn part1(input: &(u64, u64)) -> impl std::fmt::Display {
+        encryption_key(input.0, input.1)
     }
 }
 
 pub fn solve() {
-    // Solve the example.
-    let test1a = Key::solve(5764801);           // Card public key
-    let test1b = Key::solve(17807724);          // Door public key
-    let test1c = Key::merge(&test1a, &test1b);  // Encryption key
-    assert_eq!(test1a.iter, 8);
-    assert_eq!(test1b.iter, 11);
-    assert_eq!(test1c.key, 14897079u64);
-
-    // Solve Part-1.
-    let part1a = Key::solve(10212254);
-    let part1b = Key::solve(12577395);
-    let part1c = Key::merge(&part1a, &part1b);
-    println!("Part1: {}", part1c.key);
+    aoclib::print::<Day>("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Solve the example.
+        assert_eq!(loop_size(5764801), 8);              // Card public key
+        assert_eq!(loop_size(17807724), 11);            // Door public key
+        assert_eq!(encryption_key(5764801, 17807724), 14897079);
+        assert_eq!(encryption_key(17807724, 5764801), 14897079);
+    }
 }
@@ -26,9 +26,7 @@ impl BitStream {
 
     // Read the next N bits as an integer, if available.
     pub fn read(&mut self, n: usize) -> Option<u64> {
-        if let Some(tmp) = self.consume(n) {
-            Some(tmp.value())
-        } else {None}
+        self.consume(n).map(|tmp| tmp.value())
     }
 
     // Evaluate the entire BitStream as an integer.
@@ -49,25 +49,40 @@ fn count_increase(x: &Vec<u64>) -> u64 {
 }
 
 // Part-1 solution (raw data)
-fn part1(filename: &str) -> u64 {
-    // Load input from file (one integer per line)
-    let x = common::read_lines_as::<u64>(filename);
-    return count_increase(&x)
+fn part1(x: &Vec<u64>) -> u64 {
+    return count_increase(x)
 }
 
 // Part-2 solution (filtered data)
-fn part2(filename: &str) -> u64 {
-    // Load input from file (one integer per line)
-    let x = common::read_lines_as::<u64>(filename);
-    return count_increase(&filter(&x))
+fn part2(x: &Vec<u64>) -> u64 {
+    return count_increase(&filter(x))
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<u64>;
+    fn parse(input: &str) -> Vec<u64> {common::split_lines_as(input)}
+    fn part1(input: &Vec<u64>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<u64>) -> impl std::fmt::Display {part2(input)}
 }
 
 pub fn solve() {
-    // Test part-1 solver, then apply to real input.
-    assert_eq!(part1("input/test01.txt"), 7);
-    println!("Part1: {}", part1("input/input01.txt"));
+    aoclib::print::<Day>(&common::read_file("input/input01.txt"));
+}
 
-    // Test part-2 solver, then apply to real input.
-    assert_eq!(part2("input/test01.txt"), 5);
-    println!("Part2: {}", part2("input/input01.txt"));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Load input from file (one integer per line)
+        let test = common::read_lines_as::<u64>("input/test01.txt");
+
+        // Test each solver, then apply to real input.
+        assert_eq!(part1(&test), 7);
+        assert_eq!(part2(&test), 5);
+    }
 }
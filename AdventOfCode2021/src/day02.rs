@@ -57,10 +57,10 @@ impl Submarine {
         };
     }
 
-    fn run(filename: &str, part1: bool) -> Submarine {
+    fn run(input: &str, part1: bool) -> Submarine {
         let mut sub = Submarine::new();
-        for line in common::read_lines(filename).iter() {
-            if let Some(cmd) = Command::from_str(&line) {
+        for line in input.lines() {
+            if let Some(cmd) = Command::from_str(line) {
                 if part1 {sub.command1(&cmd)}
                 else     {sub.command2(&cmd)};
             }
@@ -73,20 +73,35 @@ impl Submarine {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {Submarine::run(input, true).score()}
+    fn part2(input: &String) -> impl std::fmt::Display {Submarine::run(input, false).score()}
+}
+
 pub fn solve() {
-    // Run the short example in each mode.
-    let test1 = Submarine::run("input/test02.txt", true);
-    assert_eq!(test1.x, 15);
-    assert_eq!(test1.d, 10);
-    assert_eq!(test1.score(), 150);
-    let test2 = Submarine::run("input/test02.txt", false);
-    assert_eq!(test2.x, 15);
-    assert_eq!(test2.d, 60);
-    assert_eq!(test2.score(), 900);
+    aoclib::print::<Day>(&common::read_file("input/input02.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Run the problem input in each mode.
-    let part1 = Submarine::run("input/input02.txt", true);
-    println!("Part1: {}", part1.score());
-    let part2 = Submarine::run("input/input02.txt", false);
-    println!("Part2: {}", part2.score());
+    #[test]
+    fn examples() {
+        // Run the short example in each mode.
+        let test = common::read_file("input/test02.txt");
+        let test1 = Submarine::run(&test, true);
+        assert_eq!(test1.x, 15);
+        assert_eq!(test1.d, 10);
+        assert_eq!(test1.score(), 150);
+        let test2 = Submarine::run(&test, false);
+        assert_eq!(test2.x, 15);
+        assert_eq!(test2.d, 60);
+        assert_eq!(test2.score(), 900);
+    }
 }
@@ -11,21 +11,21 @@ fn binstring(x: &str) -> u64 {
 }
 
 // Submarine diagnostic report is a matrix of ones and zeros.
-struct Diagnostic {
+pub struct Diagnostic {
     numbits: usize,
     numrows: usize,
     rows: Vec<u64>,
 }
 
 impl Diagnostic {
-    fn new(filename: &str) -> Diagnostic {
-        // Read input file.
-        let raw = common::read_lines(filename);
+    fn new(input: &str) -> Diagnostic {
+        // Split input into lines.
+        let raw = common::split_lines(input);
         let numrows = raw.len();
         assert!(numrows > 0);
         // Parse each row as an integer.
         let numbits = raw[0].len();
-        let rows = raw.iter().map(|x| binstring(&x)).collect();
+        let rows = raw.iter().map(|x| binstring(x)).collect();
         Diagnostic {numbits:numbits, numrows:numrows, rows:rows}
     }
 
@@ -66,8 +66,7 @@ impl Diagnostic {
     // masked bit matches the reference. (Then move to the next bit.)
     fn filter(&self, mask: u64, bref: u64) -> Diagnostic {
         let newrows : Vec<u64> = self.rows.iter()
-            .filter_map(|x| if (x & mask) == bref {Some(x)} else {None})
-            .map(|x| x.clone())
+            .filter(|&x| (x & mask) == bref).copied()
             .collect();
         Diagnostic {numbits: self.numbits - 1, numrows: newrows.len(), rows: newrows}
     }
@@ -101,19 +100,34 @@ impl Diagnostic {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Diagnostic;
+    fn parse(input: &str) -> Diagnostic {Diagnostic::new(input)}
+    fn part1(input: &Diagnostic) -> impl std::fmt::Display {input.power()}
+    fn part2(input: &Diagnostic) -> impl std::fmt::Display {input.life()}
+}
+
 pub fn solve() {
-    let test = Diagnostic::new("input/test03.txt");
-    let input = Diagnostic::new("input/input03.txt");
+    aoclib::print::<Day>(&common::read_file("input/input03.txt"));
+}
 
-    assert_eq!(test.gamma(), 22);
-    assert_eq!(test.epsilon(), 9);
-    assert_eq!(test.power(), 198);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part1: {}", input.power());
+    #[test]
+    fn examples() {
+        let test = Diagnostic::new(&common::read_file("input/test03.txt"));
 
-    assert_eq!(test.oxygen(), 23);
-    assert_eq!(test.carbon(), 10);
-    assert_eq!(test.life(), 230);
+        assert_eq!(test.gamma(), 22);
+        assert_eq!(test.epsilon(), 9);
+        assert_eq!(test.power(), 198);
 
-    println!("Part2: {}", input.life());
+        assert_eq!(test.oxygen(), 23);
+        assert_eq!(test.carbon(), 10);
+        assert_eq!(test.life(), 230);
+    }
 }
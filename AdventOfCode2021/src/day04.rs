@@ -45,11 +45,11 @@ impl Board {
         // Read from iterator until we get BOARD_SIZE valid rows.
         // (Note we may need to skip empty rows during this process.)
         let mut rows = Vec::new();
-        while let Some(line) = iter.next() {
-            if let Some(row) = Board::parse_row(&line) {
+        for line in iter.by_ref() {
+            if let Some(row) = Board::parse_row(line) {
                 rows.push(row)
             }
-            if rows.len() == BOARD_SIZE as usize {
+            if rows.len() == BOARD_SIZE {
                 return Some(Board::parse_board(&rows))
             }
         }
@@ -106,9 +106,9 @@ impl Board {
     }
 }
 
-// Read an input file.
-fn read_input(filename: &str) -> (Vec<Callout>, Vec<Board>) {
-    let lines = common::read_lines(filename);
+// Parse the puzzle input.
+fn read_input(input: &str) -> (Vec<Callout>, Vec<Board>) {
+    let lines = common::split_lines(input);
     let mut line_iter = lines.iter();
     let callouts = common::split_str_as(line_iter.next().unwrap(), ',');
     let boards = Board::read_all(&mut line_iter);
@@ -116,40 +116,63 @@ fn read_input(filename: &str) -> (Vec<Callout>, Vec<Board>) {
 }
 
 // Part 1 solution: Find the earliest winner.
-fn solve_part1(filename: &str) -> (u64, u64) {
-    let (callouts, mut boards) = read_input(filename);
+fn solve_part1(input: &str) -> (u64, u64) {
+    let (callouts, mut boards) = read_input(input);
     for c in callouts.iter() {
         for b in boards.iter_mut() {
-            if b.mark(&c) {return (*c as u64, b.sum_unmarked())}
+            if b.mark(c) {return (*c as u64, b.sum_unmarked())}
         }
     }
     (0, 0)
 }
 
 // Part 1 solution: Find the latest winner.
-fn solve_part2(filename: &str) -> (u64, u64) {
-    let (callouts, mut boards) = read_input(filename);
+fn solve_part2(input: &str) -> (u64, u64) {
+    let (callouts, mut boards) = read_input(input);
     let mut result = (0u64, 0u64);
     for c in callouts.iter() {
         for b in boards.iter_mut() {
-            if b.mark(&c) {result = (*c as u64, b.sum_unmarked())}
+            if b.mark(c) {result = (*c as u64, b.sum_unmarked())}
         }
     }
     result
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {
+        let (callout, sum) = solve_part1(input);
+        callout * sum
+    }
+    fn part2(input: &String) -> impl std::fmt::Display {
+        let (callout, sum) = solve_part2(input);
+        callout * sum
+    }
+}
+
 pub fn solve() {
-    // Part-1 solution (test + full)
-    let test1 = solve_part1("input/test04.txt");
-    assert_eq!(test1.0, 24);    // Final callout
-    assert_eq!(test1.1, 188);   // Unmarked points
-    let part1 = solve_part1("input/input04.txt");
-    println!("Part1: {}", part1.0 * part1.1);
-
-    // Part-2 solution (test + full)
-    let test2 = solve_part2("input/test04.txt");
-    assert_eq!(test2.0, 13);    // Final callout
-    assert_eq!(test2.1, 148);   // Unmarked points
-    let part2 = solve_part2("input/input04.txt");
-    println!("Part2: {}", part2.0 * part2.1);
+    aoclib::print::<Day>(&common::read_file("input/input04.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Part-1 solution (test)
+        let test = common::read_file("input/test04.txt");
+        let test1 = solve_part1(&test);
+        assert_eq!(test1.0, 24);    // Final callout
+        assert_eq!(test1.1, 188);   // Unmarked points
+
+        // Part-2 solution (test)
+        let test2 = solve_part2(&test);
+        assert_eq!(test2.0, 13);    // Final callout
+        assert_eq!(test2.1, 148);   // Unmarked points
+    }
 }
@@ -2,6 +2,8 @@
 /// Copyright 2021 by Alex Utter
 
 #[path = "common.rs"] mod common;
+use aoclib::parse;
+use aoclib::scan;
 use std::cmp::max;
 
 // A point (x,y)
@@ -35,13 +37,9 @@ impl MapCount {
 struct Segment (Point, Point);
 
 impl Segment {
-    fn new(line: &str) -> Option<Segment> {
-        let xy = common::split_numeric(line);
-        if xy.len() == 4 {
-            Some(Segment(
-                Point {x:xy[0] as i64, y:xy[1] as i64},
-                Point {x:xy[2] as i64, y:xy[3] as i64}))
-        } else {None}
+    fn new(line: &str) -> parse::Result<Segment> {
+        let (x1, y1, x2, y2) = scan!(line, "{},{} -> {},{}" => i64, i64, i64, i64)?;
+        Ok(Segment(Point {x:x1, y:y1}, Point {x:x2, y:y2}))
     }
 
     fn is_hv(&self) -> bool {
@@ -68,9 +66,9 @@ impl Segment {
 
 // Read input file and return a vector of line segments.
 // If "HV" is set, return only horizontal and vertical lines.
-fn read_input(filename: &str, hv: bool) -> Vec<Segment> {
-    let lines = common::read_lines(filename);
-    let segs = lines.iter().filter_map(|x| Segment::new(x));
+fn read_input(input: &str, hv: bool) -> Vec<Segment> {
+    let segs = parse::lines(input, Segment::new)
+        .unwrap_or_else(|e| panic!("{}", e)).into_iter();
     if hv {
         let keep_if_hv = |x:Segment| if x.is_hv() {Some(x)} else {None};
         segs.filter_map(keep_if_hv).collect()   // Only horiz/vert
@@ -86,18 +84,35 @@ fn count_overlap(segs: &Vec<Segment>, mapsize: usize) -> usize {
     map.count2()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {count_overlap(&read_input(input, true), 1000)}
+    fn part2(input: &String) -> impl std::fmt::Display {count_overlap(&read_input(input, false), 1000)}
+}
+
 pub fn solve() {
-    // Part 1 only considers horizontal and vertical lines.
-    let test1 = read_input("input/test05.txt", true);
-    assert_eq!(test1.len(), 6);                 // Check H/V filter
-    assert_eq!(count_overlap(&test1, 10), 5);   // Check overlap
-    let data1 = read_input("input/input05.txt", true);
-    println!("Part1: {}", count_overlap(&data1, 1000));
-
-    // Part 2 considers lines of all types.
-    let test2 = read_input("input/test05.txt", false);
-    assert_eq!(test2.len(), 10);
-    assert_eq!(count_overlap(&test2, 10), 12);
-    let data2 = read_input("input/input05.txt", false);
-    println!("Part2: {}", count_overlap(&data2, 1000));
+    aoclib::print::<Day>(&common::read_file("input/input05.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Part 1 only considers horizontal and vertical lines.
+        let test = common::read_file("input/test05.txt");
+        let test1 = read_input(&test, true);
+        assert_eq!(test1.len(), 6);                 // Check H/V filter
+        assert_eq!(count_overlap(&test1, 10), 5);   // Check overlap
+
+        // Part 2 considers lines of all types.
+        let test2 = read_input(&test, false);
+        assert_eq!(test2.len(), 10);
+        assert_eq!(count_overlap(&test2, 10), 12);
+    }
 }
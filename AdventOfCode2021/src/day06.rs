@@ -8,7 +8,7 @@ const DAYS_SPAWN0: usize = 9;   // Days from birth to first spawning
 const DAYS_SPAWN1: usize = 7;   // Days between subsequent spawnings
 
 // State vector counts fish with each possible timer state.
-struct FishCount {
+pub struct FishCount {
     count: [u64; DAYS_SPAWN0],
 }
 
@@ -26,9 +26,7 @@ impl FishCount {
     // Increment state by one day.
     fn next(&self) -> FishCount {
         let mut count = [0u64; DAYS_SPAWN0];
-        for n in 0..DAYS_SPAWN0-1 {
-            count[n] = self.count[n+1];
-        }
+        count[..DAYS_SPAWN0-1].copy_from_slice(&self.count[1..]);
         count[DAYS_SPAWN0-1] += self.count[0];
         count[DAYS_SPAWN1-1] += self.count[0];
         FishCount {count:count}
@@ -45,23 +43,38 @@ impl FishCount {
     }
 }
 
-fn read_file(filename: &str) -> Vec<FishCount> {
-    let lines = common::read_lines(filename);
+fn read_input(input: &str) -> Vec<FishCount> {
+    let lines = common::split_lines(input);
     lines.iter().map(|line| FishCount::new(line)).collect()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<FishCount>;
+    fn parse(input: &str) -> Vec<FishCount> {read_input(input)}
+    fn part1(input: &Vec<FishCount>) -> impl std::fmt::Display {input[0].advance(80).total()}
+    fn part2(input: &Vec<FishCount>) -> impl std::fmt::Display {input[0].advance(256).total()}
+}
+
 pub fn solve() {
-    // Test reference has expected state for Day 0, Day 1, ...
-    let test = read_file("input/test06.txt");
-    for n in 1..test.len() {
-        assert_eq!(test[n].total(), test[n-1].next().total());
-        assert_eq!(test[n].total(), test[0].advance(n).total());
-    }
-    assert_eq!(test[0].advance(80).total(), 5934);
-    assert_eq!(test[0].advance(256).total(), 26984457539);
+    aoclib::print::<Day>(&common::read_file("input/input06.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Real input.
-    let data = read_file("input/input06.txt");
-    println!("Part1: {}", data[0].advance(80).total());
-    println!("Part2: {}", data[0].advance(256).total());
+    #[test]
+    fn examples() {
+        // Test reference has expected state for Day 0, Day 1, ...
+        let test = read_input(&common::read_file("input/test06.txt"));
+        for n in 1..test.len() {
+            assert_eq!(test[n].total(), test[n-1].next().total());
+            assert_eq!(test[n].total(), test[0].advance(n).total());
+        }
+        assert_eq!(test[0].advance(80).total(), 5934);
+        assert_eq!(test[0].advance(256).total(), 26984457539);
+    }
 }
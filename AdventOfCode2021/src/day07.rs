@@ -3,13 +3,13 @@
 
 #[path = "common.rs"] mod common;
 
-struct Crabs {
+pub struct Crabs {
     pos: Vec<i64>,
 }
 
 impl Crabs {
-    fn new(filename: &str) -> Crabs {
-        let lines = common::read_lines(filename);
+    fn new(input: &str) -> Crabs {
+        let lines = common::split_lines(input);
         Crabs {pos: common::split_str_as(&lines[0], ',')}
     }
 
@@ -38,24 +38,37 @@ impl Crabs {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Crabs;
+    fn parse(input: &str) -> Crabs {Crabs::new(input)}
+    fn part1(input: &Crabs) -> impl std::fmt::Display {input.align_best()}
+    fn part2(input: &Crabs) -> impl std::fmt::Display {input.accel_best()}
+}
+
 pub fn solve() {
-    // Test reference alignments.
-    let test = Crabs::new("input/test07.txt");
-    assert_eq!(test.align(1), 41);
-    assert_eq!(test.align(2), 37);
-    assert_eq!(test.align(3), 39);
-    assert_eq!(test.align(10), 71);
-    assert_eq!(test.align_best(), 37);
-
-    // Real input.
-    let data = Crabs::new("input/input07.txt");
-    println!("Part1: {}", data.align_best());
-
-    // Tests with scaling costs.
-    assert_eq!(test.accel(2), 206);
-    assert_eq!(test.accel(5), 168);
-    assert_eq!(test.accel_best(), 168);
-
-    // Real input.
-    println!("Part2: {}", data.accel_best());
+    aoclib::print::<Day>(&common::read_file("input/input07.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Test reference alignments.
+        let test = Crabs::new(&common::read_file("input/test07.txt"));
+        assert_eq!(test.align(1), 41);
+        assert_eq!(test.align(2), 37);
+        assert_eq!(test.align(3), 39);
+        assert_eq!(test.align(10), 71);
+        assert_eq!(test.align_best(), 37);
+
+        // Tests with scaling costs.
+        assert_eq!(test.accel(2), 206);
+        assert_eq!(test.accel(5), 168);
+        assert_eq!(test.accel_best(), 168);
+    }
 }
@@ -45,7 +45,7 @@ impl Numeral {
     }
 }
 
-struct Display {
+pub struct Display {
     refs: Vec<Numeral>,
     disp: Vec<Numeral>,
 }
@@ -124,32 +124,48 @@ fn part2(x: &Vec<Display>) -> u64 {
     x.iter().map(|x| x.solve()).sum()
 }
 
-fn read_and_parse(filename: &str) -> Vec<Display> {
-    let lines = common::read_lines(filename);
-    lines.iter().map(|x| Display::new(&x)).collect()
+fn read_and_parse(input: &str) -> Vec<Display> {
+    let lines = common::split_lines(input);
+    lines.iter().map(|x| Display::new(x)).collect()
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<Display>;
+    fn parse(input: &str) -> Vec<Display> {read_and_parse(input)}
+    fn part1(input: &Vec<Display>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<Display>) -> impl std::fmt::Display {part2(input)}
 }
 
 pub fn solve() {
-    let test = read_and_parse("input/test08.txt");
-    let data = read_and_parse("input/input08.txt");
-
-    assert_eq!(part1(&test), 26);
-    println!("Part1: {}", part1(&data));
-
-    let test2 = Display::new(
-        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf");
-    assert_eq!(test2.solve(), 5353);
-    assert_eq!(test[0].solve(), 8394);
-    assert_eq!(test[1].solve(), 9781);
-    assert_eq!(test[2].solve(), 1197);
-    assert_eq!(test[3].solve(), 9361);
-    assert_eq!(test[4].solve(), 4873);
-    assert_eq!(test[5].solve(), 8418);
-    assert_eq!(test[6].solve(), 4548);
-    assert_eq!(test[7].solve(), 1625);
-    assert_eq!(test[8].solve(), 8717);
-    assert_eq!(test[9].solve(), 4315);
-
-    assert_eq!(part2(&test), 61229);
-    println!("Part2: {}", part2(&data));
+    aoclib::print::<Day>(&common::read_file("input/input08.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test = read_and_parse(&common::read_file("input/test08.txt"));
+        assert_eq!(part1(&test), 26);
+
+        let test2 = Display::new(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf");
+        assert_eq!(test2.solve(), 5353);
+        assert_eq!(test[0].solve(), 8394);
+        assert_eq!(test[1].solve(), 9781);
+        assert_eq!(test[2].solve(), 1197);
+        assert_eq!(test[3].solve(), 9361);
+        assert_eq!(test[4].solve(), 4873);
+        assert_eq!(test[5].solve(), 8418);
+        assert_eq!(test[6].solve(), 4548);
+        assert_eq!(test[7].solve(), 1625);
+        assert_eq!(test[8].solve(), 8717);
+        assert_eq!(test[9].solve(), 4315);
+
+        assert_eq!(part2(&test), 61229);
+    }
 }
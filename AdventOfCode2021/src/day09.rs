@@ -7,13 +7,13 @@ use std::collections::HashSet;
 use grid::RowCol;
 
 type Height = u8;
-struct HeightMap {
+pub struct HeightMap {
     h: grid::Grid<Height>,
 }
 
 impl HeightMap {
-    fn new(filename: &str) -> HeightMap {
-        HeightMap { h: grid::read_grid(filename) }
+    fn new(input: &str) -> HeightMap {
+        HeightMap { h: grid::parse_grid(input) }
     }
 
     // Fetch map height with "10" for out-of-bounds
@@ -75,13 +75,28 @@ impl HeightMap {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = HeightMap;
+    fn parse(input: &str) -> HeightMap {HeightMap::new(input)}
+    fn part1(input: &HeightMap) -> impl std::fmt::Display {input.part1()}
+    fn part2(input: &HeightMap) -> impl std::fmt::Display {input.part2()}
+}
+
 pub fn solve() {
-    let test = HeightMap::new("input/test09.txt");
-    let data = HeightMap::new("input/input09.txt");
+    aoclib::print::<Day>(&common::read_file("input/input09.txt"));
+}
 
-    assert_eq!(test.part1(), 15);
-    println!("Part1: {}", data.part1());
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(test.part2(), 1134);
-    println!("Part2: {}", data.part2());
+    #[test]
+    fn examples() {
+        let test = HeightMap::new(&common::read_file("input/test09.txt"));
+        assert_eq!(test.part1(), 15);
+        assert_eq!(test.part2(), 1134);
+    }
 }
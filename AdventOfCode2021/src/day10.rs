@@ -27,7 +27,7 @@ fn syntax_score(line: &str) -> u64 {
             stack.push(*close);
         } else if let Some(pts) = CLOSE2POINTS.get(&ch) {
             let expect = stack.pop().unwrap_or('*');
-            if !(ch == expect) {return *pts;}
+            if ch != expect {return *pts;}
         }
     }
     return 0    // Reached end without a corrupted character
@@ -43,7 +43,7 @@ fn complete_score(line: &str) -> Option<u64> {
             stack.push(*close);
         } else {
             let expect = stack.pop().unwrap_or('*');
-            if !(ch == expect) {return None;}
+            if ch != expect {return None;}
         }
     }
     // Anything leftover in the stack is our autocomplete sequence.
@@ -56,30 +56,46 @@ fn complete_score(line: &str) -> Option<u64> {
 
 // Part-1 score is the sum syntax scores for each corrupt line.
 fn part1(lines: &Vec<String>) -> u64 {
-    lines.iter().map(|x| syntax_score(&x)).sum()
+    lines.iter().map(|x| syntax_score(x)).sum()
 }
 
 // Part-2 score is the median of completion scores for each line.
 fn part2(lines: &Vec<String>) -> u64 {
     let mut scores: Vec<u64> = lines.iter()
-        .filter_map(|x| complete_score(&x)).collect();
+        .filter_map(|x| complete_score(x)).collect();
     scores.sort();
     scores[(scores.len()-1)/2]
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_lines(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {part2(input)}
+}
+
 pub fn solve() {
-    let test = common::read_lines("input/test10.txt");
-    let data = common::read_lines("input/input10.txt");
+    aoclib::print::<Day>(&common::read_file("input/input10.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(part1(&test), 26397);
-    println!("Part1: {}", part1(&data));
+    #[test]
+    fn examples() {
+        let test = common::read_lines("input/test10.txt");
+        assert_eq!(part1(&test), 26397);
 
-    assert_eq!(complete_score(&test[0]), Some(288957));
-    assert_eq!(complete_score(&test[1]), Some(5566));
-    assert_eq!(complete_score(&test[2]), None);
-    assert_eq!(complete_score(&test[3]), Some(1480781));
-    assert_eq!(complete_score(&test[6]), Some(995444));
-    assert_eq!(complete_score(&test[9]), Some(294));
-    assert_eq!(part2(&test), 288957);
-    println!("Part2: {}", part2(&data));
+        assert_eq!(complete_score(&test[0]), Some(288957));
+        assert_eq!(complete_score(&test[1]), Some(5566));
+        assert_eq!(complete_score(&test[2]), None);
+        assert_eq!(complete_score(&test[3]), Some(1480781));
+        assert_eq!(complete_score(&test[6]), Some(995444));
+        assert_eq!(complete_score(&test[9]), Some(294));
+        assert_eq!(part2(&test), 288957);
+    }
 }
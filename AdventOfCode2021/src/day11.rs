@@ -5,13 +5,13 @@
 #[path = "grid.rs"] mod grid;
 
 #[derive(Clone)]
-struct Cave {
+pub struct Cave {
     crabs: grid::Grid<u8>,
 }
 
 impl Cave {
-    fn new(filename: &str) -> Cave {
-        Cave { crabs: grid::read_grid(filename) }
+    fn new(input: &str) -> Cave {
+        Cave { crabs: grid::parse_grid(input) }
     }
 
     fn explode(&mut self, crab: &grid::RowCol) -> u64 {
@@ -61,15 +61,31 @@ impl Cave {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Cave;
+    fn parse(input: &str) -> Cave {Cave::new(input)}
+    fn part1(input: &Cave) -> impl std::fmt::Display {input.part1(100)}
+    fn part2(input: &Cave) -> impl std::fmt::Display {input.part2()}
+}
+
 pub fn solve() {
-    let test = Cave::new("input/test11.txt");
-    let data = Cave::new("input/input11.txt");
+    aoclib::print::<Day>(&common::read_file("input/input11.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(test.part1(2), 35);
-    assert_eq!(test.part1(10), 204);
-    assert_eq!(test.part1(100), 1656);
-    println!("Part1: {}", data.part1(100));
+    #[test]
+    fn examples() {
+        let test = Cave::new(&common::read_file("input/test11.txt"));
 
-    assert_eq!(test.part2(), 195);
-    println!("Part2: {}", data.part2());
+        assert_eq!(test.part1(2), 35);
+        assert_eq!(test.part1(10), 204);
+        assert_eq!(test.part1(100), 1656);
+        assert_eq!(test.part2(), 195);
+    }
 }
@@ -22,7 +22,7 @@ impl Room {
     }
 }
 
-struct Cave {
+pub struct Cave {
     labels: HashMap<String, usize>, // Map labels to indices
     rooms: Vec<Room>,               // Vector of Rooms
     start: usize,
@@ -30,7 +30,7 @@ struct Cave {
 }
 
 impl Cave {
-    fn new(filename: &str) -> Cave {
+    fn new(input: &str) -> Cave {
         let mut cave = Cave {
             labels: HashMap::new(),
             rooms: Vec::new(),
@@ -38,7 +38,7 @@ impl Cave {
             end: 0,
         };
         // Each line indicates a connection between two named rooms.
-        let lines = common::read_lines(filename);
+        let lines = common::split_lines(input);
         for line in lines.iter() {
             let words: Vec<&str> = line.split('-').collect();
             assert_eq!(words.len(), 2);
@@ -104,20 +104,36 @@ impl Cave {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Cave;
+    fn parse(input: &str) -> Cave {Cave::new(input)}
+    fn part1(input: &Cave) -> impl std::fmt::Display {input.part1()}
+    fn part2(input: &Cave) -> impl std::fmt::Display {input.part2()}
+}
+
 pub fn solve() {
-    let test1 = Cave::new("input/test12a.txt");
-    let test2 = Cave::new("input/test12b.txt");
-    let test3 = Cave::new("input/test12c.txt");
-    let input = Cave::new("input/input12.txt");
-
-    assert_eq!(test1.part1(), 10);
-    assert_eq!(test2.part1(), 19);
-    assert_eq!(test3.part1(), 226);
-    println!("Max rooms {}", input.rooms.len()); //???
-    println!("Part1: {}", input.part1());
-
-    assert_eq!(test1.part2(), 36);
-    assert_eq!(test2.part2(), 103);
-    assert_eq!(test3.part2(), 3509);
-    println!("Part2: {}", input.part2());
+    aoclib::print::<Day>(&common::read_file("input/input12.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test1 = Cave::new(&common::read_file("input/test12a.txt"));
+        let test2 = Cave::new(&common::read_file("input/test12b.txt"));
+        let test3 = Cave::new(&common::read_file("input/test12c.txt"));
+
+        assert_eq!(test1.part1(), 10);
+        assert_eq!(test2.part1(), 19);
+        assert_eq!(test3.part1(), 226);
+
+        assert_eq!(test1.part2(), 36);
+        assert_eq!(test2.part2(), 103);
+        assert_eq!(test3.part2(), 3509);
+    }
 }
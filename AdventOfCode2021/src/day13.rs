@@ -25,7 +25,7 @@ impl Paper {
             if xy.len() == 2 {
                 // Each valid line is added to the list.
                 paper.dots.insert(Dot {x:xy[0], y:xy[1]} );
-            } else if line.len() > 0 {
+            } else if !line.is_empty() {
                 // Note index of first command line.
                 paper.cmd0 = n; break;
             }
@@ -69,40 +69,60 @@ impl Paper {
     }
 
     // Print the current state.
-    fn print(&self) {
+    fn render(&self) -> String {
         let cols = self.dots.iter().map(|d| d.x).max().unwrap_or(0) + 1;
         let rows = self.dots.iter().map(|d| d.y).max().unwrap_or(0) + 1;
+        let mut result = String::new();
         for r in 0..rows {
             let row: String = (0..cols)
                 .map(|c| Dot {x:c, y:r} )           // Create Dot object
                 .map(|d| self.dots.contains(&d))    // Check current state
                 .map(|h| if h {'#'} else {' '})     // Highlight this cell?
                 .collect();
-            println!("{}", row);
+            result.push_str(&row);
+            result.push('\n');
         }
+        result
+    }
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<String>;
+    fn parse(input: &str) -> Vec<String> {common::split_lines(input)}
+    fn part1(input: &Vec<String>) -> impl std::fmt::Display {
+        // Part 1 executes only the first fold.
+        let paper = Paper::new(input);
+        paper.fold_cmd(&input[paper.cmd0]).count()
+    }
+    fn part2(input: &Vec<String>) -> impl std::fmt::Display {
+        // Part 2 executes all the folds and prints the result.
+        let mut paper = Paper::new(input);
+        for cmd in input[paper.cmd0..].iter() {
+            paper = paper.fold_cmd(cmd);
+        }
+        paper.render()
     }
 }
 
 pub fn solve() {
-    let test = common::read_lines("input/test13.txt");
-    let data = common::read_lines("input/input13.txt");
-    let ptest = Paper::new(&test);
-    let pdata = Paper::new(&data);
+    aoclib::print::<Day>(&common::read_file("input/input13.txt"));
+}
 
-    // Tests using the example input
-    assert_eq!(ptest.count(), 18);
-    assert_eq!(ptest.fold_y(7).count(), 17);
-    assert_eq!(ptest.fold_y(7).fold_x(5).count(), 16);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Part 1 executes only the first fold.
-    let cmds = &data[pdata.cmd0..];
-    let part1 = pdata.fold_cmd(&cmds[0]);
-    println!("Part1: {}", part1.count());
+    #[test]
+    fn examples() {
+        let test = common::read_lines("input/test13.txt");
+        let ptest = Paper::new(&test);
 
-    // Part 2 executes the remaining folds and prints the result.
-    let mut part2 = pdata.clone();
-    for cmd in cmds.iter() {
-        part2 = part2.fold_cmd(&cmd);
+        // Tests using the example input
+        assert_eq!(ptest.count(), 18);
+        assert_eq!(ptest.fold_y(7).count(), 17);
+        assert_eq!(ptest.fold_y(7).fold_x(5).count(), 16);
     }
-    part2.print();
 }
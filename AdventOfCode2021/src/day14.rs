@@ -80,18 +80,18 @@ impl Rule {
     }
 }
 
-struct RuleSet {
+pub struct RuleSet {
     init:   Polymer,
     rules:  Vec<Rule>,
 }
 
 impl RuleSet {
-    fn new(filename: &str) -> RuleSet {
-        let lines = common::read_lines(filename);
+    fn new(input: &str) -> RuleSet {
+        let lines = common::split_lines(input);
         assert!(lines.len() >= 3);
         RuleSet {
             init:   Polymer::new(&lines[0]),
-            rules:  lines[2..].iter().map(|l| Rule::new(&l)).collect(),
+            rules:  lines[2..].iter().map(|l| Rule::new(l)).collect(),
         }
     }
 
@@ -110,28 +110,44 @@ impl RuleSet {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = RuleSet;
+    fn parse(input: &str) -> RuleSet {RuleSet::new(input)}
+    fn part1(input: &RuleSet) -> impl std::fmt::Display {input.iterate(10).score()}
+    fn part2(input: &RuleSet) -> impl std::fmt::Display {input.iterate(40).score()}
+}
+
 pub fn solve() {
-    let test = RuleSet::new("input/test14.txt");
-    let data = RuleSet::new("input/input14.txt");
-
-    assert_eq!(test.iterate(0), Polymer::new("NNCB"));
-    assert_eq!(test.iterate(1), Polymer::new("NCNBCHB"));
-    assert_eq!(test.iterate(2), Polymer::new("NBCCNBBBCBHCB"));
-    assert_eq!(test.iterate(3), Polymer::new("NBBBCNCCNBBNBNBBCHBHHBCHB"));
-    assert_eq!(test.iterate(4), Polymer::new("NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"));
-    assert_eq!(test.iterate(5).len(), 97);
-    let test10 = test.iterate(10);
-    assert_eq!(test10.len(), 3073);
-    assert_eq!(test10.count(&'B'), 1749);
-    assert_eq!(test10.count(&'C'), 298);
-    assert_eq!(test10.count(&'H'), 161);
-    assert_eq!(test10.count(&'N'), 865);
-    assert_eq!(test10.score(), 1588);
-    let test40 = test.iterate(40);
-    assert_eq!(test40.count(&'B'), 2192039569602);
-    assert_eq!(test40.count(&'H'), 3849876073);
-    assert_eq!(test40.score(), 2188189693529);
-
-    println!("Part1: {}", data.iterate(10).score());
-    println!("Part2: {}", data.iterate(40).score());
+    aoclib::print::<Day>(&common::read_file("input/input14.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test = RuleSet::new(&common::read_file("input/test14.txt"));
+
+        assert_eq!(test.iterate(0), Polymer::new("NNCB"));
+        assert_eq!(test.iterate(1), Polymer::new("NCNBCHB"));
+        assert_eq!(test.iterate(2), Polymer::new("NBCCNBBBCBHCB"));
+        assert_eq!(test.iterate(3), Polymer::new("NBBBCNCCNBBNBNBBCHBHHBCHB"));
+        assert_eq!(test.iterate(4), Polymer::new("NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"));
+        assert_eq!(test.iterate(5).len(), 97);
+        let test10 = test.iterate(10);
+        assert_eq!(test10.len(), 3073);
+        assert_eq!(test10.count(&'B'), 1749);
+        assert_eq!(test10.count(&'C'), 298);
+        assert_eq!(test10.count(&'H'), 161);
+        assert_eq!(test10.count(&'N'), 865);
+        assert_eq!(test10.score(), 1588);
+        let test40 = test.iterate(40);
+        assert_eq!(test40.count(&'B'), 2192039569602);
+        assert_eq!(test40.count(&'H'), 3849876073);
+        assert_eq!(test40.score(), 2188189693529);
+    }
 }
@@ -3,7 +3,7 @@
 
 #[path = "common.rs"] mod common;
 
-struct Region {
+pub struct Region {
     xmin: i64,
     xmax: i64,
     ymin: i64,
@@ -97,24 +97,43 @@ fn search_part2(r: &Region) -> usize {
     for dx in 1..r.xmax+1 {
         for dy in r.ymin..r.ymin.abs() {
             let p = Projectile::new(dx, dy);
-            if let Some(_) = p.part1(r) {count += 1}
+            if p.part1(r).is_some() {count += 1}
         }
     }
     count
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Region;
+    fn parse(_input: &str) -> Region {
+        // Input for this problem is a single target area.
+        Region::new(57, 116, -198, -148)
+    }
+    fn part1(input: &Region) -> impl std::fmt::Display {search_part1(input)}
+    fn part2(input: &Region) -> impl std::fmt::Display {search_part2(input)}
+}
+
 pub fn solve() {
-    let test = Region::new(20, 30, -10, -5);
-    let data = Region::new(57, 116, -198, -148);
-
-    assert_eq!(Projectile::new(7,2).part1(&test), Some(3));
-    assert_eq!(Projectile::new(6,3).part1(&test), Some(6));
-    assert_eq!(Projectile::new(9,0).part1(&test), Some(0));
-    assert_eq!(Projectile::new(17,-4).part1(&test), None);
-    assert_eq!(Projectile::new(6,9).part1(&test), Some(45));
-    assert_eq!(search_part1(&test), 45);
-    assert_eq!(search_part2(&test), 112);
-
-    println!("Part1: {}", search_part1(&data));
-    println!("Part2: {}", search_part2(&data));
+    aoclib::print::<Day>("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test = Region::new(20, 30, -10, -5);
+
+        assert_eq!(Projectile::new(7,2).part1(&test), Some(3));
+        assert_eq!(Projectile::new(6,3).part1(&test), Some(6));
+        assert_eq!(Projectile::new(9,0).part1(&test), Some(0));
+        assert_eq!(Projectile::new(17,-4).part1(&test), None);
+        assert_eq!(Projectile::new(6,9).part1(&test), Some(45));
+        assert_eq!(search_part1(&test), 45);
+        assert_eq!(search_part2(&test), 112);
+    }
 }
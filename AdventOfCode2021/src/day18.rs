@@ -32,7 +32,7 @@ enum Item {
 
 impl Item {
     fn new(x: &str) -> Option<Item> {
-        if let Some(n) = x.parse::<u64>().ok() {
+        if let Ok(n) = x.parse::<u64>() {
             Some(Item::Simple(n))
         } else if let Some(n) = find_comma(x) {
             let l = Item::new(&x[1..n]).unwrap();
@@ -106,7 +106,7 @@ impl fmt::Debug for Item {
 }
 
 #[derive(Clone, Eq, PartialEq)]
-struct Pair {
+pub struct Pair {
     l: Item,    // Left component
     r: Item,    // Right component
 }
@@ -178,7 +178,7 @@ impl fmt::Debug for Pair {
 
 fn sum<'a>(mut iter: impl Iterator<Item=&'a Pair>) -> Pair {
     let mut sum = iter.next().unwrap().clone();
-    while let Some(x) = iter.next() {
+    for x in iter {
         sum = sum.add(x);
     }
     sum
@@ -198,85 +198,98 @@ fn largest_mag(data: &Vec<Pair>) -> u64 {
     best
 }
 
-fn read_file(filename: &str) -> Vec<Pair> {
-    let lines = common::read_lines(filename);
+fn read_input(input: &str) -> Vec<Pair> {
+    let lines = common::split_lines(input);
     lines.iter().map(|x| Pair::new(x)).collect()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Vec<Pair>;
+    fn parse(input: &str) -> Vec<Pair> {read_input(input)}
+    fn part1(input: &Vec<Pair>) -> impl std::fmt::Display {sum(input.iter()).magnitude()}
+    fn part2(input: &Vec<Pair>) -> impl std::fmt::Display {largest_mag(input)}
+}
+
 pub fn solve() {
-    // Test each of the reduction examples.
-    assert_eq!(
-        Pair::new("[[[[[9,8],1],2],3],4]").reduce(),
-        Pair::new("[[[[0,9],2],3],4]"));
-    assert_eq!(
-        Pair::new("[7,[6,[5,[4,[3,2]]]]]").reduce(),
-        Pair::new("[7,[6,[5,[7,0]]]]"));
-    assert_eq!(
-        Pair::new("[[6,[5,[4,[3,2]]]],1]").reduce(),
-        Pair::new("[[6,[5,[7,0]]],3]"));
-    assert_eq!(
-        Pair::new("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").reduce(),
-        Pair::new("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"));
-
-    // Test the simple addition example.
-    assert_eq!(
-        Pair::new("[[[[4,3],4],4],[7,[[8,4],9]]]").add(&Pair::new("[1,1]")),
-        Pair::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
-
-    // Test each list-summation example.
-    let test1 = vec!(
-        Pair::new("[1,1]"), Pair::new("[2,2]"), Pair::new("[3,3]"),
-        Pair::new("[4,4]"), Pair::new("[5,5]"), Pair::new("[6,6]"));
-    let test2 = read_file("input/test18a.txt");
-    let test3 = read_file("input/test18b.txt");
-
-    assert_eq!(sum(test1[0..4].iter()),
-        Pair::new("[[[[1,1],[2,2]],[3,3]],[4,4]]"));
-    assert_eq!(sum(test1[0..5].iter()),
-        Pair::new("[[[[3,0],[5,3]],[4,4]],[5,5]]"));
-    assert_eq!(sum(test1[0..6].iter()),
-        Pair::new("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
-    assert_eq!(sum(test2[0..2].iter()),
-        Pair::new("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"));
-    assert_eq!(sum(test2[0..3].iter()),
-        Pair::new("[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]"));
-    assert_eq!(sum(test2[0..4].iter()),
-        Pair::new("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]"));
-    assert_eq!(sum(test2[0..5].iter()),
-        Pair::new("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]"));
-    assert_eq!(sum(test2[0..6].iter()),
-        Pair::new("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]"));
-    assert_eq!(sum(test2[0..7].iter()),
-        Pair::new("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]"));
-    assert_eq!(sum(test2[0..8].iter()),
-        Pair::new("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]"));
-    assert_eq!(sum(test2[0..9].iter()),
-        Pair::new("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]"));
-    assert_eq!(sum(test2.iter()),
-        Pair::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"));
-    assert_eq!(sum(test3.iter()),
-        Pair::new("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"));
-
-    // Example magnitude calculations.
-    assert_eq!(143,
-        Pair::new("[[1,2],[[3,4],5]]").magnitude());
-    assert_eq!(1384,
-        Pair::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude());
-    assert_eq!(445,
-        Pair::new("[[[[1,1],[2,2]],[3,3]],[4,4]]").magnitude());
-    assert_eq!(791,
-        Pair::new("[[[[3,0],[5,3]],[4,4]],[5,5]]").magnitude());
-    assert_eq!(1137,
-        Pair::new("[[[[5,0],[7,4]],[5,5]],[6,6]]").magnitude());
-    assert_eq!(3488,
-        Pair::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude());
-    assert_eq!(4140, sum(test3.iter()).magnitude());
-
-    // Solve the Part-1 homework problem.
-    let data = read_file("input/input18.txt");
-    println!("Part1: {}", sum(data.iter()).magnitude());
-
-    // Solve the Part-2 homework problem.
-    assert_eq!(3993, largest_mag(&test3));
-    println!("Part2: {}", largest_mag(&data));
+    aoclib::print::<Day>(&common::read_file("input/input18.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Test each of the reduction examples.
+        assert_eq!(
+            Pair::new("[[[[[9,8],1],2],3],4]").reduce(),
+            Pair::new("[[[[0,9],2],3],4]"));
+        assert_eq!(
+            Pair::new("[7,[6,[5,[4,[3,2]]]]]").reduce(),
+            Pair::new("[7,[6,[5,[7,0]]]]"));
+        assert_eq!(
+            Pair::new("[[6,[5,[4,[3,2]]]],1]").reduce(),
+            Pair::new("[[6,[5,[7,0]]],3]"));
+        assert_eq!(
+            Pair::new("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").reduce(),
+            Pair::new("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"));
+
+        // Test the simple addition example.
+        assert_eq!(
+            Pair::new("[[[[4,3],4],4],[7,[[8,4],9]]]").add(&Pair::new("[1,1]")),
+            Pair::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+
+        // Test each list-summation example.
+        let test1 = [Pair::new("[1,1]"), Pair::new("[2,2]"), Pair::new("[3,3]"),
+            Pair::new("[4,4]"), Pair::new("[5,5]"), Pair::new("[6,6]")];
+        let test2 = read_input(&common::read_file("input/test18a.txt"));
+        let test3 = read_input(&common::read_file("input/test18b.txt"));
+
+        assert_eq!(sum(test1[0..4].iter()),
+            Pair::new("[[[[1,1],[2,2]],[3,3]],[4,4]]"));
+        assert_eq!(sum(test1[0..5].iter()),
+            Pair::new("[[[[3,0],[5,3]],[4,4]],[5,5]]"));
+        assert_eq!(sum(test1[0..6].iter()),
+            Pair::new("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
+        assert_eq!(sum(test2[0..2].iter()),
+            Pair::new("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"));
+        assert_eq!(sum(test2[0..3].iter()),
+            Pair::new("[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]"));
+        assert_eq!(sum(test2[0..4].iter()),
+            Pair::new("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]"));
+        assert_eq!(sum(test2[0..5].iter()),
+            Pair::new("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]"));
+        assert_eq!(sum(test2[0..6].iter()),
+            Pair::new("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]"));
+        assert_eq!(sum(test2[0..7].iter()),
+            Pair::new("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]"));
+        assert_eq!(sum(test2[0..8].iter()),
+            Pair::new("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]"));
+        assert_eq!(sum(test2[0..9].iter()),
+            Pair::new("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]"));
+        assert_eq!(sum(test2.iter()),
+            Pair::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"));
+        assert_eq!(sum(test3.iter()),
+            Pair::new("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"));
+
+        // Example magnitude calculations.
+        assert_eq!(143,
+            Pair::new("[[1,2],[[3,4],5]]").magnitude());
+        assert_eq!(1384,
+            Pair::new("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").magnitude());
+        assert_eq!(445,
+            Pair::new("[[[[1,1],[2,2]],[3,3]],[4,4]]").magnitude());
+        assert_eq!(791,
+            Pair::new("[[[[3,0],[5,3]],[4,4]],[5,5]]").magnitude());
+        assert_eq!(1137,
+            Pair::new("[[[[5,0],[7,4]],[5,5]],[6,6]]").magnitude());
+        assert_eq!(3488,
+            Pair::new("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude());
+        assert_eq!(4140, sum(test3.iter()).magnitude());
+
+        assert_eq!(3993, largest_mag(&test3));
+    }
 }
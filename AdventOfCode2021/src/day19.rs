@@ -2,6 +2,7 @@
 /// Copyright 2021 by Alex Utter
 
 #[path = "common.rs"] mod common;
+use aoclib::geom::{Vec3, ROTATIONS};
 use std::cmp::max;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -9,88 +10,20 @@ use std::collections::HashSet;
 const VERBOSE:bool = false;
 
 // An XYZ coordinate (usually relative coordinates of a Beacon)
-#[derive(Clone, Eq, Hash, PartialEq)]
-struct Xyz {
-    x: i64,
-    y: i64,
-    z: i64,
-}
-
-impl Xyz {
-    fn new() -> Xyz {
-        Xyz { x:0, y:0, z:0 }
-    }
-
-    fn from(line:&str) -> Xyz {
-        let xyz = common::split_str_as::<i64>(line, ',');
-        assert_eq!(xyz.len(), 3);
-        Xyz { x:xyz[0], y:xyz[1], z:xyz[2] }
-    }
-
-    // Squared-distance to another point.
-    fn dist_sq(&self, arg:&Xyz) -> u64 {
-        let dx = (self.x - arg.x).abs() as u64;
-        let dy = (self.y - arg.y).abs() as u64;
-        let dz = (self.z - arg.z).abs() as u64;
-        dx*dx + dy*dy + dz*dz
-    }
-
-    // Manhattan-distance to another point.
-    fn dist_man(&self, arg:&Xyz) -> u64 {
-        let dx = (self.x - arg.x).abs() as u64;
-        let dy = (self.y - arg.y).abs() as u64;
-        let dz = (self.z - arg.z).abs() as u64;
-        dx + dy + dz
-    }
-
-    // Add or subtract coordinates.
-    fn add(&self, arg:&Xyz) -> Xyz {
-        Xyz { x:self.x+arg.x, y:self.y+arg.y, z:self.z+arg.z }
-    }
-    fn sub(&self, arg:&Xyz) -> Xyz {
-        Xyz { x:self.x-arg.x, y:self.y-arg.y, z:self.z-arg.z }
-    }
+type Xyz = Vec3<i64>;
 
-    // All 24 possible rotations:
-    //  * Pick forward axis (new +x)
-    //  * Pick upward axis (new +y)
-    //  * Remaining axis by right-hand-rule
-    fn rotate(&self, r:usize) -> Xyz {
-        match r {
-            0  => Xyz {x: self.x, y: self.y, z: self.z},
-            1  => Xyz {x: self.x, y:-self.y, z:-self.z},
-            2  => Xyz {x: self.x, y: self.z, z:-self.y},
-            3  => Xyz {x: self.x, y:-self.z, z: self.y},
-            4  => Xyz {x:-self.x, y: self.y, z:-self.z},
-            5  => Xyz {x:-self.x, y:-self.y, z: self.z},
-            6  => Xyz {x:-self.x, y: self.z, z: self.y},
-            7  => Xyz {x:-self.x, y:-self.z, z:-self.y},
-            8  => Xyz {x: self.y, y: self.x, z:-self.z},
-            9  => Xyz {x: self.y, y:-self.x, z: self.z},
-            10 => Xyz {x: self.y, y: self.z, z: self.x},
-            11 => Xyz {x: self.y, y:-self.z, z:-self.x},
-            12 => Xyz {x:-self.y, y: self.x, z: self.z},
-            13 => Xyz {x:-self.y, y:-self.x, z:-self.z},
-            14 => Xyz {x:-self.y, y: self.z, z:-self.x},
-            15 => Xyz {x:-self.y, y:-self.z, z: self.x},
-            16 => Xyz {x: self.z, y: self.x, z: self.y},
-            17 => Xyz {x: self.z, y:-self.x, z:-self.y},
-            18 => Xyz {x: self.z, y: self.y, z:-self.x},
-            19 => Xyz {x: self.z, y:-self.y, z: self.x},
-            20 => Xyz {x:-self.z, y: self.x, z:-self.y},
-            21 => Xyz {x:-self.z, y:-self.x, z: self.y},
-            22 => Xyz {x:-self.z, y: self.y, z: self.x},
-            _  => Xyz {x:-self.z, y:-self.y, z:-self.x},
-        }
-    }
+fn parse_xyz(line:&str) -> Xyz {
+    let xyz = common::split_str_as::<i64>(line, ',');
+    assert_eq!(xyz.len(), 3);
+    Xyz::new(xyz[0], xyz[1], xyz[2])
 }
 
 // Distance sets provide a rotation-invariant check for common points.
-type DistanceMap = HashSet<u64>;
+type DistanceMap = HashSet<i64>;
 
 // A scanner is a list of relative beacon coordinates.
 #[derive(Clone)]
-struct Scanner {
+pub struct Scanner {
     idx:  usize,
     beac: HashSet<Xyz>,
     scan: HashSet<Xyz>,
@@ -105,13 +38,13 @@ impl Scanner {
         } else {return None;}
         // Read beacons until we reach a blank line.
         let mut beac = HashSet::new();
-        while let Some(line) = lines.next() {
+        for line in lines.by_ref() {
             if line.is_empty() {break;}
-            beac.insert(Xyz::from(line));
+            beac.insert(parse_xyz(line));
         }
         // All coordinates are relative to the origin.
         let mut scan = HashSet::new();
-        scan.insert(Xyz::new());
+        scan.insert(Xyz::default());
         Some( Scanner { idx:idx, beac:beac, scan:scan} )
     }
 
@@ -133,7 +66,7 @@ impl Scanner {
 
     // Apply operation to all beacons in the set.
     fn add(&self, arg:&Xyz) -> Scanner {
-        let beac = self.beac.iter().map(|b| b.add(arg));
+        let beac = self.beac.iter().map(|b| *b + *arg);
         Scanner { idx:self.idx, beac:beac.collect(), scan:self.scan.clone() }
     }
     fn rotate(&self, r:usize) -> Scanner {
@@ -146,13 +79,13 @@ impl Scanner {
         // Trial and error voting on every possible pairwise alignment.
         type Delta = (usize,Xyz);
         let mut votes: HashMap<Delta,usize> = HashMap::new();
-        let mut vbest: Delta = (0, Xyz {x:0, y:0, z:0});
+        let mut vbest: Delta = (0, Xyz::default());
         let mut vmax:  usize = 0;
-        for r in 0..24usize {
+        for r in 0..ROTATIONS {
             for a in self.beac.iter() {
                 for b in scan.beac.iter() {
-                    let delta = (r, a.sub(&b.rotate(r)));
-                    let entry = votes.entry(delta.clone()).or_insert(0usize);
+                    let delta = (r, *a - b.rotate(r));
+                    let entry = votes.entry(delta).or_insert(0usize);
                     *entry += 1; // Increment vote count
                     if *entry > vmax {vmax = *entry; vbest = delta;}
                 }
@@ -174,8 +107,8 @@ impl Scanner {
 }
 
 // Read input file as a list of Scanner objects.
-fn read_file(filename: &str) -> Vec<Scanner> {
-    let file = common::read_lines(filename);
+fn read_input(input: &str) -> Vec<Scanner> {
+    let file = common::split_lines(input);
     let mut lines = file.iter();
     let mut scans = Vec::new();
     while let Some(scan) = Scanner::new(scans.len(), &mut lines) {
@@ -222,27 +155,44 @@ fn part1(scans: &Vec<Scanner>) -> Option<Scanner> {
 }
 
 // Find Maximum manhattan distance between Scanners.
-fn part2(x: &Scanner) -> u64 {
-    let mut best = 0u64;
+fn part2(x: &Scanner) -> i64 {
+    let mut best = 0i64;
     for a in x.scan.iter() {
         for b in x.scan.iter() {
-            best = max(best, a.dist_man(b));
+            best = max(best, a.manhattan(b));
         }
     }
     best
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    // Both parts use the aligned scans, so do that up front.
+    type Input = Scanner;
+    fn parse(input: &str) -> Scanner {part1(&read_input(input)).unwrap()}
+    fn part1(input: &Scanner) -> impl std::fmt::Display {input.count()}
+    fn part2(input: &Scanner) -> impl std::fmt::Display {part2(input)}
+}
+
 pub fn solve() {
-    let test = read_file("input/test19.txt");
-    let data = read_file("input/input19.txt");
-
-    // Part 1 solution aligns the partial scans.
-    let test1 = part1(&test).unwrap();
-    assert_eq!(test1.count(), 79);
-    let data1 = part1(&data).unwrap();
-    println!("Part1: {}", data1.count());
-
-    // Part 2 solution measures the extent of the complete map.
-    assert_eq!(part2(&test1), 3621);
-    println!("Part2: {}", part2(&data1));
+    aoclib::print::<Day>(&common::read_file("input/input19.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test = read_input(&common::read_file("input/test19.txt"));
+
+        // Part 1 solution aligns the partial scans.
+        let test1 = part1(&test).unwrap();
+        assert_eq!(test1.count(), 79);
+
+        // Part 2 solution measures the extent of the complete map.
+        assert_eq!(part2(&test1), 3621);
+    }
 }
@@ -62,33 +62,54 @@ fn enhance(img: &Image, filt: &Filter) -> Image {
     (border, out)
 }
 
+// Apply the enhancement filter N times.
+fn enhance_n(img: &Image, filt: &Filter, iter: usize) -> Image {
+    let mut next = img.clone();
+    for _ in 0..iter {next = enhance(&next, filt);}
+    next
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (Filter, Image);
+    fn parse(input: &str) -> (Filter, Image) {
+        let lines = common::split_lines(input);
+        (read_filter(&lines[0]), read_image(&lines[2..]))
+    }
+    fn part1(input: &(Filter, Image)) -> impl std::fmt::Display {
+        count(&enhance_n(&input.1, &input.0, 2))
+    }
+    fn part2(input: &(Filter, Image)) -> impl std::fmt::Display {
+        count(&enhance_n(&input.1, &input.0, 50))
+    }
+}
+
 pub fn solve() {
-    let test = common::read_lines("input/test20.txt");
-    let data = common::read_lines("input/input20.txt");
-
-    // Part 1 tests
-    let testf = read_filter(&test[0]);
-    let test0 = read_image(&test[2..]);
-    let test1 = enhance(&test0, &testf);
-    let test2 = enhance(&test1, &testf);
-    assert_eq!(count(&test0), 10);
-    assert_eq!(count(&test1), 24);
-    assert_eq!(count(&test2), 35);
-
-    // Part 1 data
-    let dataf = read_filter(&data[0]);
-    let data0 = read_image(&data[2..]);
-    let data1 = enhance(&data0, &dataf);
-    let data2 = enhance(&data1, &dataf);
-    println!("Part1: {}", count(&data2));
-
-    // Part 2 tests
-    let mut test50 = test2.clone();
-    for _ in 2..50 {test50 = enhance(&test50, &testf);}
-    assert_eq!(count(&test50), 3351);
-
-    // Part 2 data
-    let mut data50 = data2.clone();
-    for _ in 2..50 {data50 = enhance(&data50, &dataf);}
-    println!("Part2: {}", count(&data50));
+    aoclib::print::<Day>(&common::read_file("input/input20.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test = common::read_lines("input/test20.txt");
+
+        // Part 1 tests
+        let testf = read_filter(&test[0]);
+        let test0 = read_image(&test[2..]);
+        let test1 = enhance(&test0, &testf);
+        let test2 = enhance(&test1, &testf);
+        assert_eq!(count(&test0), 10);
+        assert_eq!(count(&test1), 24);
+        assert_eq!(count(&test2), 35);
+
+        // Part 2 tests
+        let mut test50 = test2.clone();
+        for _ in 2..50 {test50 = enhance(&test50, &testf);}
+        assert_eq!(count(&test50), 3351);
+    }
 }
@@ -111,10 +111,30 @@ fn part2(start: &(usize,usize)) -> u64 {
     max(wins.0, wins.1)
 }
 
+// Input for this problem is the starting position of each player.
+const INPUT: (usize, usize) = (7, 4);
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = (usize, usize);
+    fn parse(_input: &str) -> (usize, usize) {INPUT}
+    fn part1(input: &(usize, usize)) -> impl std::fmt::Display {part1(&(input.0 as u64, input.1 as u64))}
+    fn part2(input: &(usize, usize)) -> impl std::fmt::Display {part2(input)}
+}
+
 pub fn solve() {
-    assert_eq!(part1(&(4,8)), 739785);
-    println!("Part1: {}", part1(&(7,4)));
+    aoclib::print::<Day>("");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(part2(&(4,8)), 444356092776315);
-    println!("Part2: {}", part2(&(7,4)));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&(4,8)), 739785);
+        assert_eq!(part2(&(4,8)), 444356092776315);
+    }
 }
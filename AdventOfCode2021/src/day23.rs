@@ -260,14 +260,42 @@ fn dijkstra(start: &GameState) -> Option<u64> {
     return None
 }
 
+// Input for this problem is the starting position of each amphipod.
+const INPUT: &str = "ADBDBCAC";
+
+// Part 2 unfolds the diagram, adding two more rows in the middle.
+fn unfold(start: &str) -> String {
+    format!("{}DCBADBAC{}", &start[0..4], &start[4..8])
+}
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(_input: &str) -> String {INPUT.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {
+        dijkstra(&GameState::new(input)).unwrap()
+    }
+    fn part2(input: &String) -> impl std::fmt::Display {
+        dijkstra(&GameState::new(&unfold(input))).unwrap()
+    }
+}
+
 pub fn solve() {
-    let test1 = GameState::new("BCBDADCA");
-    let data1 = GameState::new("ADBDBCAC");
-    let test2 = GameState::new("BCBDDCBADBACADCA");
-    let data2 = GameState::new("ADBDDCBADBACBCAC");
+    aoclib::print::<Day>("");
+}
 
-    assert_eq!(dijkstra(&test1).unwrap(), 12521);
-    println!("Part1: {}", dijkstra(&data1).unwrap());
-    assert_eq!(dijkstra(&test2).unwrap(), 44169);
-    println!("Part2: {}", dijkstra(&data2).unwrap());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let test1 = GameState::new("BCBDADCA");
+        let test2 = GameState::new("BCBDDCBADBACADCA");
+        assert_eq!(unfold("BCBDADCA"), "BCBDDCBADBACADCA");
+        assert_eq!(dijkstra(&test1).unwrap(), 12521);
+        assert_eq!(dijkstra(&test2).unwrap(), 44169);
+    }
 }
@@ -83,7 +83,7 @@ impl Command {
     }
 
     fn exec(&self, reg:&Regs, digit:i64) -> Regs {
-        let mut result = reg.clone();
+        let mut result = *reg;
         match self {
             Command::Input(a)     => result[*a] = digit,
             Command::InpNeq(a, b) => {result[*a] = digit; result[*b] = bool2int(reg[*b] != digit)},
@@ -112,8 +112,8 @@ struct Program {
 }
 
 impl Program {
-    fn new(filename: &str) -> Program {
-        let lines = common::read_lines(filename);
+    fn new(input: &str) -> Program {
+        let lines = common::split_lines(input);
         let cmds = lines.into_iter()
             .map(|l| Command::new(&l).unwrap())
             .collect();
@@ -191,28 +191,43 @@ fn monad(regs: &RegState) -> MinMax {
     result
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = MinMax;
+    // Run the program and apply Z=0 constraint.
+    // (Expects the hand-edited version of the input, which moves or consolidates
+    //  instructions to help minimize exponential growth in the number of tracked states.)
+    fn parse(input: &str) -> MinMax {monad(&Program::new(input).run())}
+    fn part1(input: &MinMax) -> impl std::fmt::Display {input.1}    // Maximum
+    fn part2(input: &MinMax) -> impl std::fmt::Display {input.0}    // Minimum
+}
+
 pub fn solve() {
-    // Run the test program.
-    let test = Program::new("input/test24.txt").run();
-    assert_eq!(test.states.get(&[0,0,0,1]), Some(&(1,1)));
-    assert_eq!(test.states.get(&[0,0,1,0]), Some(&(2,2)));
-    assert_eq!(test.states.get(&[0,0,1,1]), Some(&(3,3)));
-    assert_eq!(test.states.get(&[0,1,0,0]), Some(&(4,4)));
-    assert_eq!(test.states.get(&[0,1,0,1]), Some(&(5,5)));
-    assert_eq!(test.states.get(&[0,1,1,0]), Some(&(6,6)));
-    assert_eq!(test.states.get(&[0,1,1,1]), Some(&(7,7)));
-    assert_eq!(test.states.get(&[1,0,0,0]), Some(&(8,8)));
-    assert_eq!(test.states.get(&[1,0,0,1]), Some(&(9,9)));
-    assert_eq!(test.states.get(&[1,0,1,0]), None);
-
-    // Apply the Z=0 constraint to the test result.
-    assert_eq!(monad(&test), (2,8));
-
-    // Run the main program and apply Z=0 constraint.
-    // (Hand-edited version moves or consolidates instructions to help
-    //  minimize exponential growth in the number of tracked states.)
-    let data = Program::new("input/input24_trim.txt").run();
-    let soln = monad(&data);
-    println!("Part1: {}", soln.1);  // Maximum
-    println!("Part2: {}", soln.0);  // Minimum
+    aoclib::print::<Day>(&common::read_file("input/input24_trim.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Run the test program.
+        let test = Program::new(&common::read_file("input/test24.txt")).run();
+        assert_eq!(test.states.get(&[0,0,0,1]), Some(&(1,1)));
+        assert_eq!(test.states.get(&[0,0,1,0]), Some(&(2,2)));
+        assert_eq!(test.states.get(&[0,0,1,1]), Some(&(3,3)));
+        assert_eq!(test.states.get(&[0,1,0,0]), Some(&(4,4)));
+        assert_eq!(test.states.get(&[0,1,0,1]), Some(&(5,5)));
+        assert_eq!(test.states.get(&[0,1,1,0]), Some(&(6,6)));
+        assert_eq!(test.states.get(&[0,1,1,1]), Some(&(7,7)));
+        assert_eq!(test.states.get(&[1,0,0,0]), Some(&(8,8)));
+        assert_eq!(test.states.get(&[1,0,0,1]), Some(&(9,9)));
+        assert_eq!(test.states.get(&[1,0,1,0]), None);
+
+        // Apply the Z=0 constraint to the test result.
+        assert_eq!(monad(&test), (2,8));
+    }
 }
@@ -9,16 +9,16 @@ const VERBOSE:bool = false;
 type RowCol = (usize,usize);
 
 #[derive(Clone)]
-struct Cucumbers {
+pub struct Cucumbers {
     size: RowCol,           // Grid size (rows,cols)
     e: HashSet<RowCol>,     // Coordinates of east-moving sea cucumbers
     s: HashSet<RowCol>,     // Coordinates of south-moving sea cucumbers
 }
 
 impl Cucumbers {
-    // Read map from file.
-    fn new(filename: &str) -> Cucumbers {
-        let lines = common::read_lines(filename);
+    // Read map from puzzle input.
+    fn new(input: &str) -> Cucumbers {
+        let lines = common::split_lines(input);
         let rows = lines.len();
         let cols = lines[0].chars().count();
         let mut e = HashSet::new();
@@ -79,10 +79,26 @@ impl Cucumbers {
     }
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Cucumbers;
+    fn parse(input: &str) -> Cucumbers {Cucumbers::new(input)}
+    fn part1(input: &Cucumbers) -> impl std::fmt::Display {input.time_to_deadlock()}
+}
+
 pub fn solve() {
-    let test = Cucumbers::new("input/test25.txt");
-    let data = Cucumbers::new("input/input25.txt");
+    aoclib::print::<Day>(&common::read_file("input/input25.txt"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    assert_eq!(test.time_to_deadlock(), 58);
-    println!("Part1: {}", data.time_to_deadlock());
+    #[test]
+    fn examples() {
+        let test = Cucumbers::new(&common::read_file("input/test25.txt"));
+        assert_eq!(test.time_to_deadlock(), 58);
+    }
 }
@@ -6,7 +6,13 @@
 /// Read an MxN single-digit grid as a vector-of-vectors.
 #[allow(dead_code)]
 pub fn read_grid<T: From<u8>>(filename: &str) -> Grid<T> {
-    let lines = common::read_lines(filename);
+    parse_grid(&common::read_file(filename))
+}
+
+/// Parse an MxN single-digit grid from a string.
+#[allow(dead_code)]
+pub fn parse_grid<T: From<u8>>(input: &str) -> Grid<T> {
+    let lines = common::split_lines(input);
     let rows = lines.iter()     // For each line...
         .map(|x| x.trim())      // Trim whitespace
         .map(|x| x.chars()      // Parse each digit
@@ -35,7 +41,7 @@ impl<T:Clone> Grid<T> {
     pub fn empty(data: &T, rc: &GridSize) -> Grid<T> {
         let erow = vec![data.clone();rc.c];
         let emat = vec![erow.clone();rc.r];
-        Grid { data:emat.clone(), size:rc.clone() }
+        Grid { data:emat.clone(), size:*rc }
     }
 }
 
@@ -71,7 +77,7 @@ impl<T> Grid<T> {
     }
 
     pub fn iter(&self) -> GridIterator {
-        GridIterator { size:self.size.clone(), next: GridSize{r:0,c:0} }
+        GridIterator { size:self.size, next: GridSize{r:0,c:0} }
     }
 }
 
@@ -83,7 +89,7 @@ pub struct GridIterator {
 }
 
 #[allow(dead_code)]
-impl<'a> Iterator for GridIterator {
+impl Iterator for GridIterator {
     type Item = RowCol;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -114,7 +120,7 @@ pub struct RowCol {
 #[allow(dead_code)]
 impl RowCol {
     pub fn new(r: i32, c: usize) -> RowCol {
-        RowCol {r:r as i32, c:c as i32}
+        RowCol {r:r, c:c as i32}
     }
 
     // Move one space in a given direction
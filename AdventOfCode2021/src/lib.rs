@@ -0,0 +1,75 @@
+//! Advent of Code 2021, all days as a library for the unified runner.
+//! Copyright 2025 by Alex Utter
+//!
+//! The "advent_2021" binary still runs each day with its verbose test output.
+
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate lazy_static;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Path to the local input file for a given day.
+macro_rules! input {
+    ($day:literal) => {concat!(env!("CARGO_MANIFEST_DIR"), "/input/input", $day, ".txt")};
+}
+
+/// Recorded answers for this year, see "aoc verify".
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Registry of solutions for this year.
+pub fn solutions() -> Vec<aoclib::Entry>
+{
+    vec![
+        aoclib::Entry::new::<day01::Day>(2021, 1).file(input!("01")),
+        aoclib::Entry::new::<day02::Day>(2021, 2).file(input!("02")),
+        aoclib::Entry::new::<day03::Day>(2021, 3).file(input!("03")),
+        aoclib::Entry::new::<day04::Day>(2021, 4).file(input!("04")),
+        aoclib::Entry::new::<day05::Day>(2021, 5).file(input!("05")),
+        aoclib::Entry::new::<day06::Day>(2021, 6).file(input!("06")),
+        aoclib::Entry::new::<day07::Day>(2021, 7).file(input!("07")),
+        aoclib::Entry::new::<day08::Day>(2021, 8).file(input!("08")),
+        aoclib::Entry::new::<day09::Day>(2021, 9).file(input!("09")),
+        aoclib::Entry::new::<day10::Day>(2021, 10).file(input!("10")),
+        aoclib::Entry::new::<day11::Day>(2021, 11).file(input!("11")),
+        aoclib::Entry::new::<day12::Day>(2021, 12).file(input!("12")),
+        aoclib::Entry::new::<day13::Day>(2021, 13).file(input!("13")),
+        aoclib::Entry::new::<day14::Day>(2021, 14).file(input!("14")),
+        aoclib::Entry::new::<day15::Day>(2021, 15).file(input!("15")),
+        aoclib::Entry::new::<day16::Day>(2021, 16).file(input!("16")),
+        aoclib::Entry::new::<day17::Day>(2021, 17).embedded(),
+        aoclib::Entry::new::<day18::Day>(2021, 18).file(input!("18")),
+        aoclib::Entry::new::<day19::Day>(2021, 19).file(input!("19")),
+        aoclib::Entry::new::<day20::Day>(2021, 20).file(input!("20")),
+        aoclib::Entry::new::<day21::Day>(2021, 21).embedded(),
+        aoclib::Entry::new::<day22::Day>(2021, 22).file(input!("22")),
+        aoclib::Entry::new::<day23::Day>(2021, 23).embedded(),
+        aoclib::Entry::new::<day24::Day>(2021, 24).file(input!("24_trim")),
+        aoclib::Entry::new::<day25::Day>(2021, 25).file(input!("25")),
+    ]
+}
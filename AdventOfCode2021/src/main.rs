@@ -9,31 +9,7 @@ extern crate lazy_static;
 use std::collections::HashMap;
 use std::env;
 use std::time;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+use aoc2021::*;
 
 // List of functions, one for each day's problem.
 lazy_static! {
@@ -76,7 +52,7 @@ fn solve_timer(idx: &usize) -> u32 {
         uut();  // Run designated function
         let elapsed = timer.elapsed().as_millis();
         println!("Elapsed: {} msec.", elapsed);
-        println!("");
+        println!();
         elapsed as u32
     } else {
         eprintln!("No solution for Day {}.", idx);
@@ -1,13 +1,11 @@
 /// Advent of Code 2023, Day 1
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 // Parse a single numeric digit.
 fn get_digit(ch: char) -> Option<i64>
 {
     const BASE: u32 = '0' as u32;
-    if ch.is_digit(10) {
+    if ch.is_ascii_digit() {
         Some((ch as u32 - BASE) as i64)
     } else {None}
 }
@@ -40,7 +38,7 @@ fn digits(input: &str, allow_words: bool) -> i64
 {
     let mut d0 = -1i64;                 // Leftmost digit
     let mut d1 = -1i64;                 // Rightmost digit
-    for (n, ch) in input.chars().enumerate() {
+    for (n, ch) in input.char_indices() {
         if let Some(dd) = get_digit(ch) {
             if d0 < 0 {d0 = dd};        // First in sequence
             d1 = dd;                    // Last in sequence
@@ -68,24 +66,36 @@ fn part2(input: &str) -> i64
     input.lines().map(|line| digits(line, true)).sum()
 }
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 1).unwrap();
+    aoclib::main::<Day>(2023, 1);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(digits("1abc2", false),              12);
-    assert_eq!(digits("pqr3stu8vwx", false),        38);
-    assert_eq!(digits("a1b2c3d4e5f", false),        15);
-    assert_eq!(digits("treb7uchet", false),         77);
-    assert_eq!(digits("two1nine", true),            29);
-    assert_eq!(digits("eightwothree", true),        83);
-    assert_eq!(digits("abcone2threexyz", true),     13);
-    assert_eq!(digits("xtwone3four", true),         24);
-    assert_eq!(digits("4nineeightseven2", true),    42);
-    assert_eq!(digits("zoneight234", true),         14);
-    assert_eq!(digits("7pqrstsixteen", true),       76);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(digits("1abc2", false),              12);
+        assert_eq!(digits("pqr3stu8vwx", false),        38);
+        assert_eq!(digits("a1b2c3d4e5f", false),        15);
+        assert_eq!(digits("treb7uchet", false),         77);
+        assert_eq!(digits("two1nine", true),            29);
+        assert_eq!(digits("eightwothree", true),        83);
+        assert_eq!(digits("abcone2threexyz", true),     13);
+        assert_eq!(digits("xtwone3four", true),         24);
+        assert_eq!(digits("4nineeightseven2", true),    42);
+        assert_eq!(digits("zoneight234", true),         14);
+        assert_eq!(digits("7pqrstsixteen", true),       76);
+    }
 }
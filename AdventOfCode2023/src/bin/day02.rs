@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 2
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use core::cmp::max;
 
 // A triplet of red, green, and blue marbles.
@@ -84,6 +83,7 @@ fn part2(input: &str) -> usize {
     return total;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
     Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
@@ -91,15 +91,27 @@ const EXAMPLE: &'static str = "\
     Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
     Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 2).unwrap();
+    aoclib::main::<Day>(2023, 2);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 8);
-    assert_eq!(part2(EXAMPLE), 2286);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 8);
+        assert_eq!(part2(EXAMPLE), 2286);
+    }
 }
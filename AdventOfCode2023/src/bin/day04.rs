@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 4
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -44,6 +43,7 @@ fn part2(input: &str) -> usize {
     return total;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
     Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -52,15 +52,27 @@ const EXAMPLE: &'static str = "\
     Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
     Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 4).unwrap();
+    aoclib::main::<Day>(2023, 4);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 13);
-    assert_eq!(part2(EXAMPLE), 30);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 13);
+        assert_eq!(part2(EXAMPLE), 30);
+    }
 }
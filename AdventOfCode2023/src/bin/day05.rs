@@ -1,21 +1,15 @@
 /// Advent of Code 2023, Day 5
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::range::{RangeMap, RangeSet};
 use std::collections::HashMap;
 
 const DEBUG: bool = false;
 
-struct Range {
-    dst: usize,
-    src: usize,
-    len: usize,
-}
-
 struct Map {
     src: String,
     dst: String,
-    ranges: Vec<Range>,
+    ranges: RangeMap<usize>,
 }
 
 struct Almanac {
@@ -23,82 +17,18 @@ struct Almanac {
     maps: HashMap<String, Map>,
 }
 
-impl Range {
-    // Create a range object from a line of text (dst, src, len).
-    fn new(line: &str) -> Self {
-        let tok: Vec<usize> = line.trim().split(' ')
-            .map(|x| x.parse().unwrap()).collect();
-        Range { dst: tok[0], src: tok[1], len: tok[2] }
-    }
-
-    // Forward conversion, if value falls within this range.
-    fn fwd_one(&self, val: usize) -> Option<usize> {
-        if self.src <= val && val < self.src + self.len {
-            Some(self.dst + val - self.src)
-        } else {
-            None
-        }
-    }
-}
-
 impl Map {
     // Read labels to create an empty map (e.g., "seed-to-soil map:").
     fn new(line: &str) -> Self {
         let tok: Vec<&str> = line.trim().split(&['-', ' ']).collect();
-        Map { src: tok[0].to_string(), dst: tok[2].to_string(), ranges: Vec::new() }
-    }
-
-    // Normalize this mapping by sorting the segments.
-    fn normalize(&mut self) {
-        self.ranges.sort_by(|a,b| a.src.partial_cmp(&b.src).unwrap());
+        Map { src: tok[0].to_string(), dst: tok[2].to_string(), ranges: RangeMap::new() }
     }
 
-    // Apply this numeric map to a single value.
-    fn fwd_one(&self, src: usize) -> usize {
-        // Brute-force search across each range...
-        for r in self.ranges.iter() {
-            if let Some(dst) = r.fwd_one(src) {return dst;}
-        }
-        return src; // No match = passthrough
-    }
-
-    // Apply this numeric map to a range of values.
-    fn fwd_range(&self, src: (usize,usize)) -> Vec<(usize,usize)> {
-        let mut result = Vec::new();
-        let mut src_min = src.0;
-        let src_max = src.0 + src.1;
-        for range in self.ranges.iter() {
-            // Skip this rule if input range is entirely to the right.
-            if src_min >= range.src + range.len {continue;}
-            // Process any portions to the left of the current rule.
-            if src_max <= range.src {
-                // Passthrough for the entire remaining input.
-                if DEBUG {println!("A {}-{}", src_min, src_max);}
-                result.push((src_min, src_max - src_min));
-                src_min = src_max; break;
-            } else if src_min < range.src {
-                // Passthrough for the leading portion only.
-                if DEBUG {println!("B {}-{}", src_min, src_max);}
-                result.push((src_min, range.src - src_min));
-                src_min = range.src;
-            }
-            // Process any portions that fall within the current rule.
-            let dst_min = range.dst + src_min - range.src;
-            if src_max <= range.src + range.len {
-                // Map the remaining portion and stop.
-                if DEBUG {println!("C {}-{}", src_min, src_max);}
-                result.push((dst_min, src_max - src_min));
-                src_min = src_max; break;
-            } else {
-                // Map up to the end of the current rule and continue.
-                if DEBUG {println!("D {}-{}", src_min, src_max);}
-                result.push((dst_min, range.src + range.len - src_min));
-                src_min = range.src + range.len;
-            }
-        }
-        // Passthrough for any trailing portion.
-        if src_min < src_max {result.push((src_min, src_max - src_min));}
-        return result;
+    // Add a segment from a line of text (dst, src, len).
+    fn add(&mut self, line: &str) {
+        let tok: Vec<usize> = line.trim().split(' ')
+            .map(|x| x.parse().unwrap()).collect();
+        self.ranges.insert(tok[1]..tok[1]+tok[2], tok[0]);
     }
 }
 
@@ -120,25 +50,24 @@ impl Almanac {
                 section = Some(almanac.maps.entry(tmp.src.clone()).or_insert(tmp));
             } else if let Some(sec) = &mut section {
                 // Continue the current map section.
-                sec.ranges.push(Range::new(line));
+                sec.add(line);
             }
         }
-        for sec in almanac.maps.values_mut() {sec.normalize();}
         return almanac;
     }
 
     // Apply the designated mapping to in-place individual values.
     fn fwd_one(&self, typ: &mut String, idx: &mut Vec<usize>) {
         let map = &self.maps[typ];
-        for x in idx.iter_mut() {*x = map.fwd_one(*x);}
+        for x in idx.iter_mut() {*x = map.ranges.get(*x);}
         *typ = map.dst.clone();
     }
 
-    // Apply the designated mapping to a list of ranges.
-    fn fwd_range(&self, typ: &mut String, idx: &mut Vec<(usize,usize)>) {
+    // Apply the designated mapping to a set of ranges.
+    fn fwd_range(&self, typ: &mut String, idx: &mut RangeSet<usize>) {
         if DEBUG {println!("{}: {:?}", typ, idx);}
         let map = &self.maps[typ];
-        *idx = idx.into_iter().map(|x| map.fwd_range(*x)).flatten().collect();
+        *idx = map.ranges.apply(idx);
         *typ = map.dst.clone();
     }
 }
@@ -158,13 +87,14 @@ fn part2(input: &str) -> usize {
     let almanac = Almanac::new(input);
     let seed0 = almanac.seeds.iter().cloned().step_by(2);
     let seed1 = almanac.seeds.iter().cloned().skip(1).step_by(2);
-    let mut idx = seed0.zip(seed1).collect();
+    let mut idx = seed0.zip(seed1).map(|(x, n)| x..x+n).collect();
     let mut typ = "seed".to_string();
     // Search until we find locations.
     while typ != "location" {almanac.fwd_range(&mut typ, &mut idx);}
-    return idx.into_iter().map(|x| x.0).min().unwrap();
+    return idx.first().unwrap();
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     seeds: 79 14 55 13
     seed-to-soil map:
@@ -193,15 +123,27 @@ const EXAMPLE: &'static str = "\
     60 56 37
     56 93 4";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 5).unwrap();
+    aoclib::main::<Day>(2023, 5);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 35);
-    assert_eq!(part2(EXAMPLE), 46);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 35);
+        assert_eq!(part2(EXAMPLE), 46);
+    }
 }
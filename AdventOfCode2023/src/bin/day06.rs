@@ -1,8 +1,6 @@
 /// Advent of Code 2023, Day 6
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn parse_single(line: &str) -> Vec<usize> {
     let mut total = 0usize;
     for ch in line.trim().chars() {
@@ -24,7 +22,7 @@ fn parse(input: &str, part2: bool) -> Vec<(usize, usize)> {
     let myfun = if part2 {parse_single} else {parse_many};
     let line0 = myfun(lines[0]);
     let line1 = myfun(lines[1]);
-    line0.into_iter().zip(line1.into_iter()).collect()
+    line0.into_iter().zip(line1).collect()
 }
 
 // Find the number of discrete solutions to "(t-x)*x > d", where:
@@ -46,19 +44,32 @@ fn part2(input: &str) -> usize {
     parse(input, true).into_iter().map(|(k,d)| count(k,d)).product()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     Time:      7  15   30
     Distance:  9  40  200";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 6).unwrap();
+    aoclib::main::<Day>(2023, 6);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 288);
-    assert_eq!(part2(EXAMPLE), 71503);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 288);
+        assert_eq!(part2(EXAMPLE), 71503);
+    }
 }
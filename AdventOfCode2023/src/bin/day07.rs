@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 7
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use core::cmp::Ordering;
 
 #[derive(Eq, PartialEq)]
@@ -72,6 +71,7 @@ fn part2(input: &str) -> usize {
     return hands.iter().enumerate().map(|(r,h)| (r+1) * h.bid).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     32T3K 765
     T55J5 684
@@ -79,15 +79,27 @@ const EXAMPLE: &'static str = "\
     KTJJT 220
     QQQJA 483";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 7).unwrap();
+    aoclib::main::<Day>(2023, 7);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 6440);
-    assert_eq!(part2(EXAMPLE), 5905);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 6440);
+        assert_eq!(part2(EXAMPLE), 5905);
+    }
 }
@@ -1,7 +1,7 @@
 /// Advent of Code 2023, Day 8
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::numtheory::crt;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -77,29 +77,16 @@ impl Maze {
     }
 }
 
-// Increment "a += k*b", choosing the smallest k such that "a + kb >= c".
-fn incr(a: &mut usize, b: usize, c: usize) {
-    if *a < c {
-        let k = (c - *a + b - 1) / b;   // Divide rounding up
-        *a += k * b;
-    }
-}
-
-// Given a pair of Repeat objects, find the least common multiple.
+// Given a pair of Repeat objects, find the first time both are at an exit,
+// and the period at which that repeats.
 fn lcm(r1: &Repeat, r2: &Repeat) -> Repeat {
-    let mut acc1 = r1.0;
-    let mut acc2 = r2.0;
-    while acc1 != acc2 {
-        if acc1 < acc2 {incr(&mut acc1, r1.1, acc2)}
-                  else {incr(&mut acc2, r2.1, acc1)};
-    }
-    let mut step1 = r1.1;
-    let mut step2 = r2.1;
-    while step1 != step2 {
-        if step1 < step2 {incr(&mut step1, r1.1, step2)}
-                    else {incr(&mut step2, r2.1, step1)};
-    }
-    return Repeat(acc1, step1);
+    let (t, period) = crt(&[(r1.0 as i64, r1.1 as i64), (r2.0 as i64, r2.1 as i64)])
+        .expect("Exits never line up");
+    let (t, period) = (t as usize, period as usize);
+    // The CRT solution may fall before either starting offset.
+    let first = r1.0.max(r2.0);
+    let skip = first.saturating_sub(t).div_ceil(period);
+    return Repeat(t + skip * period, period);
 }
 
 fn part1(input: &str) -> usize {
@@ -121,6 +108,7 @@ fn part2(input: &str) -> usize {
     return acc.0;
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     RL\n
     AAA = (BBB, CCC)
@@ -131,12 +119,14 @@ const EXAMPLE1: &'static str = "\
     GGG = (GGG, GGG)
     ZZZ = (ZZZ, ZZZ)";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     LLR\n
     AAA = (BBB, BBB)
     BBB = (AAA, ZZZ)
     ZZZ = (ZZZ, ZZZ)";
 
+#[cfg(test)]
 const EXAMPLE3: &'static str = "\
     LR\n
     11A = (11B, XXX)
@@ -148,18 +138,30 @@ const EXAMPLE3: &'static str = "\
     22Z = (22B, 22B)
     XXX = (XXX, XXX)";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 8).unwrap();
-
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE1), 2);
-    assert_eq!(part1(EXAMPLE2), 6);
-    assert_eq!(part2(EXAMPLE1), 2);
-    assert_eq!(part2(EXAMPLE2), 6);
-    assert_eq!(part2(EXAMPLE3), 6);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2023, 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 2);
+        assert_eq!(part1(EXAMPLE2), 6);
+        assert_eq!(part2(EXAMPLE1), 2);
+        assert_eq!(part2(EXAMPLE2), 6);
+        assert_eq!(part2(EXAMPLE3), 6);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2023, Day 9
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Sequence {
     val: Vec<i64>,
 }
@@ -37,20 +35,33 @@ fn part2(input: &str) -> i64 {
     input.trim().lines().map(|s| Sequence::new(s).prev()).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     0 3 6 9 12 15
     1 3 6 10 15 21
     10 13 16 21 30 45";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 9).unwrap();
+    aoclib::main::<Day>(2023, 9);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 114);
-    assert_eq!(part2(EXAMPLE), 2);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 114);
+        assert_eq!(part2(EXAMPLE), 2);
+    }
 }
@@ -1,17 +1,12 @@
 /// Advent of Code 2023, Day 10
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
+use aoclib::polygon::{Point, Polygon};
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::VecDeque;
-
-const DEBUG: bool = false;
 
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 struct Rc(isize, isize);        // Row + column
 
-const DIR_NW: Rc = Rc(-1,-1);
 const DIR_N:  Rc = Rc(-1, 0);
 const DIR_E:  Rc = Rc( 0, 1);
 const DIR_S:  Rc = Rc( 1, 0);
@@ -69,77 +64,41 @@ impl Maze {
         return Maze { pipes:pipes, start:start };
     }
 
-    // Print a HashSet for debugging.
-    fn debug(set: &HashSet<Rc>) {
-        let rmax = set.iter().map(|rc| rc.0).max().unwrap_or(1);
-        let cmax = set.iter().map(|rc| rc.1).max().unwrap_or(1);
-        for r in 0..=rmax {
-            for c in 0..=cmax {
-                print!("{}", if set.contains(&Rc(r,c)) {'*'} else {'-'});
-            }
-            println!(" Row {}", r);
-        }
-    }
-
-    // Breadth-first search along the main loop.
-    fn trace(&self) -> HashMap<Rc, usize> {
-        let mut result = HashMap::new();
-        let mut queue: VecDeque<(Rc,usize)> = VecDeque::new();
-        result.insert(self.start, 0);
-        queue.push_back((self.start, 0));
-        while let Some((rc,steps)) = queue.pop_front() {
+    // Follow the main loop from the start, listing each tile in order.
+    fn path(&self) -> Vec<Rc> {
+        let mut path = vec![self.start];
+        let mut rc = self.start;
+        let mut back = None;    // Direction we just came from
+        loop {
             let pipe = self.pipes.get(&rc).unwrap();
-            for (n,d) in DIRECTIONS.iter().enumerate() {
-                let adj = rc.add(d);
-                if pipe.next[n] && self.pipes.contains_key(&adj) && !result.contains_key(&adj) {
-                    result.insert(adj, steps+1);
-                    queue.push_back((adj, steps+1));
-                }
-            }
+            let n = (0..4).find(|&n| pipe.next[n] && Some(n) != back).unwrap();
+            rc = rc.add(&DIRECTIONS[n]);
+            if rc == self.start {return path;}
+            path.push(rc);
+            back = Some((n+2) % 4);
         }
-        if DEBUG {Self::debug(&result.keys().cloned().collect());}
-        return result;
     }
 
-    // Test if a given tile is inside the provided loop.
-    fn inner_test(&self, path: &HashSet<Rc>, start: &Rc) -> bool {
-        if path.contains(start) {return false;}
-        let mut count = 0usize;             // Count wall-crossings.
-        let mut pos = start.add(&DIR_NW);   // Scan until we reach edge...
-        while let Some(pipe) = self.pipes.get(&pos) {
-            if path.contains(&pos) {
-                if pipe.next[0] && pipe.next[2] {count += 1;}
-                if pipe.next[1] && pipe.next[3] {count += 1;}
-                if pipe.next[0] && pipe.next[3] {count += 1;}
-                if pipe.next[1] && pipe.next[2] {count += 1;}
-            }
-            pos = pos.add(&DIR_NW);
-        }
-        return (count % 2) > 0;
-    }
-
-    // List all the tiles completely inside the main loop.
-    fn inner(&self) -> HashSet<Rc> {
-        let path: HashSet<Rc> = self.trace().into_keys().collect();
-        let mut result = HashSet::new();
-        for rc in self.pipes.keys() {
-            if self.inner_test(&path, rc) {result.insert(*rc);}
-        }
-        if DEBUG {Self::debug(&result);}
-        return result;
+    // Treat the main loop as a polygon, with a vertex at each tile.
+    fn polygon(&self) -> Polygon {
+        Polygon::new(self.path().iter()
+            .map(|rc| Point::new(rc.0 as i64, rc.1 as i64))
+            .collect())
     }
 }
 
 fn part1(input: &str) -> usize {
     let maze = Maze::new(input);
-    return *maze.trace().values().max().unwrap();
+    return maze.path().len() / 2;
 }
 
 fn part2(input: &str) -> usize {
+    // Tiles enclosed by the loop are the polygon's interior points.
     let maze = Maze::new(input);
-    return maze.inner().len();
+    return maze.polygon().interior() as usize;
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     -L|F7
     7S-7|
@@ -147,6 +106,7 @@ const EXAMPLE1: &'static str = "\
     -L-J|
     L|-JF";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     ..F7.
     .FJ|.
@@ -154,6 +114,7 @@ const EXAMPLE2: &'static str = "\
     |F--J
     LJ...";
 
+#[cfg(test)]
 const EXAMPLE3: &'static str = "\
     ...........
     .S-------7.
@@ -165,6 +126,7 @@ const EXAMPLE3: &'static str = "\
     .L--J.L--J.
     ...........";
 
+#[cfg(test)]
 const EXAMPLE4: &'static str = "\
     ..........
     .S------7.
@@ -176,6 +138,7 @@ const EXAMPLE4: &'static str = "\
     .L--JL--J.
     ..........";
 
+#[cfg(test)]
 const EXAMPLE5: &'static str = "\
     .F----7F7F7F7F-7....
     .|F--7||||||||FJ....
@@ -188,6 +151,7 @@ const EXAMPLE5: &'static str = "\
     ....FJL-7.||.||||...
     ....L---J.LJ.LJLJ...";
 
+#[cfg(test)]
 const EXAMPLE6: &'static str = "\
     FF7FSF7F7F7F7F7F---7
     L|LJ||||||||||||F--J
@@ -200,19 +164,31 @@ const EXAMPLE6: &'static str = "\
     L.L7LFJ|||||FJL7||LJ
     L7JLJL-JLJLJL--JLJ.L";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 10).unwrap();
-
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE1), 4);
-    assert_eq!(part1(EXAMPLE2), 8);
-    assert_eq!(part2(EXAMPLE3), 4);
-    assert_eq!(part2(EXAMPLE4), 4);
-    assert_eq!(part2(EXAMPLE5), 8);
-    assert_eq!(part2(EXAMPLE6), 10);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2023, 10);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 4);
+        assert_eq!(part1(EXAMPLE2), 8);
+        assert_eq!(part2(EXAMPLE3), 4);
+        assert_eq!(part2(EXAMPLE4), 4);
+        assert_eq!(part2(EXAMPLE5), 8);
+        assert_eq!(part2(EXAMPLE6), 10);
+    }
 }
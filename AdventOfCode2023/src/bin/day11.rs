@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 11
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -75,6 +74,7 @@ fn part2(input: &str) -> isize {
     solve(input, 1000000)
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ...#......
     .......#..
@@ -87,16 +87,28 @@ const EXAMPLE: &'static str = "\
     .......#..
     #...#.....";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 11).unwrap();
+    aoclib::main::<Day>(2023, 11);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(solve(EXAMPLE, 2), 374);
-    assert_eq!(solve(EXAMPLE, 10), 1030);
-    assert_eq!(solve(EXAMPLE, 100), 8410);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(solve(EXAMPLE, 2), 374);
+        assert_eq!(solve(EXAMPLE, 10), 1030);
+        assert_eq!(solve(EXAMPLE, 100), 8410);
+    }
 }
@@ -1,13 +1,10 @@
 /// Advent of Code 2023, Day 12
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-use std::collections::HashMap;
+use aoclib::memo::Memo;
 
-struct Cache {
-    repeat: usize,
-    cache: HashMap<(usize,usize),usize>,
-}
+// Number of solutions, keyed by the next run's starting position and index.
+type Cache = Memo<(usize,usize),usize>;
 
 struct Puzzle {
     width: usize,
@@ -17,12 +14,6 @@ struct Puzzle {
     rsum: usize,
 }
 
-impl Cache {
-    fn new(repeat: usize) -> Self {
-        Cache {repeat:repeat, cache:HashMap::new()}
-    }
-}
-
 impl Puzzle {
     fn new(line: &str) -> Self {
         let tok: Vec<&str> = line.trim().split(' ').collect();
@@ -60,16 +51,20 @@ impl Puzzle {
 
     // Count the number of solutions from the given initial state:
     //  * cache = Shared cache object.
+    //  * repeat = Number of copies of the puzzle.
     //  * bidx = Leftmost starting position for the next run.
     //  * ridx = Number of runs already placed.
     //  * rsum = Total width of runs placed so far. 
-    fn search(&self, cache: &mut Cache, bidx:usize, ridx:usize, rsum:usize) -> usize {
-        // Has this solution been cached?
-        if let Some(x) = cache.cache.get(&(bidx, ridx)) {return *x;}
+    fn search(&self, cache: &mut Cache, repeat:usize, bidx:usize, ridx:usize, rsum:usize) -> usize {
+        cache.get_or_compute((bidx, ridx), |cache, _| self.search_new(cache, repeat, bidx, ridx, rsum))
+    }
+
+    // As "search", for any state that isn't already cached.
+    fn search_new(&self, cache: &mut Cache, repeat:usize, bidx:usize, ridx:usize, rsum:usize) -> usize {
         // Precalculate various quantities...
-        let rmax = cache.repeat * self.runs.len();      // Number of runs
-        let rrem = cache.repeat * self.rsum - rsum;     // Remaining run size
-        let wmax = cache.repeat * (self.width+1) - 1;   // Output width
+        let rmax = repeat * self.runs.len();            // Number of runs
+        let rrem = repeat * self.rsum - rsum;           // Remaining run size
+        let wmax = repeat * (self.width+1) - 1;         // Output width
         let wrem = rrem + rmax - ridx - 1;              // Including min gaps
         // Quickly eliminate impossible states.
         if bidx + wrem > wmax {return 0;}               // Negative slack :(
@@ -86,16 +81,15 @@ impl Puzzle {
             if ridx + 1 == rmax {                       // Final run?
                 if self.consistent0(bmax, wmax) {total += 1;}
             } else if self.consistent0(bmax, bmax+1) {  // Gap OK?
-                total += self.search(cache, bmax+1, ridx+1, rsum+rnext);
+                total += self.search(cache, repeat, bmax+1, ridx+1, rsum+rnext);
             }
         }
-        cache.cache.insert((bidx, ridx), total);
         return total;
     }
 
     // Shortcut for starting a full search.
     fn count(&self, repeat:usize) -> usize {
-        self.search(&mut Cache::new(repeat), 0, 0, 0)
+        self.search(&mut Cache::new(), repeat, 0, 0, 0)
     }
 }
 
@@ -107,6 +101,7 @@ fn part2(input: &str) -> usize {
     input.trim().lines().map(|s| Puzzle::new(s).count(5)).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ???.### 1,1,3
     .??..??...?##. 1,1,3
@@ -115,15 +110,27 @@ const EXAMPLE: &'static str = "\
     ????.######..#####. 1,6,5
     ?###???????? 3,2,1";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 12).unwrap();
+    aoclib::main::<Day>(2023, 12);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 21);
-    assert_eq!(part2(EXAMPLE), 525152);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 21);
+        assert_eq!(part2(EXAMPLE), 525152);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2023, Day 13
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 struct Maze {
     cols: Vec<u64>,
     rows: Vec<u64>,
@@ -23,7 +21,7 @@ impl Maze {
         // Read row-by-row until we reach end-of-input or a blank line.
         let mut rows = Vec::new();
         let mut width = 0usize;
-        while let Some(line) = lines.next() {
+        for line in lines.by_ref() {
             let count = line.trim().chars().count();
             if count == 0 {break;}
             let mut row = 0u64;
@@ -77,6 +75,7 @@ fn part2(input: &str) -> usize {
     mazes.iter().map(|m| m.score(1)).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     #.##..##.
     ..#.##.#.
@@ -93,15 +92,27 @@ const EXAMPLE: &'static str = "\
     ..##..###
     #....#..#";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 13).unwrap();
+    aoclib::main::<Day>(2023, 13);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 405);
-    assert_eq!(part2(EXAMPLE), 400);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 405);
+        assert_eq!(part2(EXAMPLE), 400);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 14
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -62,10 +61,10 @@ impl State {
     // Return a list of all coordinates on the designated edge.
     fn edge(&self, dir: &Rc) -> Vec<Rc> {
         match *dir {
-            DIR_N => (0..self.cols).map(|c| Rc(-1 as isize,        c as isize)).collect(),
-            DIR_S => (0..self.cols).map(|c| Rc(self.rows as isize, c as isize)).collect(),
-            DIR_W => (0..self.rows).map(|r| Rc(r as isize,        -1 as isize)).collect(),
-            DIR_E => (0..self.rows).map(|r| Rc(r as isize, self.cols as isize)).collect(),
+            DIR_N => (0..self.cols).map(|c| Rc(-1_isize,        c)).collect(),
+            DIR_S => (0..self.cols).map(|c| Rc(self.rows, c)).collect(),
+            DIR_W => (0..self.rows).map(|r| Rc(r,        -1_isize)).collect(),
+            DIR_E => (0..self.rows).map(|r| Rc(r, self.cols)).collect(),
             _     => Vec::new(),
         }
     }
@@ -88,10 +87,10 @@ impl State {
         // segment, then place them in their final configuration.
         let mut moved = HashSet::new();
         for rc in self.edge(dir).iter() {
-            moved.extend(self.segment(dir, &rc));
+            moved.extend(self.segment(dir, rc));
         }
         for rc in self.square.iter() {
-            moved.extend(self.segment(dir, &rc));
+            moved.extend(self.segment(dir, rc));
         }
         assert_eq!(moved.len(), self.round.len());
         State {
@@ -109,21 +108,12 @@ impl State {
 
     // Calculate the system state at the end of a spin-cycle.
     fn spin_for(&self, count: usize) -> Self {
-        // Keep a cache of previous states to look for repeats.
-        // TODO: Linear search is inefficient, but we can't hash a HashSet.
-        let mut cache = Vec::<State>::new();
-        let mut state = self.clone();
-        while cache.len() < count {
-            if let Some(prev) = cache.iter().position(|s| s == &state) {
-                // Repeat detected, fast-forward N periods.
-                let rem = (count - cache.len()) % (cache.len() - prev);
-                return cache[prev + rem].clone();
-            } else {
-                // Simulate one more timestep.
-                (state, _) = (state.spin(), cache.push(state));
-            }
-        }
-        return state;
+        // A HashSet can't be hashed, so compare states by sorted position.
+        let key = |s: &State| {
+            let mut tmp: Vec<Rc> = s.round.iter().cloned().collect();
+            tmp.sort(); tmp
+        };
+        aoclib::cycle::nth_state_by_key(self.clone(), State::spin, key, count)
     }
 
     // Calculate total load for this configuration of rocks.
@@ -156,6 +146,7 @@ fn part2(input: &str) -> isize {
     State::new(input).spin_for(1000000000).load()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     O....#....
     O.OO#....#
@@ -168,15 +159,27 @@ const EXAMPLE: &'static str = "\
     #....###..
     #OO..#....";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 14).unwrap();
+    aoclib::main::<Day>(2023, 14);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 136);
-    assert_eq!(part2(EXAMPLE), 64);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 136);
+        assert_eq!(part2(EXAMPLE), 64);
+    }
 }
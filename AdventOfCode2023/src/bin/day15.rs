@@ -1,8 +1,6 @@
 /// Advent of Code 2023, Day 15
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-
 fn hash(input: &str) -> u8 {
     let mut total = 0u8;
     for ch in input.trim().chars() {
@@ -80,18 +78,31 @@ fn part2(input: &str) -> usize {
     HashMap::new(input).focus()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str =
     "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 15).unwrap();
+    aoclib::main::<Day>(2023, 15);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 1320);
-    assert_eq!(part2(EXAMPLE), 145);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 1320);
+        assert_eq!(part2(EXAMPLE), 145);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 16
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -86,8 +85,8 @@ impl Grid {
     }
 
     fn edges(&self) -> Vec<Beam> {
-        let rmax = self.optics.keys().map(|rc| rc.0 as isize).max().unwrap();
-        let cmax = self.optics.keys().map(|rc| rc.1 as isize).max().unwrap();
+        let rmax = self.optics.keys().map(|rc| rc.0).max().unwrap();
+        let cmax = self.optics.keys().map(|rc| rc.1).max().unwrap();
         let mut tmp = Vec::new();
         tmp.extend((0..=cmax).map(|c| Beam(Rc(0,    c), DIR_S)));
         tmp.extend((0..=cmax).map(|c| Beam(Rc(rmax, c), DIR_N)));
@@ -126,6 +125,7 @@ fn part2(input: &str) -> usize {
     grid.edges().into_iter().map(|b| grid.energized(b).len()).max().unwrap()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     .|...\\....
     |.-.\\.....
@@ -138,15 +138,27 @@ const EXAMPLE: &'static str = "\
     .|....-|.\\
     ..//.|....";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 16).unwrap();
+    aoclib::main::<Day>(2023, 16);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 46);
-    assert_eq!(part2(EXAMPLE), 51);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 46);
+        assert_eq!(part2(EXAMPLE), 51);
+    }
 }
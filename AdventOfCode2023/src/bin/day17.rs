@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 17
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use core::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
@@ -94,6 +93,7 @@ fn part2(input: &str) -> usize {
     return city.search(Rc(0,0), city.rcmax);
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     2413432311323
     3215453535623
@@ -109,15 +109,27 @@ const EXAMPLE: &'static str = "\
     2546548887735
     4322674655533";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 17).unwrap();
+    aoclib::main::<Day>(2023, 17);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 102);
-    assert_eq!(part2(EXAMPLE), 94);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 102);
+        assert_eq!(part2(EXAMPLE), 94);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 19
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 // A conditional threshold that tests a single variable.
@@ -106,7 +105,7 @@ impl RuleSet {
     fn new(input: &str) -> Self {
         let tok: Vec<&str> = input.trim().split(&['{', ',', '}']).collect();
         let label = tok[0].to_string();
-        let rules = tok[1..=tok.len()-2].iter().map(|s| Rule::new(*s)).collect();
+        let rules = tok[1..=tok.len()-2].iter().map(|s| Rule::new(s)).collect();
         let other = tok[tok.len()-1].to_string();
         return RuleSet { label:label, rules:rules, other:other };
     }
@@ -121,7 +120,7 @@ impl RuleSet {
 
     // Split an input range into all possible label/range pairs.
     // (Skip any outputs that have no valid combinations.)
-    fn split<'a>(&'a self, range: Range) -> Vec<(&'a str, Range)> {
+    fn split(&self, range: Range) -> Vec<(&str, Range)> {
         let mut out = Vec::new();
         let mut rem = range;
         for rule in self.rules.iter() {
@@ -173,16 +172,16 @@ impl Range {
 
     // Mask the designated variable mask1 = X and Y, mask0 = X and !Y.
     fn mask1(&self, ch:char, mask:&Mask) -> Self {
-        Range { x: if ch=='x' {mask_and(&self.x, mask)} else {self.x.clone()},
-                m: if ch=='m' {mask_and(&self.m, mask)} else {self.m.clone()},
-                a: if ch=='a' {mask_and(&self.a, mask)} else {self.a.clone()},
-                s: if ch=='s' {mask_and(&self.s, mask)} else {self.s.clone()} }
+        Range { x: if ch=='x' {mask_and(&self.x, mask)} else {self.x},
+                m: if ch=='m' {mask_and(&self.m, mask)} else {self.m},
+                a: if ch=='a' {mask_and(&self.a, mask)} else {self.a},
+                s: if ch=='s' {mask_and(&self.s, mask)} else {self.s} }
     }
     fn mask0(&self, ch:char, mask:&Mask) -> Self {
-        Range { x: if ch=='x' {mask_not(&self.x, mask)} else {self.x.clone()},
-                m: if ch=='m' {mask_not(&self.m, mask)} else {self.m.clone()},
-                a: if ch=='a' {mask_not(&self.a, mask)} else {self.a.clone()},
-                s: if ch=='s' {mask_not(&self.s, mask)} else {self.s.clone()} }
+        Range { x: if ch=='x' {mask_not(&self.x, mask)} else {self.x},
+                m: if ch=='m' {mask_not(&self.m, mask)} else {self.m},
+                a: if ch=='a' {mask_not(&self.a, mask)} else {self.a},
+                s: if ch=='s' {mask_not(&self.s, mask)} else {self.s} }
     }
 
     // Count the total number of valid input combinations.
@@ -201,7 +200,7 @@ impl Work {
         let mut work = Work { flows: HashMap::new(), parts: Vec::new() };
         let mut upper = true;
         for line in input.trim().lines() {
-            if line.trim().len() == 0 {
+            if line.trim().is_empty() {
                 upper = false;  // Everything after this is a "part"
             } else if upper {
                 let flow = RuleSet::new(line);
@@ -252,6 +251,7 @@ fn part2(input: &str) -> usize {
     Work::new(input).combos()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     px{a<2006:qkq,m>2090:A,rfg}
     pv{a>1716:R,A}
@@ -270,15 +270,27 @@ const EXAMPLE: &'static str = "\
     {x=2461,m=1339,a=466,s=291}
     {x=2127,m=1623,a=2188,s=1013}";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 19).unwrap();
+    aoclib::main::<Day>(2023, 19);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 19114);
-    assert_eq!(part2(EXAMPLE), 167409079868000);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 19114);
+        assert_eq!(part2(EXAMPLE), 167409079868000);
+    }
 }
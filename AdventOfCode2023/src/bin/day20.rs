@@ -1,8 +1,7 @@
 /// Advent of Code 2023, Day 20
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
-use num::integer::lcm;
+use aoclib::numtheory::lcm_all;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 
@@ -41,7 +40,7 @@ struct Network {
 
 impl Module {
     // Parse descriptor -> Type, label, and output list.
-    fn parse<'a>(line: &'a str) -> (char, &'a str, &'a str) {
+    fn parse(line: &str) -> (char, &str, &str) {
         let tok: Vec<&str> = line.trim().split(" -> ").collect();
         return match tok[0].chars().nth(0) {
             Some('%') => ('%', &tok[0][1..], tok[1]),
@@ -82,7 +81,7 @@ impl Module {
         // Parse the output list and lookup each destination index.
         let (_, _, dstr) = Module::parse(line);
         return dstr.split(',').map(|s| s.trim())
-            .filter(|s| net.labels.get(*s) == None)
+            .filter(|s| !net.labels.contains_key(*s))
             .collect();
     }
 
@@ -99,10 +98,7 @@ impl Module {
 
     // If applicable, register an input connection.
     fn accept(&mut self, src: usize, idx: &mut usize) {
-        match &mut self.mtype {
-            Action::Conj(map) => {map.insert(src, *idx); *idx += 1;}
-            _ => (),
-        }
+        if let Action::Conj(map) = &mut self.mtype {map.insert(src, *idx); *idx += 1;}
     }
 
     // Update system state based on the given input pulse.
@@ -175,7 +171,7 @@ impl Network {
     }
 
     // Fetch label by module index.
-    fn module_name<'a>(&'a self, index: usize) -> &'a str {
+    fn module_name(&self, index: usize) -> &str {
         if index < self.modules.len() {
             &self.modules[index].label
         } else {
@@ -250,9 +246,10 @@ fn part2(input: &str) -> usize {
     let period: Vec<usize> = upstream.iter()
         .map(|n| net.first((*n,false))).collect();
     // Return the LCM, rather than trying to count "rx" directly.
-    return period.into_iter().fold(1usize, |n,p| lcm(n, p));
+    return lcm_all(period.into_iter().map(|p| p as i64)) as usize;
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     broadcaster -> a, b, c
     %a -> b
@@ -260,6 +257,7 @@ const EXAMPLE1: &'static str = "\
     %c -> inv
     &inv -> a";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     broadcaster -> a
     %a -> inv, con
@@ -267,15 +265,27 @@ const EXAMPLE2: &'static str = "\
     %b -> con
     &con -> output";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 20).unwrap();
+    aoclib::main::<Day>(2023, 20);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE1), 32000000);
-    assert_eq!(part1(EXAMPLE2), 11687500);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 32000000);
+        assert_eq!(part1(EXAMPLE2), 11687500);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 21
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -124,6 +123,7 @@ fn part2(input: &str) -> usize {
     Garden::new(input, true).predict(26501365)
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ...........
     .....###.#.
@@ -137,24 +137,36 @@ const EXAMPLE: &'static str = "\
     .##..##.##.
     ...........";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 21).unwrap();
-
-    // Unit tests on provided examples
-    // Omit largest test cases because they take a long time.
-    let example1 = Garden::new(EXAMPLE, false);
-    let example2 = Garden::new(EXAMPLE, true);
-    assert_eq!(example1.steps(6),    16);
-    assert_eq!(example2.steps(6),    16);
-    assert_eq!(example2.steps(10),   50);
-    assert_eq!(example2.steps(50),   1594);
-    assert_eq!(example2.steps(100),  6536);
-    assert_eq!(example2.steps(500),  167004);
-    //assert_eq!(example2.steps(1000), 668697);
-    //assert_eq!(example2.steps(5000), 16733044);
-
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    aoclib::main::<Day>(2023, 21);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        // Omit largest test cases because they take a long time.
+        let example1 = Garden::new(EXAMPLE, false);
+        let example2 = Garden::new(EXAMPLE, true);
+        assert_eq!(example1.steps(6),    16);
+        assert_eq!(example2.steps(6),    16);
+        assert_eq!(example2.steps(10),   50);
+        assert_eq!(example2.steps(50),   1594);
+        assert_eq!(example2.steps(100),  6536);
+        assert_eq!(example2.steps(500),  167004);
+        //assert_eq!(example2.steps(1000), 668697);
+        //assert_eq!(example2.steps(5000), 16733044);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 22
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -74,7 +73,7 @@ impl Brick {
 
     // Drop this brick until we contact an existing object.
     fn drop(&self, pile: &Pile) -> Self {
-        let mut best: Brick = self.clone();
+        let mut best: Brick = *self;
         for dz in 1..self.0.2 {
             let test = self.sub(&Xyz(0,0,dz));
             let overlap = test.floor().iter()
@@ -223,6 +222,7 @@ fn part2(input: &str) -> usize {
     (0..pile.bricks.len()).map(|n| pile.chain(n)).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     1,0,1~1,2,1
     0,0,2~2,0,2
@@ -232,15 +232,27 @@ const EXAMPLE: &'static str = "\
     0,1,6~2,1,6
     1,1,8~1,1,9";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 22).unwrap();
+    aoclib::main::<Day>(2023, 22);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 5);
-    assert_eq!(part2(EXAMPLE), 7);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 5);
+        assert_eq!(part2(EXAMPLE), 7);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 24
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use core::cmp::max;
 
 // An X/Y/Z triplet for a position or a velocity.
@@ -34,7 +33,6 @@ impl<T> Xyz<T>
     }
 }
 
-
 // An object with an initial position and a velocity.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Hail {
@@ -174,6 +172,7 @@ fn part2(input: &str) -> i64 {
     return rock.pos.0 + rock.pos.1 + rock.pos.2;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     19, 13, 30 @ -2,  1, -2
     18, 19, 22 @ -1, -1, -2
@@ -181,15 +180,27 @@ const EXAMPLE: &'static str = "\
     12, 31, 28 @ -1, -2, -1
     20, 19, 15 @  1, -5, -3";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 24).unwrap();
+    aoclib::main::<Day>(2023, 24);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(HailStorm::new(EXAMPLE).part1(7, 27), 2);
-    assert_eq!(part2(EXAMPLE), 47);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
-    println!("Part 2: {}", part2(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(HailStorm::new(EXAMPLE).part1(7, 27), 2);
+        assert_eq!(part2(EXAMPLE), 47);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2023, Day 25
 /// Copyright 2023 by Alex Utter
 
-use aocfetch;
 use core::cmp::max;
 use core::cmp::min;
 use rand::Rng;
@@ -36,7 +35,7 @@ impl Graph {
         let mut labels: HashMap<String, usize> = HashMap::new();
         for line in lines.iter() {
             for lbl in line.iter() {
-                if lbl.len() > 0 && !labels.contains_key(*lbl) {
+                if !lbl.is_empty() && !labels.contains_key(*lbl) {
                     let new_idx = labels.len();
                     labels.insert(lbl.to_string(), new_idx);
                 }
@@ -70,7 +69,7 @@ impl Graph {
     // Clone this graph, deleting the direct connection between two nodes.
     fn merge(&mut self, edge: usize) {
         // Lookup from/to indices for the selected edge.
-        let Edge(retain, remove) = self.edges.keys().nth(edge).unwrap().clone();
+        let Edge(retain, remove) = *self.edges.keys().nth(edge).unwrap();
         // Remove the victim node and add its weight to the other.
         let wt_removed = self.nodes.remove(&remove).unwrap();
         let wt_retained = self.nodes.entry(retain).or_insert(0);
@@ -80,7 +79,7 @@ impl Graph {
             .filter(|Edge(f,t)| *f == remove || *t == remove)
             .cloned().collect();
         for edge in edge_removed.iter() {
-            let w = self.edges.remove(&edge).unwrap().clone();
+            let w = self.edges.remove(edge).unwrap();
             if edge.0 == retain && edge.1 == remove {continue;}
             let next = if edge.0 == remove {
                 Edge::new(edge.1, retain)
@@ -112,6 +111,7 @@ fn part1(input: &str) -> usize {
     }
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     jqt: rhn xhk nvd
     rsh: frs pzl lsr
@@ -127,13 +127,25 @@ const EXAMPLE: &'static str = "\
     rzs: qnr cmg lsr rsh
     frs: qnr lhk lsr";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.trim().to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2023, 25).unwrap();
+    aoclib::main::<Day>(2023, 25);
+}
 
-    // Unit tests on provided examples
-    assert_eq!(part1(EXAMPLE), 54);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Solve for real input.
-    println!("Part 1: {}", part1(input.trim()));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 54);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 1
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type Pair = (i64, i64);
@@ -28,7 +27,7 @@ fn parse_lr(line: &str) -> Pair {
 
 fn parse(input: &str) -> List {
     return input.trim().lines()
-        .map(|line| parse_lr(line))
+        .map(parse_lr)
         .collect();
 }
 
@@ -73,6 +72,7 @@ fn part2(input: &List) -> i64 {
     return total;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     3   4
     4   3
@@ -81,14 +81,27 @@ const EXAMPLE: &'static str = "\
     3   9
     3   3";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = List;
+    fn parse(input: &str) -> List {parse(input)}
+    fn part1(input: &List) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &List) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 1).unwrap();
+    aoclib::main::<Day>(2024, 1);
+}
 
-    assert_eq!(part1(&parse(EXAMPLE)), 11);
-    assert_eq!(part2(&parse(EXAMPLE)), 31);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let data = parse(&input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&parse(EXAMPLE)), 11);
+        assert_eq!(part2(&parse(EXAMPLE)), 31);
+    }
 }
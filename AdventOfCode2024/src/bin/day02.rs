@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 2
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 struct Report {
     cols: Vec<i64>,
 }
@@ -18,7 +16,7 @@ impl Report {
     fn dvec<'a>(mut iter: impl Iterator<Item=&'a i64>) -> Vec<i64> {
         let mut result = Vec::new();
         let mut prev = iter.next().unwrap();
-        while let Some(next) = iter.next() {
+        for next in iter {
             result.push(next - prev);
             prev = next;
         }
@@ -60,7 +58,7 @@ struct Grid {
 impl Grid {
     fn new(input: &str) -> Self {
         let rows = input.trim().lines()
-            .map(|line| Report::new(line));
+            .map(Report::new);
         Grid { rows: rows.collect() }
     }
 }
@@ -77,6 +75,7 @@ fn part2(input: &str) -> usize {
         .filter(|r| r.safe() || r.safe_any()).count();
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     7 6 4 2 1
     1 2 7 8 9
@@ -85,13 +84,27 @@ const EXAMPLE: &'static str = "\
     8 6 4 4 1
     1 3 6 7 9";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 2).unwrap();
+    aoclib::main::<Day>(2024, 2);
+}
 
-    assert_eq!(part1(EXAMPLE), 2);
-    assert_eq!(part2(EXAMPLE), 4);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 2);
+        assert_eq!(part2(EXAMPLE), 4);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 3
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 type Mul = (i64, i64);
 
 fn parse(input: &str, part2: bool) -> Vec<Mul> {
@@ -12,7 +10,7 @@ fn parse(input: &str, part2: bool) -> Vec<Mul> {
     let mut state = 0usize;
     let mut keep  = true;
     for ch in input.trim().chars() {
-        state = match(state, ch, ch.is_digit(10)) {
+        state = match(state, ch, ch.is_ascii_digit()) {
             (_, 'm', _)     => {1},     // Start of "mul(123,456)" or similar
             (1, 'u', _)     => {2},
             (2, 'l', _)     => {3},
@@ -61,24 +59,40 @@ fn part2(input: &str) -> i64 {
         .sum()
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 3).unwrap();
+    aoclib::main::<Day>(2024, 3);
+}
 
-    assert_eq!(part1("mul(44,46)"), 2024);
-    assert_eq!(part1("mul(123,4)"), 123*4);
-    assert_eq!(part1("mul(4*"), 0);
-    assert_eq!(part1("mul(6,9!"), 0);
-    assert_eq!(part1("?(12,34)"), 0);
-    assert_eq!(part1("mul ( 2 , 4 )"), 0);
-    assert_eq!(part1(EXAMPLE1), 161);
-    assert_eq!(part2(EXAMPLE2), 48);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1("mul(44,46)"), 2024);
+        assert_eq!(part1("mul(123,4)"), 123*4);
+        assert_eq!(part1("mul(4*"), 0);
+        assert_eq!(part1("mul(6,9!"), 0);
+        assert_eq!(part1("?(12,34)"), 0);
+        assert_eq!(part1("mul ( 2 , 4 )"), 0);
+        assert_eq!(part1(EXAMPLE1), 161);
+        assert_eq!(part2(EXAMPLE2), 48);
+    }
 }
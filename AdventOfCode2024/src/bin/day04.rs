@@ -1,106 +1,79 @@
 /// Advent of Code 2024, Day 4
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
+use aoclib::grid::{Delta, Grid, Rc, DIR8};
 
-// A grid of letters.
-struct Grid {
-    grid: Vec<Vec<char>>,
+// Does the word appear starting from a given point, in a given direction?
+fn has_word(grid: &Grid<char>, rc: Rc, d: Delta, word: &str) -> bool {
+    let mut ray = grid.ray(rc, d);
+    word.chars().all(|ch| ray.next().map(|x| *x.1) == Some(ch))
 }
 
-impl Grid {
-    fn new(input: &str) -> Self {
-        let mut grid = Vec::new();
-        for line in input.trim().lines() {
-            grid.push(line.trim().chars().collect());
-        }
-        return Grid {grid: grid};
-    }
-
-    fn rows(&self) -> usize {self.grid.len()}
-    fn cols(&self) -> usize {self.grid[0].len()}
-
-    fn get(&self, r: usize, c: usize, dr: isize, dc: isize) -> Option<char> {
-        let rr = r.overflowing_add_signed(dr).0;
-        let cc = c.overflowing_add_signed(dc).0;
-        if rr >= self.rows() {return None;}
-        if cc >= self.cols() {return None;}
-        return Some(self.grid[rr][cc]);
-    }
-
-    fn count_xmas(&self, r: usize, c: usize) -> usize {
-        // Ignore anything that doesn't start with an 'X'.
-        if self.grid[r][c] != 'X' {return 0;}
-        // Otherwise, try searching in each of eight directions.
-        let mut count = 0usize;
-        for d in [(-1,0), (-1,1), (0,1), (1,1), (1,0), (1,-1), (0,-1), (-1,-1)] {
-            if self.get(r, c, 1*d.0, 1*d.1) == Some('M') &&
-               self.get(r, c, 2*d.0, 2*d.1) == Some('A') &&
-               self.get(r, c, 3*d.0, 3*d.1) == Some('S')
-               {count += 1;}
-        }
-        return count;
-    }
-
-    fn count_x_mas(&self, r: usize, c: usize) -> usize {
-        // Ignore anything where the center isn't an 'A'.
-        if self.grid[r][c] != 'A' {return 0;}
-        // Otherwise, try searching in each of eight orientations.
-        let mut count = 0usize;
-        for d in [(-1,-1), (-1,1), (1,-1), (1,1)] {
-            if self.get(r, c, -d.0, -d.1) == Some('M') &&
-               self.get(r, c, -d.1,  d.0) == Some('M') &&
-               self.get(r, c,  d.1, -d.0) == Some('S') &&
-               self.get(r, c,  d.0,  d.1) == Some('S')
-               {count += 1;}
-        }
-        return count;
-    }
+// Count XMAS in every direction from a given point.
+fn count_xmas(grid: &Grid<char>, rc: Rc) -> usize {
+    DIR8.iter().filter(|d| has_word(grid, rc, **d, "XMAS")).count()
 }
 
-// Find all XMAS in the word-search grid.
-fn part1(input: &str) -> usize {
-    let grid = Grid::new(input);
+// Count X-MAS centered on a given point, in each of four orientations.
+fn count_x_mas(grid: &Grid<char>, rc: Rc) -> usize {
+    // Ignore anything where the center isn't an 'A'.
+    if grid[rc] != 'A' {return 0;}
     let mut count = 0usize;
-    for r in 0..grid.rows() {
-        for c in 0..grid.cols() {
-            count += grid.count_xmas(r, c);
-        }
+    for d in [(-1,-1), (-1,1), (1,-1), (1,1)] {
+        if grid.peek(rc, (-d.0, -d.1)) == Some(&'M') &&
+           grid.peek(rc, (-d.1,  d.0)) == Some(&'M') &&
+           grid.peek(rc, ( d.1, -d.0)) == Some(&'S') &&
+           grid.peek(rc, ( d.0,  d.1)) == Some(&'S')
+           {count += 1;}
     }
     return count;
 }
 
+// Find all XMAS in the word-search grid.
+fn part1(grid: &Grid<char>) -> usize {
+    grid.positions().map(|rc| count_xmas(grid, rc)).sum()
+}
+
 // Find all X-MAS in the word-search grid.
-fn part2(input: &str) -> usize {
-    let grid = Grid::new(input);
-    let mut count = 0usize;
-    for r in 0..grid.rows() {
-        for c in 0..grid.cols() {
-            count += grid.count_x_mas(r, c);
-        }
-    }
-    return count;
+fn part2(grid: &Grid<char>) -> usize {
+    grid.positions().map(|rc| count_x_mas(grid, rc)).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
-    MMMSXXMASM
-    MSAMXMSMSA
-    AMXSXMAAMM
-    MSAMASMSMX
-    XMASAMXAMM
-    XXAMMXXAMA
-    SMSMSASXSS
-    SAXAMASAAA
-    MAMMMXMMMM
-    MXMXAXMASX";
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Grid<char>;
+    fn parse(input: &str) -> Grid<char> {Grid::parse(input, |ch| ch)}
+    fn part1(input: &Grid<char>) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Grid<char>) -> impl std::fmt::Display {part2(input)}
+}
 
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 4).unwrap();
+    aoclib::main::<Day>(2024, 4);
+}
 
-    assert_eq!(part1(EXAMPLE), 18);
-    assert_eq!(part2(EXAMPLE), 9);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        let example = Grid::parse(EXAMPLE, |ch| ch);
+        assert_eq!(part1(&example), 18);
+        assert_eq!(part2(&example), 9);
+    }
 }
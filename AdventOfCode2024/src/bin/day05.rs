@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 5
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -28,7 +27,7 @@ impl Ruleset {
             .map(|x| x.parse().unwrap()).collect();
         let a = tok[0]; let b = tok[1];
         // Direct updates: Add new rules A < B and B > A.
-        self.lt.entry(a).or_insert(HashSet::new()).insert(b);
+        self.lt.entry(a).or_default().insert(b);
     }
 
     // Comparison function for use with sort_by(...).
@@ -57,11 +56,11 @@ impl Ruleset {
             for &b in bb.iter() {
                 if !pset.contains(&b) {continue;}
                 // Insert direct rule A < B.
-                rules.lt.entry(a).or_insert(HashSet::new()).insert(b);
+                rules.lt.entry(a).or_default().insert(b);
                 // For each prior rule B < [C], create new transitive rule A < C.
                 if let Some(cc) = rules.lt.get(&b).cloned() {
                     for c in cc.into_iter() {
-                        rules.lt.entry(a).or_insert(HashSet::new()).insert(c);
+                        rules.lt.entry(a).or_default().insert(c);
                     }
                 }
             }
@@ -79,7 +78,7 @@ impl Pages {
 
     // For an odd-length list of pages, find the middle value.
     fn middle(&self) -> Option<i64> {
-        if self.pages.len() == 0 {return None;}
+        if self.pages.is_empty() {return None;}
         return Some(self.pages[(self.pages.len() - 1) / 2]);
     }
 
@@ -114,7 +113,7 @@ fn parse(input: &str) -> (Ruleset, Pageset) {
     let mut pset = Pageset::new();
     for line in input.trim().lines() {
         // Parse rules, then blank line, then page lists.
-        if line.len() == 0 {
+        if line.is_empty() {
             blank = true;
         } else if blank {
             pset.push(Pages::new(line));
@@ -145,6 +144,7 @@ fn part2(input: &str) -> i64 {
     return total;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     47|53
     97|13
@@ -175,14 +175,27 @@ const EXAMPLE: &'static str = "\
     61,13,29
     97,13,75,29,47";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
 
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 5).unwrap();
+    aoclib::main::<Day>(2024, 5);
+}
 
-    assert_eq!(part1(EXAMPLE), 143);
-    assert_eq!(part2(EXAMPLE), 123);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 143);
+        assert_eq!(part2(EXAMPLE), 123);
+    }
 }
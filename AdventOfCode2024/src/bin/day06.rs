@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 6
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Posn = (i64, i64);
@@ -82,7 +81,7 @@ impl State {
         loop {
             if visit.contains(&guard) {return true;}
             if !guard.inside(&self.size) {return false;}
-            visit.insert(guard.clone());
+            visit.insert(guard);
             if self.map.contains(&guard.next()) {
                 guard.rotate();
             } else {
@@ -97,7 +96,7 @@ impl State {
         let mut visit_next = HashSet::new();
         loop {
             if !guard.inside(&self.size) {return (visit_posn, visit_next);}
-            visit_posn.insert(guard.rc.clone());
+            visit_posn.insert(guard.rc);
             visit_next.insert(guard.next());
             if self.map.contains(&guard.next()) {
                 guard.rotate();
@@ -127,6 +126,7 @@ fn part2(input: &str) -> usize {
     return count;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ....#.....
     .........#
@@ -139,13 +139,27 @@ const EXAMPLE: &'static str = "\
     #.........
     ......#...";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 6).unwrap();
+    aoclib::main::<Day>(2024, 6);
+}
 
-    assert_eq!(part1(EXAMPLE), 41);
-    assert_eq!(part2(EXAMPLE), 6);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 41);
+        assert_eq!(part2(EXAMPLE), 6);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 7
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 // What's the next-largest power of 10? e.g., 123 -> 1000
 fn scale(x:u64) -> u64 {
     let mut rem = x;
@@ -75,6 +73,7 @@ fn part2(input: &str) -> u64 {
     solve(input, 3)
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     190: 10 19
     3267: 81 40 27
@@ -86,13 +85,27 @@ const EXAMPLE: &'static str = "\
     21037: 9 7 18 13
     292: 11 6 16 20";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 7).unwrap();
+    aoclib::main::<Day>(2024, 7);
+}
 
-    assert_eq!(part1(EXAMPLE), 3749);
-    assert_eq!(part2(EXAMPLE), 11387);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 3749);
+        assert_eq!(part2(EXAMPLE), 11387);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 9
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 struct Disk {
     blk: Vec<Option<usize>>,
 }
@@ -43,7 +41,7 @@ impl Disk {
             let empty = self.scan_right(idx);
             if empty >= rsize {return Some(idx);}
             idx += empty;
-            while idx < rdpos && self.blk[idx] != None {idx += 1;}
+            while idx < rdpos && self.blk[idx].is_some() {idx += 1;}
         }
         return None;
     }
@@ -60,7 +58,7 @@ impl Disk {
                 blk.push(self.blk[rdpos]);
                 rdpos -= 1;
                 // Before we continue, skip over empty blocks.
-                while self.blk[rdpos] == None {rdpos -= 1;}
+                while self.blk[rdpos].is_none() {rdpos -= 1;}
             }
         }
         return Disk { blk:blk };
@@ -78,11 +76,11 @@ impl Disk {
                 for n in 0..rsize {disk.blk[wrtmp+n] = disk.blk[rdpos-n];}
                 for n in 0..rsize {disk.blk[rdpos-n] = None;}
                 // Update pointer to the first empty block?
-                while disk.blk[wrpos] != None {wrpos += 1;}
+                while disk.blk[wrpos].is_some() {wrpos += 1;}
             }
             // Find the next file of interest.
             rdpos -= rsize;
-            while disk.blk[rdpos] == None {rdpos -= 1;}
+            while disk.blk[rdpos].is_none() {rdpos -= 1;}
         }
         return disk;
     }
@@ -100,15 +98,30 @@ fn part2(input: &str) -> usize {
     Disk::new(input).defrag().checksum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "2333133121414131402";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 9).unwrap();
+    aoclib::main::<Day>(2024, 9);
+}
 
-    assert_eq!(part1(EXAMPLE), 1928);
-    assert_eq!(part2(EXAMPLE), 2858);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 1928);
+        assert_eq!(part2(EXAMPLE), 2858);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 11
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 #[derive(Clone)]
@@ -63,15 +62,29 @@ fn part2(input: &str) -> u64 {
     Stones::parse(input).blink(75).count()
 }
 
-
+#[cfg(test)]
 const EXAMPLE: &'static str = "125 17";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 11).unwrap();
+    aoclib::main::<Day>(2024, 11);
+}
 
-    assert_eq!(part1(EXAMPLE), 55312);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 55312);
+    }
 }
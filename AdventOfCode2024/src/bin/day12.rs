@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 12
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -130,7 +129,7 @@ impl Garden {
         let mut plants: HashMap<char, HashSet<Rc>> = HashMap::new();
         for (r,row) in input.trim().lines().enumerate() {
             for (c,ch) in row.trim().chars().enumerate() {
-                plants.entry(ch).or_insert(HashSet::new()).insert(Rc::new(r+1,c+1));
+                plants.entry(ch).or_default().insert(Rc::new(r+1,c+1));
             }
         }
         // For each plant type, extract contiguous regions.
@@ -162,12 +161,14 @@ fn part2(input: &str) -> usize {
     Garden::new(input).price2()
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     AAAA
     BBCD
     BBCC
     EEEC";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     OOOOO
     OXOXO
@@ -175,6 +176,7 @@ const EXAMPLE2: &'static str = "\
     OXOXO
     OOOOO";
 
+#[cfg(test)]
 const EXAMPLE3: &'static str = "\
     RRRRIICCFF
     RRRRIICCCF
@@ -187,6 +189,7 @@ const EXAMPLE3: &'static str = "\
     MIIISIJEEE
     MMMISSJEEE";
 
+#[cfg(test)]
 const EXAMPLE4: &'static str = "\
     EEEEE
     EXXXX
@@ -194,6 +197,7 @@ const EXAMPLE4: &'static str = "\
     EXXXX
     EEEEE";
 
+#[cfg(test)]
 const EXAMPLE5: &'static str = "\
     AAAAAA
     AAABBA
@@ -202,19 +206,33 @@ const EXAMPLE5: &'static str = "\
     ABBAAA
     AAAAAA";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 12).unwrap();
-
-    assert_eq!(part1(EXAMPLE1), 140);
-    assert_eq!(part1(EXAMPLE2), 772);
-    assert_eq!(part1(EXAMPLE3), 1930);
-    assert_eq!(part2(EXAMPLE1), 80);
-    assert_eq!(part2(EXAMPLE2), 436);
-    assert_eq!(part2(EXAMPLE3), 1206);
-    assert_eq!(part2(EXAMPLE4), 236);
-    assert_eq!(part2(EXAMPLE5), 368);
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::main::<Day>(2024, 12);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 140);
+        assert_eq!(part1(EXAMPLE2), 772);
+        assert_eq!(part1(EXAMPLE3), 1930);
+        assert_eq!(part2(EXAMPLE1), 80);
+        assert_eq!(part2(EXAMPLE2), 436);
+        assert_eq!(part2(EXAMPLE3), 1206);
+        assert_eq!(part2(EXAMPLE4), 236);
+        assert_eq!(part2(EXAMPLE5), 368);
+    }
 }
@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 13
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 fn tokenize(line: &str) -> Vec<i64> {
     line.trim().split([' ', '+', '=', ','])
         .filter_map(|s| s.parse::<i64>().ok())
@@ -69,6 +67,7 @@ fn part2(input: &str) -> i64 {
     Game::new(input, true).iter().filter_map(|game| game.solve()).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     Button A: X+94, Y+34
     Button B: X+22, Y+67
@@ -86,12 +85,26 @@ const EXAMPLE: &'static str = "\
     Button B: X+27, Y+71
     Prize: X=18641, Y=10279";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 13).unwrap();
+    aoclib::main::<Day>(2024, 13);
+}
 
-    assert_eq!(part1(EXAMPLE), 480);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 480);
+    }
 }
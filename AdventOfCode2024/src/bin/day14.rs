@@ -1,8 +1,6 @@
 /// Advent of Code 2024, Day 14
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
-
 struct Robot {
     px: i64,
     py: i64,
@@ -105,6 +103,7 @@ impl Grid {
     }
 }
 
+#[cfg(test)]
 fn part1_smol(input: &str) -> usize {
     Grid::new(input, 11, 7).predict(100).safety()
 }
@@ -128,6 +127,7 @@ fn part2(input: &str) -> usize {
     return best_time as usize;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     p=0,4 v=3,-3
     p=6,3 v=-1,-3
@@ -142,12 +142,26 @@ const EXAMPLE: &'static str = "\
     p=2,4 v=2,-3
     p=9,5 v=-3,-3";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 14).unwrap();
+    aoclib::main::<Day>(2024, 14);
+}
 
-    assert_eq!(part1_smol(EXAMPLE), 12);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1_smol(EXAMPLE), 12);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 15
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Rc = (usize, usize);
@@ -54,7 +53,7 @@ impl Warehouse {
 
     fn get_box(&self, rc: &Rc) -> Option<Rc> {
         let ll = add(rc, &(0,-1));
-        let rr = rc.clone();
+        let rr = *rc;
         if self.part2 && self.boxes.contains(&ll) {
             return Some(ll);
         } else if self.boxes.contains(&rr) {
@@ -103,6 +102,7 @@ fn part2(input: &str) -> usize {
     return state.gps();
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     ########
     #..O.O.#
@@ -115,6 +115,7 @@ const EXAMPLE1: &'static str = "\
 
     <^^>>>vv<v>>v<<";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     ##########
     #..O..O.O#
@@ -138,14 +139,28 @@ const EXAMPLE2: &'static str = "\
     ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
     v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 15).unwrap();
+    aoclib::main::<Day>(2024, 15);
+}
 
-    assert_eq!(part1(EXAMPLE1), 2028);
-    assert_eq!(part1(EXAMPLE2), 10092);
-    assert_eq!(part2(EXAMPLE2), 9021);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 2028);
+        assert_eq!(part1(EXAMPLE2), 10092);
+        assert_eq!(part2(EXAMPLE2), 9021);
+    }
 }
@@ -1,7 +1,7 @@
 /// Advent of Code 2024, Day 17
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
+use aoclib::parse::{self, ParseError};
 use std::collections::VecDeque;
 
 const VERBOSE: bool = false;
@@ -11,11 +11,6 @@ fn format(input: &Vec<u8>) -> String {
         .collect::<Vec<_>>().join(",")
 }
 
-fn parse<T: std::str::FromStr>(line: &str) -> Vec<T> {
-    line.trim().split([' ', ','])
-        .filter_map(|s| s.parse().ok()).collect()
-}
-
 #[derive(Clone)]
 struct State {
     rega: i64,      // Register A, B, C
@@ -43,7 +38,7 @@ impl State {
             op, arg, self.rega, self.regb, self.regc);}
         match op {
             0 => {  // ADV (division rega)
-                self.rega = self.rega / 2i64.pow(self.cbo(arg) as u32);
+                self.rega /= 2i64.pow(self.cbo(arg) as u32);
                 self.iptr += 2; None},
             1 => {  // BXL (bitwise xor literal)
                 self.regb ^= arg as i64;
@@ -77,20 +72,24 @@ struct Program {
 }
 
 impl Program {
-    fn new(input: &str) -> Self {
-        let mut iter = input.trim().lines();
-        let rega = parse::<i64>(iter.next().unwrap());
-        let regb = parse::<i64>(iter.next().unwrap());
-        let regc = parse::<i64>(iter.next().unwrap());
+    fn new(input: &str) -> parse::Result<Self> {
+        // Initial registers, then the program, separated by a blank line.
+        let blocks = parse::blocks(input);
+        if blocks.len() != 2 {
+            return Err(ParseError::new(input, input, "Expected registers and program"));
+        }
+        let reg = parse::ints::<i64>(blocks[0]).map_err(|e| e.within(input, blocks[0]))?;
+        if reg.len() != 3 {
+            return Err(ParseError::new(input, blocks[0], "Expected three registers"));
+        }
         let init = State {
-            rega: rega[0],
-            regb: regb[0],
-            regc: regc[0],
+            rega: reg[0],
+            regb: reg[1],
+            regc: reg[2],
             iptr: 0,
         };
-        iter.next();
-        let prog = parse::<u8>(iter.next().unwrap());
-        return Program { init:init, prog:prog };
+        let prog = parse::ints::<u8>(blocks[1]).map_err(|e| e.within(input, blocks[1]))?;
+        return Ok(Program { init:init, prog:prog });
     }
 
     fn run(&self) -> Vec<u8> {
@@ -106,7 +105,7 @@ impl Program {
 }
 
 fn part1(input: &str) -> String {
-    let prog = Program::new(input);
+    let prog = Program::new(input).unwrap_or_else(|e| panic!("{}", e));
     return format(&prog.run());
 }
 
@@ -116,7 +115,7 @@ fn part1(input: &str) -> String {
 // match the last N digits of the output, until we've matched the entire output.
 fn part2(input: &str) -> i64 {
     // Read the desired input.
-    let mut prog = Program::new(input);
+    let mut prog = Program::new(input).unwrap_or_else(|e| panic!("{}", e));
     let plen = prog.prog.len();
     // Guess-and-check starting from the last digit.
     // Recurse on anything that matches more than the initial guess.
@@ -136,6 +135,7 @@ fn part2(input: &str) -> i64 {
     panic!("No solution?");
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     Register A: 2024
     Register B: 0
@@ -143,6 +143,7 @@ const EXAMPLE1: &'static str = "\
 
     Program: 0,1,5,4,3,0";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     Register A: 729
     Register B: 0
@@ -150,6 +151,7 @@ const EXAMPLE2: &'static str = "\
 
     Program: 0,1,5,4,3,0";
 
+#[cfg(test)]
 const EXAMPLE3: &'static str = "\
     Register A: 2024
     Register B: 0
@@ -157,14 +159,28 @@ const EXAMPLE3: &'static str = "\
 
     Program: 0,3,5,4,3,0";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 17).unwrap();
+    aoclib::main::<Day>(2024, 17);
+}
 
-    assert_eq!(part1(EXAMPLE1), String::from("4,2,5,6,7,7,7,7,3,1,0"));
-    assert_eq!(part1(EXAMPLE2), String::from("4,6,3,5,6,3,5,2,1,0"));
-    assert_eq!(part2(EXAMPLE3), 117440);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), String::from("4,2,5,6,7,7,7,7,3,1,0"));
+        assert_eq!(part1(EXAMPLE2), String::from("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(part2(EXAMPLE3), 117440);
+    }
 }
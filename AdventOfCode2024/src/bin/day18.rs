@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 18
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
@@ -74,7 +73,7 @@ impl Grid {
         let mut max = self.drops.len() - 1;
         while min < max {
             let mid = (min + max) / 2;
-            if let Some(_) = self.part1(mid) {
+            if self.part1(mid).is_some() {
                 min = mid + 1;
             } else {
                 max = mid;
@@ -95,17 +94,32 @@ fn part2(input:&str, rmax: usize) -> String {
     return format!("{},{}", posn.0, posn.1);
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2
     5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input, 70, 1024)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input, 70)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 18).unwrap();
+    aoclib::main::<Day>(2024, 18);
+}
 
-    assert_eq!(part1(EXAMPLE, 6, 12), 22);
-    assert_eq!(part2(EXAMPLE, 6), "6,1");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input, 70, 1024));
-    println!("Part 2: {}", part2(&input, 70));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE, 6, 12), 22);
+        assert_eq!(part2(EXAMPLE, 6), "6,1");
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 19
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type Design = Vec<char>;
@@ -91,6 +90,7 @@ fn part2(input:&str) -> usize {
     Towels::new(input).part2()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     r, wr, b, g, bwu, rb, gb, br
 
@@ -103,13 +103,27 @@ const EXAMPLE: &'static str = "\
     brgr
     bbrgwb";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 19).unwrap();
+    aoclib::main::<Day>(2024, 19);
+}
 
-    assert_eq!(part1(EXAMPLE), 6);
-    assert_eq!(part2(EXAMPLE), 16);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 6);
+        assert_eq!(part2(EXAMPLE), 16);
+    }
 }
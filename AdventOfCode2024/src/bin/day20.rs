@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 20
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -117,6 +116,7 @@ fn part2(input:&str) -> usize {
     Wallhack::new(input).count_hacks(20, 100)
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ###############
     #...#...#.....#
@@ -134,18 +134,32 @@ const EXAMPLE: &'static str = "\
     #...#...#...###
     ###############";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 20).unwrap();
-
-    let example = Wallhack::new(EXAMPLE);
-    assert_eq!(example.cost_base(), 84);
-    assert_eq!(example.count_hacks(2, 20), 5);
-    assert_eq!(example.count_hacks(2, 64), 1);
-    assert_eq!(example.count_hacks(20, 72), 29);
-    assert_eq!(example.count_hacks(20, 74), 7);
-    assert_eq!(example.count_hacks(20, 76), 3);
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    aoclib::main::<Day>(2024, 20);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let example = Wallhack::new(EXAMPLE);
+        assert_eq!(example.cost_base(), 84);
+        assert_eq!(example.count_hacks(2, 20), 5);
+        assert_eq!(example.count_hacks(2, 64), 1);
+        assert_eq!(example.count_hacks(20, 72), 29);
+        assert_eq!(example.count_hacks(20, 74), 7);
+        assert_eq!(example.count_hacks(20, 76), 3);
+    }
 }
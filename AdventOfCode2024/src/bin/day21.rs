@@ -1,11 +1,12 @@
 /// Advent of Code 2024, Day 21
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
+use aoclib::memo::Memo;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
 type Rc = (i8, i8);                 // Row, column (posn or delta)
+type Cache = Memo<(bool,char,char,usize), usize>;
 
 struct Keypad {
     keys: HashSet<Rc>,              // Valid key locations
@@ -35,7 +36,6 @@ struct Solver {
     value:  usize,
     dirpad: Keypad,
     numpad: Keypad,
-    cache:  HashMap<(char,char,usize), usize>,
 }
 
 impl Solver {
@@ -54,7 +54,6 @@ impl Solver {
             value:  accum,
             dirpad: Keypad::new(".^A\n<v>"),
             numpad: Keypad::new("789\n456\n123\n.0A"),
-            cache:  HashMap::new(),
         };
     }
 
@@ -71,7 +70,7 @@ impl Solver {
     }
 
     // Calculate cost of horizontal-first path from "prev" to "next".
-    fn cost_hfirst(&mut self, dpad:bool, prev:Rc, next:Rc, lvl:usize) -> usize {
+    fn cost_hfirst(&self, cache:&mut Cache, dpad:bool, prev:Rc, next:Rc, lvl:usize) -> usize {
         // Does this move have a horizontal component?
         if prev.1 == next.1 {return usize::MAX;}
         // Does this move go out of bounds?
@@ -80,20 +79,20 @@ impl Solver {
         // Otherwise, press required key(s) and return to home position.
         let vmove = self.vmove(next.0 - prev.0);
         let hmove = self.hmove(next.1 - prev.1);
-        let count = (next.0 - prev.0).abs() as usize
-                  + (next.1 - prev.1).abs() as usize;
+        let count = (next.0 - prev.0).unsigned_abs() as usize
+                  + (next.1 - prev.1).unsigned_abs() as usize;
         if prev.0 == next.0 {   // Two-part move (H, A)
-            return self.cost(true, 'A',   hmove, lvl-1)
-                 + self.cost(true, hmove, 'A',   lvl-1) + count;
+            return self.cost(cache, true, 'A',   hmove, lvl-1)
+                 + self.cost(cache, true, hmove, 'A',   lvl-1) + count;
         } else {                // Three-part move (H, V, A)
-            return self.cost(true, 'A',   hmove, lvl-1)
-                 + self.cost(true, hmove, vmove, lvl-1)
-                 + self.cost(true, vmove, 'A',   lvl-1) + count;
+            return self.cost(cache, true, 'A',   hmove, lvl-1)
+                 + self.cost(cache, true, hmove, vmove, lvl-1)
+                 + self.cost(cache, true, vmove, 'A',   lvl-1) + count;
         }
     }
 
     // Calculate cost of vertical-first path from "prev" to "next".
-    fn cost_vfirst(&mut self, dpad:bool, prev:Rc, next:Rc, lvl:usize) -> usize {
+    fn cost_vfirst(&self, cache:&mut Cache, dpad:bool, prev:Rc, next:Rc, lvl:usize) -> usize {
         // Does this move have a vertical component?
         if prev.0 == next.0 {return usize::MAX;}
         // Does this move go out of bounds?
@@ -102,43 +101,40 @@ impl Solver {
         // Otherwise, press required key(s) and return to home position.
         let vmove = self.vmove(next.0 - prev.0);
         let hmove = self.hmove(next.1 - prev.1);
-        let count = (next.0 - prev.0).abs() as usize
-                  + (next.1 - prev.1).abs() as usize;
+        let count = (next.0 - prev.0).unsigned_abs() as usize
+                  + (next.1 - prev.1).unsigned_abs() as usize;
         if prev.1 == next.1 {   // Two-part move (V, A)
-            return self.cost(true, 'A',   vmove, lvl-1)
-                 + self.cost(true, vmove, 'A',   lvl-1) + count;
+            return self.cost(cache, true, 'A',   vmove, lvl-1)
+                 + self.cost(cache, true, vmove, 'A',   lvl-1) + count;
         } else {                // Three-part move (V, H, A)
-            return self.cost(true, 'A',   vmove, lvl-1)
-                 + self.cost(true, vmove, hmove, lvl-1)
-                 + self.cost(true, hmove, 'A',   lvl-1) + count;
+            return self.cost(cache, true, 'A',   vmove, lvl-1)
+                 + self.cost(cache, true, vmove, hmove, lvl-1)
+                 + self.cost(cache, true, hmove, 'A',   lvl-1) + count;
         }
     }
 
     // Return the minimum cost of a given move, with memoization.
     // (Note: Includes return of all preceding layers to the "A" key.)
-    fn cost(&mut self, dpad:bool, prev:char, next:char, lvl:usize) -> usize {
-        let key = (prev, next, lvl);
-        if lvl == 0 {
-            return 0;
-        } else if let Some(&cost) = self.cache.get(&key) {
-            return cost;
-        } else {
+    fn cost(&self, cache:&mut Cache, dpad:bool, prev:char, next:char, lvl:usize) -> usize {
+        if lvl == 0 {return 0;}
+        return cache.get_or_compute((dpad, prev, next, lvl), |cache, _| {
             let prev_rc = self.keypad(dpad).posn[&prev];
             let next_rc = self.keypad(dpad).posn[&next];
             let cost = std::cmp::min(
-                self.cost_hfirst(dpad, prev_rc, next_rc, lvl),
-                self.cost_vfirst(dpad, prev_rc, next_rc, lvl));
+                self.cost_hfirst(cache, dpad, prev_rc, next_rc, lvl),
+                self.cost_vfirst(cache, dpad, prev_rc, next_rc, lvl));
             assert!(cost < usize::MAX);
-            if dpad {self.cache.insert(key, cost);}
-            return cost;
-        }
+            cost
+        });
     }
-    fn solve(&mut self, layers: usize) -> usize {
+
+    fn solve(&self, layers: usize) -> usize {
+        let mut cache = Cache::new();
         let mut posn = 'A';         // Numpad starts at 'A'
         let mut accum = 0usize;     // Total cost so far
-        for &ch in self.code.clone().iter() {
+        for &ch in self.code.iter() {
             // Move to each numpad key, then press 'A'.
-            accum += self.cost(false, posn, ch, layers) + 1;
+            accum += self.cost(&mut cache, false, posn, ch, layers) + 1;
             posn = ch;
         }
         return accum * self.value;
@@ -157,6 +153,7 @@ fn part2(input:&str) -> usize {
         .sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     029A
     980A
@@ -164,12 +161,26 @@ const EXAMPLE: &'static str = "\
     456A
     379A";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 21).unwrap();
+    aoclib::main::<Day>(2024, 21);
+}
 
-    assert_eq!(part1(EXAMPLE), 126384);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 126384);
+    }
 }
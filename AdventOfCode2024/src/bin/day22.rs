@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 22
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 
 type Sequence = (i64, i64, i64, i64);
@@ -64,16 +63,32 @@ fn part2(input:&str) -> i64 {
     return *profit.values().max().unwrap();
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "1\n10\n100\n2024";
+#[cfg(test)]
 const EXAMPLE2: &'static str = "1\n2\n3\n2024";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 22).unwrap();
+    aoclib::main::<Day>(2024, 22);
+}
 
-    assert_eq!(part1(EXAMPLE1), 37327623);
-    assert_eq!(part2(EXAMPLE2), 23);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 37327623);
+        assert_eq!(part2(EXAMPLE2), 23);
+    }
 }
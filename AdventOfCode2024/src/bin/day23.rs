@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 23
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -121,19 +120,34 @@ fn part2(input:&str) -> String {
     Network::new(input).part2()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     kh-tc\n qp-kh\n de-cg\n ka-co\n yn-aq\n qp-ub\n cg-tb\n vc-aq
     tb-ka\n wh-tc\n yn-cg\n kh-ub\n ta-co\n de-co\n tc-td\n tb-wq
     wh-td\n ta-ka\n td-qp\n aq-cg\n wq-ub\n ub-vc\n de-ta\n wq-aq
     wq-vc\n wh-yn\n ka-de\n kh-ta\n co-tc\n wh-qp\n tb-vc\n td-yn";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 23).unwrap();
+    aoclib::main::<Day>(2024, 23);
+}
 
-    assert_eq!(part1(EXAMPLE), 7);
-    assert_eq!(part2(EXAMPLE), "co,de,ka,ta");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 7);
+        assert_eq!(part2(EXAMPLE), "co,de,ka,ta");
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2024, Day 24
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -119,16 +118,16 @@ impl Circuit {
             self.value.push(None);
             self.wires.push(HashSet::new());
             // Is this a special input or output wire?
-            if lbl.starts_with('x') {
-                let bidx: usize = lbl[1..].parse().unwrap();
+            if let Some(idx) = lbl.strip_prefix('x') {
+                let bidx: usize = idx.parse().unwrap();
                 self.xwire.insert(bidx, tmp);
             }
-            if lbl.starts_with('y') {
-                let bidx: usize = lbl[1..].parse().unwrap();
+            if let Some(idx) = lbl.strip_prefix('y') {
+                let bidx: usize = idx.parse().unwrap();
                 self.ywire.insert(bidx, tmp);
             }
-            if lbl.starts_with('z') {
-                let bidx: usize = lbl[1..].parse().unwrap();
+            if let Some(idx) = lbl.strip_prefix('z') {
+                let bidx: usize = idx.parse().unwrap();
                 self.zwire.insert(bidx, tmp);
             }
             return tmp;     // New index
@@ -332,6 +331,7 @@ fn part2(input:&str) -> String {
     return lbl.join(",");
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     x00: 1
     x01: 1
@@ -344,6 +344,7 @@ const EXAMPLE1: &'static str = "\
     x01 XOR y01 -> z01
     x02 OR y02 -> z02";
 
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     x00: 1
     x01: 0
@@ -393,13 +394,27 @@ const EXAMPLE2: &'static str = "\
     tgd XOR rvg -> z12
     tnw OR pbm -> gnj";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 24).unwrap();
+    aoclib::main::<Day>(2024, 24);
+}
 
-    assert_eq!(part1(EXAMPLE1), 4);
-    assert_eq!(part1(EXAMPLE2), 2024);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 4);
+        assert_eq!(part1(EXAMPLE2), 2024);
+    }
 }
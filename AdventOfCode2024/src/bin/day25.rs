@@ -1,35 +1,39 @@
 /// Advent of Code 2024, Day 25
 /// Copyright 2024 by Alex Utter
 
-use aocfetch;
+use aoclib::bits::BitSet;
+
+// Each schematic is a 7x5 grid, with one bit per filled slot.
+type Cells = BitSet<35>;
 
 struct LockOrKey {
     is_key: bool,
-    height: [usize;5],
+    cells: Cells,
 }
 
 impl LockOrKey {
     fn new<'a>(input: &mut impl Iterator<Item=&'a str>) -> Option<Self> {
-        // Read the 7x5 grid, counting filled slots in each column.
+        // Read the 7x5 grid, noting filled slots.
         let mut is_key = true;
-        let mut count_col = [0usize;5];
-        let mut first_row = true;
-        while let Some(next) = input.next() {
+        let mut cells = Cells::new();
+        let mut rows = 0usize;
+        for next in input.by_ref() {
             let row: Vec<char> = next.trim().chars().collect();
             if row.is_empty() {break;}
-            if row.len() != 5 {return None;}
+            if row.len() != 5 || rows >= 7 {return None;}
             for (c,&ch) in row.iter().enumerate() {
-                if ch == '#' && first_row {is_key = false;}
-                if ch == '#' {count_col[c] += 1;}
+                if ch == '#' && rows == 0 {is_key = false;}
+                if ch == '#' {cells.insert(5*rows + c);}
             }
-            first_row = false;
+            rows += 1;
         }
-        if first_row {return None;}
-        Some(LockOrKey { is_key: is_key, height: count_col } )
+        if rows == 0 {return None;}
+        Some(LockOrKey { is_key: is_key, cells: cells } )
     }
 
+    // A key fits a lock if none of their filled slots overlap.
     fn fits(&self, other: &LockOrKey) -> bool {
-        (0..5).all(|n| self.height[n] + other.height[n] <= 7)
+        self.cells.is_disjoint(&other.cells)
     }
 }
 
@@ -63,6 +67,7 @@ fn part1(input: &str) -> usize {
     Schematics::new(input).count_pairs()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     #####
     .####
@@ -104,11 +109,25 @@ const EXAMPLE: &'static str = "\
     #.#.#
     #####";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2024, 25).unwrap();
+    aoclib::main::<Day>(2024, 25);
+}
 
-    assert_eq!(part1(EXAMPLE), 3);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("Part 1: {}", part1(&input));
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 3);
+    }
 }
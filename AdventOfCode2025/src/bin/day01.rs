@@ -1,13 +1,11 @@
 /// Advent of Code 2025, Day 1
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
-
 type CmdVec = Vec<i64>;
 
 // Convert a single command "L68" or "R48" to a number.
 fn parse_one(word: &str) -> Result<i64, &str> {
-    let cmd = word.chars().nth(0).ok_or("Empty")?;
+    let cmd = word.chars().next().ok_or("Empty")?;
     let num = word[1..].parse::<i64>().or(Err("Bad distance"))?;
     if cmd == 'L' { return Ok(-num); }  // Left = Negative
     if cmd == 'R' { return Ok( num); }  // Right = Positive
@@ -15,7 +13,7 @@ fn parse_one(word: &str) -> Result<i64, &str> {
 }
 
 fn parse(input: &str) -> CmdVec {
-    return input.trim().split_whitespace()
+    return input.split_whitespace()
         .filter_map(|w| parse_one(w).ok())
         .collect();
 }
@@ -45,23 +43,36 @@ fn part2(cmds: &CmdVec) -> usize {
     run(cmds).1
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     L68 L30 R48 L5 R60 L55 L1 L99 R14 L82";
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     R1000 L50 R100 L200 R200 R1 R99 L1 L99 R1 R100 L100 L102 L99";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = CmdVec;
+    fn parse(input: &str) -> CmdVec {parse(input)}
+    fn part1(input: &CmdVec) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &CmdVec) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 1).unwrap();
+    aoclib::main::<Day>(2025, 1);
+}
 
-    assert_eq!(part1(&parse(EXAMPLE1)), 3);
-    assert_eq!(part1(&parse(EXAMPLE2)), 7);
-    assert_eq!(part2(&parse(EXAMPLE1)), 6);
-    assert_eq!(part2(&parse(EXAMPLE2)), 23);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    let data = parse(&input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&parse(EXAMPLE1)), 3);
+        assert_eq!(part1(&parse(EXAMPLE2)), 7);
+        assert_eq!(part2(&parse(EXAMPLE1)), 6);
+        assert_eq!(part2(&parse(EXAMPLE2)), 23);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2025, Day 2
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Range = (usize, usize);
@@ -42,7 +41,7 @@ fn repeat(seed: usize, rpt: usize) -> usize {
 
 // Given number of repetitions, scan for invalid IDs in a range.
 fn scan(range: &Range, rpt: usize, ids: &mut RSet) {
-    let (range_lo, range_hi) = range.clone();
+    let (range_lo, range_hi) = *range;
     let len_base = strlen(range_lo).0;
     let len_diff = len_base - len_base / rpt;
     let mut seed = range_lo / 10usize.pow(len_diff as u32);
@@ -79,21 +78,33 @@ fn part2(input: &RVec) -> usize {
     return total;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
     1698522-1698528,446443-446449,38593856-38593862,565653-565659,
     824824821-824824827,2121212118-2121212124";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = RVec;
+    fn parse(input: &str) -> RVec {parse(input)}
+    fn part1(input: &RVec) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &RVec) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 2).unwrap();
+    aoclib::main::<Day>(2025, 2);
+}
 
-    assert_eq!(part1(&parse(EXAMPLE)), 1227775554);
-    assert_eq!(part2(&parse(EXAMPLE)), 4174379265);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    let data = parse(&input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&parse(EXAMPLE)), 1227775554);
+        assert_eq!(part2(&parse(EXAMPLE)), 4174379265);
+    }
 }
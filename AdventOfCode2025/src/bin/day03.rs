@@ -1,8 +1,6 @@
 /// Advent of Code 2025, Day 3
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
-
 type Row = Vec<usize>;
 type Grid = Vec<Row>;
 
@@ -42,22 +40,34 @@ fn part2(grid: &Grid) -> usize {
     grid.iter().map(|row| scan(row, 12)).sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     987654321111111
     811111111111119
     234234234234278
     818181911112111";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Grid;
+    fn parse(input: &str) -> Grid {parse(input)}
+    fn part1(input: &Grid) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Grid) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 3).unwrap();
+    aoclib::main::<Day>(2025, 3);
+}
 
-    assert_eq!(part1(&parse(EXAMPLE)), 357);
-    assert_eq!(part2(&parse(EXAMPLE)), 3121910778619);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    let data = parse(&input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(&parse(EXAMPLE)), 357);
+        assert_eq!(part2(&parse(EXAMPLE)), 3121910778619);
+    }
 }
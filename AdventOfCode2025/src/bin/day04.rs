@@ -1,7 +1,6 @@
 /// Advent of Code 2025, Day 4
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -14,7 +13,7 @@ fn rc_adj(rc: &Rc) -> [Rc;8] {
 }
 
 #[derive(Clone)]
-struct Grid {
+pub struct Grid {
     data: HashMap<Rc, usize>,
 }
 
@@ -41,7 +40,7 @@ impl Grid {
     fn accessible(&self) -> HashSet<Rc> {
         self.data.iter()
             .filter( |(_,ct)| **ct < 4 )
-            .map( |(rc,_)| rc.clone() )
+            .map( |(rc,_)| *rc )
             .collect()
     }
 
@@ -52,7 +51,7 @@ impl Grid {
         }
         for rc in tmp.iter() {
             for old_rc in rc_adj(rc).iter() {
-                if let Some(old_ct) = self.data.get_mut(&old_rc) {
+                if let Some(old_ct) = self.data.get_mut(old_rc) {
                     *old_ct -= 1;
                 }
             }
@@ -62,7 +61,7 @@ impl Grid {
 }
 
 fn part1(grid: &Grid) -> usize {
-    grid.accessible().iter().count()
+    grid.accessible().len()
 }
 
 fn part2(grid: &Grid) -> usize {
@@ -71,6 +70,7 @@ fn part2(grid: &Grid) -> usize {
     return grid.data.len() - rem.data.len();
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     ..@@.@@@@.
     @@@.@.@.@@
@@ -83,17 +83,28 @@ const EXAMPLE: &'static str = "\
     .@@@@@@@@.
     @.@.@@@.@.";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = Grid;
+    fn parse(input: &str) -> Grid {Grid::new(input)}
+    fn part1(input: &Grid) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &Grid) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 4).unwrap();
+    aoclib::main::<Day>(2025, 4);
+}
 
-    let example = Grid::new(EXAMPLE);
-    assert_eq!(part1(&example), 13);
-    assert_eq!(part2(&example), 43);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    let data = Grid::new(&input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        let example = Grid::new(EXAMPLE);
+        assert_eq!(part1(&example), 13);
+        assert_eq!(part2(&example), 43);
+    }
 }
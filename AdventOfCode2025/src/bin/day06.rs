@@ -1,8 +1,6 @@
 /// Advent of Code 2025, Day 6
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
-
 struct Problem {
     dat: Vec<i64>,
     op: char,
@@ -34,12 +32,12 @@ impl ProblemSet {
         let mut problems: Vec<Problem> = Vec::new();
         for row in input.trim().lines() {
             if row.contains('+') || row.contains('*') {
-                for item in row.trim().split_whitespace() {
+                for item in row.split_whitespace() {
                     let op = item.chars().next().unwrap();
                     problems.push(Problem::new(op));
                 }
             } else {
-                grid.push(row.trim().split_whitespace()
+                grid.push(row.split_whitespace()
                     .filter_map( |n| n.parse::<i64>().ok() )
                     .collect());
             }
@@ -100,21 +98,34 @@ fn part2(input: &str) -> i64 {
     return problems.problems.iter().map(|p| p.solve()).sum();
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
 123 328  51 64
  45 64  387 23
   6 98  215 314
 *   +   *   +";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 6).unwrap();
+    aoclib::main::<Day>(2025, 6);
+}
 
-    assert_eq!(part1(EXAMPLE), 4277556);
-    assert_eq!(part2(EXAMPLE), 3263827);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 4277556);
+        assert_eq!(part2(EXAMPLE), 3263827);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2025, Day 7
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -61,6 +60,7 @@ fn part2(input: &str) -> usize {
     Splitters::new(input).split().0.values().sum()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     .......S.......
     ...............
@@ -79,15 +79,27 @@ const EXAMPLE: &'static str = "\
     .^.^.^.^.^...^.
     ...............";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 7).unwrap();
+    aoclib::main::<Day>(2025, 7);
+}
 
-    assert_eq!(part1(EXAMPLE), 21);
-    assert_eq!(part2(EXAMPLE), 40);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 21);
+        assert_eq!(part2(EXAMPLE), 40);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2025, Day 8
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 
 type Circuit = HashSet<usize>;
@@ -39,7 +38,7 @@ impl Problem {
     fn new(input: &str) -> Self {
         // Parse X/Y/Z coordinates of each junction box.
         let boxes: Vec<Box> = input.trim().lines()
-            .map( |line| Box::new(line) ).collect();
+            .map( Box::new ).collect();
         // Initially, each box is its own island.
         let ckts = (0..boxes.len())
             .map( |n| HashSet::from([n]) ).collect();
@@ -111,6 +110,7 @@ fn part2(input: &str) -> i64 {
     Problem::new(input).part2()
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     162,817,812
     57,618,57
@@ -133,15 +133,27 @@ const EXAMPLE: &'static str = "\
     984,92,344
     425,690,689";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input, 1000)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 8).unwrap();
+    aoclib::main::<Day>(2025, 8);
+}
 
-    assert_eq!(part1(EXAMPLE, 10), 40);
-    assert_eq!(part2(EXAMPLE), 25272);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input, 1000));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE, 10), 40);
+        assert_eq!(part2(EXAMPLE), 25272);
+    }
 }
@@ -1,121 +1,30 @@
 /// Advent of Code 2025, Day 9
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
-use std::cmp::max;
-use std::cmp::min;
-use std::collections::HashSet;
+use aoclib::polygon::{Point, Polygon};
 
-const DEBUG: bool = false;
-type RowCol = (i64, i64);
-
-struct Polygon {
-    rc: Vec<RowCol>,
-    rows: HashSet<i64>,
-    cols: HashSet<i64>,
-}
-
-impl Polygon {
-    fn new(input: &str) -> Self {
-        let mut poly = Polygon {
-            rc: Vec::new(),
-            rows: HashSet::new(),
-            cols: HashSet::new(),
-        };
-        for line in input.trim().lines() {
+fn parse(input: &str) -> Vec<Point> {
+    input.trim().lines()
+        .map(|line| {
             let rc: Vec<i64> = line.trim().split(',')
                 .filter_map( |s| s.parse::<i64>().ok() )
                 .collect();
-            poly.rc.push((rc[0], rc[1]));
-            poly.rows.insert(rc[0]);
-            poly.cols.insert(rc[1]);
-        }
-        return poly;
-    }
-
-    // Given two corners, find the area of the contained rectangle.
-    fn area(&self, m:usize, n:usize) -> i64 {
-        let dr = (self.rc[m].0 - self.rc[n].0).abs() + 1;
-        let dc = (self.rc[m].1 - self.rc[n].1).abs() + 1;
-        return dr * dc;
-    }
-
-    // Given two corners, normalize the bounding rectangle.
-    fn rect(&self, m:usize, n:usize) -> (i64, i64, i64, i64) {
-        ( min(self.rc[m].0, self.rc[n].0),      // Row-min
-          max(self.rc[m].0, self.rc[n].0),      // Row-max
-          min(self.rc[m].1, self.rc[n].1),      // Col-min    
-          max(self.rc[m].1, self.rc[n].1) )     // Col-max
-    }
-
-    // Fetch normalized perimeter segment #N as a rectangle.
-    fn seg(&self, n:usize) -> (i64, i64, i64, i64) {
-        let m = if n > 0 {n-1} else {self.rc.len() - 1};
-        return self.rect(m, n);
-    }
-
-    // Check if all points along perimeter are inside the polygon.
-    // (Scan only unique row and column coordinates of interest.)
-    fn check_perimeter(&self, m:usize, n:usize) -> bool {
-        let (r0, r1, c0, c1) = self.rect(m, n);
-        for &r in self.rows.iter() {
-            if r0 <= r && r <= r1 {
-                if !self.contains((r, c0)) { return false; }
-                if !self.contains((r, c1)) { return false; }
-            }
-        }
-        for &c in self.cols.iter() {
-            if c0 <= c && c <= c1 {
-                if !self.contains((r0, c)) { return false; }
-                if !self.contains((r1, c)) { return false; }
-            }
-        }
-        return true;
-    }
-
-    // Check if a given point is inside the polygon.
-    fn contains(&self, rc:RowCol) -> bool {
-        // Any point on the perimeter is "inside" the polygon.
-        for m in 0..self.rc.len() {
-            let (r0, r1, c0, c1) = self.seg(m);
-            if r0 <= rc.0 && rc.0 <= r1 && c0 <= rc.1 && rc.1 <= c1 { return true; }
-        }
-        // For all other points, scan to far left and count crossings.
-        let mut count = 0usize;
-        for m in 0..self.rc.len() {
-            let (r0, r1, c0, c1) = self.seg(m);
-            if c0 == c1 && r0 <= rc.0 && rc.0 < r1 && rc.1 > c0 { count += 1; }
-        }
-        return (count % 2) == 1;
-    }
+            Point::new(rc[0], rc[1])
+        })
+        .collect()
+}
 
-    // Print a rectangle for debugging.
-    fn print(&self, m:usize, n:usize) {
-        let rmin = self.rows.iter().min().unwrap().clone();
-        let rmax = self.rows.iter().max().unwrap().clone();
-        let cmin = self.cols.iter().min().unwrap().clone();
-        let cmax = self.cols.iter().max().unwrap().clone();
-        let (r0, r1, c0, c1) = self.rect(m, n);
-        let ok = self.check_perimeter(m, n);
-        println!("Corners {}-{} = {} ({})", m, n, self.area(m, n), ok);
-        for r in rmin..=rmax {
-            let mut line = String::from("  ");
-            for c in cmin..=cmax {
-                line += if self.rc.contains(&(r,c)) {"#"}
-                    else if r0 <= r && r <= r1 && c0 <= c && c <= c1 {"O"}
-                    else {"."};
-            }
-            println!("{}", line);
-        }
-    }
+// Given two corners, find the area of the contained rectangle.
+fn area(a: &Point, b: &Point) -> i64 {
+    ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
 }
 
 fn part1(input: &str) -> i64 {
-    let poly = Polygon::new(input);
+    let red = parse(input);
     let mut best = 0i64;
-    for m in 0..poly.rc.len()-1 {
-        for n in m+1..poly.rc.len() {
-            let next = poly.area(m, n);
+    for m in 0..red.len()-1 {
+        for n in m+1..red.len() {
+            let next = area(&red[m], &red[n]);
             if next > best { best = next; }
         }
     }
@@ -123,18 +32,19 @@ fn part1(input: &str) -> i64 {
 }
 
 fn part2(input: &str) -> i64 {
-    let poly = Polygon::new(input);
+    let poly = Polygon::new(parse(input));
+    let red = poly.vertices();
     let mut best = 0i64;
-    for m in 0..poly.rc.len()-1 {
-        for n in m+1..poly.rc.len() {
-            let next = poly.area(m, n);
-            if DEBUG { poly.print(m, n); }
-            if next > best && poly.check_perimeter(m, n) {best = next; }
+    for m in 0..red.len()-1 {
+        for n in m+1..red.len() {
+            let next = area(&red[m], &red[n]);
+            if next > best && poly.contains_rect(red[m], red[n]) { best = next; }
         }
     }
     return best;
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     7,1
     11,1
@@ -145,15 +55,27 @@ const EXAMPLE: &'static str = "\
     2,3
     7,3";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 9).unwrap();
+    aoclib::main::<Day>(2025, 9);
+}
 
-    assert_eq!(part1(EXAMPLE), 50);
-    assert_eq!(part2(EXAMPLE), 24);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 50);
+        assert_eq!(part2(EXAMPLE), 24);
+    }
 }
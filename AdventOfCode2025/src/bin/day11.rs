@@ -1,10 +1,10 @@
 /// Advent of Code 2025, Day 11
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
+use aoclib::memo::Memo;
 use std::collections::HashMap;
 
-type Cache = HashMap<usize,usize>;
+type Cache = Memo<usize,usize>;
 
 const DEBUG:bool = false;
 
@@ -37,7 +37,7 @@ impl Graph {
         };
         for line in input.trim().lines() {
             let labels: Vec<&str> = line.trim().split(&[' ', ':']).collect();
-            let root = tmp.find_or_create(&labels[0]);
+            let root = tmp.find_or_create(labels[0]);
             for label in &labels[2..] {
                 let leaf = tmp.find_or_create(label);
                 tmp.nodes[root].fwd.push(leaf);
@@ -61,13 +61,13 @@ impl Graph {
 
     fn dfs(&self, node:usize, cache:&mut Cache) -> usize {
         // Depth first search with memoization.
-        if let Some(ct) = cache.get(&node) { return *ct; }
-        let total: usize = self.nodes[node].fwd.iter()
-            .map( |next| self.dfs(*next, cache) )
-            .sum();
-        if DEBUG { println!("{} -> {}", self.nodes[node].label, total); }
-        cache.insert(node, total);
-        return total;
+        cache.get_or_compute(node, |cache, &node| {
+            let total: usize = self.nodes[node].fwd.iter()
+                .map( |next| self.dfs(*next, cache) )
+                .sum();
+            if DEBUG { println!("{} -> {}", self.nodes[node].label, total); }
+            total
+        })
     }
 
     fn count_paths(&self, src_str:&str, dst_str:&str) -> usize {
@@ -93,6 +93,7 @@ fn part2(input: &str) -> usize {
          * graph.count_paths("fft", "out");
 }
 
+#[cfg(test)]
 const EXAMPLE1: &'static str = "\
     aaa: you hhh
     you: bbb ccc
@@ -104,6 +105,7 @@ const EXAMPLE1: &'static str = "\
     ggg: out
     hhh: ccc fff iii
     iii: out";
+#[cfg(test)]
 const EXAMPLE2: &'static str = "\
     svr: aaa bbb
     aaa: fft
@@ -119,15 +121,27 @@ const EXAMPLE2: &'static str = "\
     ggg: out
     hhh: out";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 11).unwrap();
+    aoclib::main::<Day>(2025, 11);
+}
 
-    assert_eq!(part1(EXAMPLE1), 5);
-    assert_eq!(part2(EXAMPLE2), 2);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE1), 5);
+        assert_eq!(part2(EXAMPLE2), 2);
+    }
 }
@@ -1,7 +1,6 @@
 /// Advent of Code 2025, Day 12
 /// Copyright 2025 by Alex Utter
 
-use aocfetch;
 use std::collections::HashSet;
 type RowCol = (usize, usize);
 
@@ -33,7 +32,7 @@ impl Grid {
             .filter_map( |s| s.parse::<usize>().ok() ).collect();
         return Grid {
             size: (x[0], x[1]),
-            count: x[2..].iter().cloned().collect(),
+            count: x[2..].to_vec(),
         };
     }
 
@@ -77,10 +76,11 @@ fn part1(input: &str) -> usize {
     Problem::new(input).part1()
 }
 
-fn part2(input: &str) -> usize {
+fn part2(_input: &str) -> usize {
     0
 }
 
+#[cfg(test)]
 const EXAMPLE: &'static str = "\
     0:
     ###
@@ -116,15 +116,27 @@ const EXAMPLE: &'static str = "\
     12x5: 1 0 1 0 2 2
     12x5: 1 0 1 0 3 2";
 
+/// Solution for the unified runner.
+pub struct Day;
+
+impl aoclib::Solution for Day {
+    type Input = String;
+    fn parse(input: &str) -> String {input.to_string()}
+    fn part1(input: &String) -> impl std::fmt::Display {part1(input)}
+    fn part2(input: &String) -> impl std::fmt::Display {part2(input)}
+}
+
 fn main() {
-    // Fetch input from server.
-    let input = aocfetch::get_data(2025, 12).unwrap();
+    aoclib::main::<Day>(2025, 12);
+}
 
-    assert_eq!(part1(EXAMPLE), 2);
-    assert_eq!(part2(EXAMPLE), 0);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let time = std::time::Instant::now();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-    println!("Elapsed time: {:.1?}", time.elapsed());
+    #[test]
+    fn examples() {
+        assert_eq!(part1(EXAMPLE), 2);
+        assert_eq!(part2(EXAMPLE), 0);
+    }
 }
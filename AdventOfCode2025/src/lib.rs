@@ -0,0 +1,41 @@
+//! Advent of Code 2025, all days as a library for the unified runner.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each day is still its own binary; this just gathers them in one place.
+
+#![allow(dead_code)]
+
+#[path = "bin/day01.rs"] pub mod day01;
+#[path = "bin/day02.rs"] pub mod day02;
+#[path = "bin/day03.rs"] pub mod day03;
+#[path = "bin/day04.rs"] pub mod day04;
+#[path = "bin/day05.rs"] pub mod day05;
+#[path = "bin/day06.rs"] pub mod day06;
+#[path = "bin/day07.rs"] pub mod day07;
+#[path = "bin/day08.rs"] pub mod day08;
+#[path = "bin/day09.rs"] pub mod day09;
+#[path = "bin/day10.rs"] pub mod day10;
+#[path = "bin/day11.rs"] pub mod day11;
+#[path = "bin/day12.rs"] pub mod day12;
+
+/// Recorded answers for this year, see "aoc verify".
+pub const ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Registry of solutions for this year.
+pub fn solutions() -> Vec<aoclib::Entry>
+{
+    vec![
+        aoclib::Entry::new::<day01::Day>(2025, 1),
+        aoclib::Entry::new::<day02::Day>(2025, 2),
+        aoclib::Entry::new::<day03::Day>(2025, 3),
+        aoclib::Entry::new::<day04::Day>(2025, 4),
+        aoclib::Entry::new::<day05::Day>(2025, 5),
+        aoclib::Entry::new::<day06::Day>(2025, 6),
+        aoclib::Entry::new::<day07::Day>(2025, 7),
+        aoclib::Entry::new::<day08::Day>(2025, 8),
+        aoclib::Entry::new::<day09::Day>(2025, 9),
+        aoclib::Entry::new::<day10::Day>(2025, 10),
+        aoclib::Entry::new::<day11::Day>(2025, 11),
+        aoclib::Entry::new::<day12::Day>(2025, 12),
+    ]
+}
@@ -0,0 +1,260 @@
+//! Unified runner for every Rust solution, across all years.
+//! Copyright 2025 by Alex Utter
+//!
+//! Examples:
+//!     aoc run 2016 11     Solve 2016 Day 11.
+//!     aoc run 2023 all    Solve every day from 2023.
+//!     aoc run all         Solve everything.
+//!     aoc verify 2021     Check every day from 2021 against recorded answers.
+//!     aoc bench 2023 17   Time each step of 2023 Day 17.
+
+use aoclib::{AnswerKey, BenchConfig, Check, Entry, History, STEPS};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc COMMAND [ARGS]
+
+Commands:
+    run YEAR [DAY|all]      Solve one day, or every day in a given year.
+    run all                 Solve every day from every year.
+    verify YEAR [DAY|all]   Check answers against each year's \"answers.toml\".
+    verify all              Check answers for every year.
+    bench YEAR [DAY|all]    Time each step, and compare to previous results.
+    bench all               Time each step for every year.
+    list [YEAR]             List available solutions.
+
+Options:
+    --record                With \"verify\", save any new answers.
+    --runs N                With \"bench\", number of timed runs (default 10).
+    --warmup N              With \"bench\", number of warm-up runs (default 1).
+    --history FILE          With \"bench\", results file (default \"bench_history.csv\").";
+
+/// Default file for benchmark history, in the top-level folder.
+fn default_history() -> PathBuf
+{
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("bench_history.csv")
+}
+
+type Registry = fn() -> Vec<Entry>;
+
+/// Each year's registry and recorded answers, in order.
+const YEARS: [(usize, Registry, &str); 8] = [
+    (2015, aoc2015::solutions, aoc2015::ANSWERS),
+    (2016, aoc2016::solutions, aoc2016::ANSWERS),
+    (2017, aoc2017::solutions, aoc2017::ANSWERS),
+    (2020, aoc2020::solutions, aoc2020::ANSWERS),
+    (2021, aoc2021::solutions, aoc2021::ANSWERS),
+    (2023, aoc2023::solutions, aoc2023::ANSWERS),
+    (2024, aoc2024::solutions, aoc2024::ANSWERS),
+    (2025, aoc2025::solutions, aoc2025::ANSWERS),
+];
+
+/// Every solution from every year, in order.
+fn registry() -> Vec<Entry>
+{
+    YEARS.iter().flat_map(|(_, solutions, _)| solutions()).collect()
+}
+
+/// Path to the recorded answers for a given year.
+fn answer_file(year: usize) -> &'static str
+{
+    YEARS.iter().find(|(y, _, _)| *y == year).map(|(_, _, path)| *path).unwrap()
+}
+
+/// Remove a flag from the argument list, returning true if it was present.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool
+{
+    let len = args.len();
+    args.retain(|a| a != name);
+    args.len() < len
+}
+
+/// Remove an option and its value from the argument list, if present.
+fn take_opt(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String>
+{
+    match args.iter().position(|a| a == name) {
+        Some(n) if n + 1 < args.len() => {
+            let value = args.remove(n + 1);
+            args.remove(n);
+            Ok(Some(value))
+        },
+        Some(_) => Err(format!("Missing value for {}", name)),
+        None => Ok(None),
+    }
+}
+
+/// As `take_opt`, for numeric options with a default value.
+fn take_num(args: &mut Vec<String>, name: &str, default: usize) -> Result<usize, String>
+{
+    match take_opt(args, name)? {
+        Some(x) => x.parse().map_err(|_| format!("Invalid {}: {}", name, x)),
+        None => Ok(default),
+    }
+}
+
+/// Parse an argument that's either a number or "all".
+fn parse_sel(arg: Option<&String>, name: &str) -> Result<Option<usize>, String>
+{
+    match arg.map(String::as_str) {
+        None | Some("all") => Ok(None),
+        Some(x) => x.parse().map(Some).map_err(|_| format!("Invalid {}: {}", name, x)),
+    }
+}
+
+/// Select solutions matching the given year and day, if specified.
+fn select(args: &[String]) -> Result<Vec<Entry>, String>
+{
+    let year = parse_sel(args.get(1), "YEAR")?;
+    let day = parse_sel(args.get(2), "DAY")?;
+    if year.is_none() && day.is_some() {return Err("Missing YEAR".to_string());}
+    let list: Vec<Entry> = registry().into_iter()
+        .filter(|e| year.is_none() || year == Some(e.year))
+        .filter(|e| day.is_none() || day == Some(e.day))
+        .collect();
+    if list.is_empty() {return Err("No matching solutions".to_string());}
+    Ok(list)
+}
+
+fn run(args: &[String]) -> Result<(), String>
+{
+    let list = select(args)?;
+    let mut failed = 0usize;
+    let mut total = Duration::ZERO;
+    for entry in list.iter() {
+        println!("{} Day {:02}", entry.year, entry.day);
+        match entry.run() {
+            Ok(result) => {
+                aoclib::print_answer(1, &result.part1);
+                aoclib::print_answer(2, &result.part2);
+                println!("Elapsed time: {:.1?}", result.elapsed());
+                total += result.elapsed();
+            },
+            Err(err) => {println!("Error: {}", err); failed += 1;},
+        }
+        println!();
+    }
+    if list.len() > 1 {println!("Total elapsed time: {:.1?}", total);}
+    if failed > 0 {Err(format!("{} day(s) failed", failed))} else {Ok(())}
+}
+
+fn verify(args: &[String]) -> Result<(), String>
+{
+    let mut args = args.to_vec();
+    let record = take_flag(&mut args, "--record");
+    let list = select(&args)?;
+    let (mut pass, mut fail, mut new, mut error) = (0usize, 0usize, 0usize, 0usize);
+    let mut keys: Vec<AnswerKey> = Vec::new();
+    for entry in list.iter() {
+        // Load each year's answers as we reach it.
+        if keys.last().map(|k| k.year()) != Some(entry.year) {
+            // Only "--record" may start a new file.
+            let path = answer_file(entry.year);
+            keys.push(if record && !Path::new(path).exists() {
+                AnswerKey::new(entry.year, path)
+            } else {
+                AnswerKey::load(entry.year, path)?
+            });
+        }
+        let key = keys.last_mut().unwrap();
+        let result = match entry.run() {
+            Ok(result) => result,
+            Err(err) => {
+                println!("{} Day {:02}  Error: {}", entry.year, entry.day, err);
+                error += 1; continue;
+            },
+        };
+        let checks = key.check(entry.day, &result);
+        println!("{} Day {:02}  Part 1: {:4}  Part 2: {:4}  ({:.1?})",
+            entry.year, entry.day, checks[0], checks[1], result.elapsed());
+        for (part, (chk, actual)) in checks.iter().zip([&result.part1, &result.part2]).enumerate() {
+            match chk {
+                Check::Pass | Check::Skip => {},
+                Check::Fail(expected) => {
+                    println!("    Part {}: expected {:?}, got {:?}", part+1, expected, actual);
+                    fail += 1;
+                },
+                Check::New => {
+                    println!("    Part {}: new answer {:?}", part+1, actual);
+                    new += 1;
+                },
+            }
+            if *chk == Check::Pass {pass += 1;}
+        }
+        if record {key.record(entry.day, &result);}
+    }
+    if record {
+        for key in keys.iter() {key.save()?;}
+    }
+    println!("\n{} passed, {} failed, {} new, {} error(s)", pass, fail, new, error);
+    if record && new > 0 {println!("Recorded {} new answer(s).", new);}
+    if fail + error > 0 {Err("Verification failed".to_string())} else {Ok(())}
+}
+
+fn bench(args: &[String]) -> Result<(), String>
+{
+    let mut args = args.to_vec();
+    let default = BenchConfig::default();
+    let cfg = BenchConfig {
+        runs: take_num(&mut args, "--runs", default.runs)?,
+        warmup: take_num(&mut args, "--warmup", default.warmup)?,
+    };
+    let path = take_opt(&mut args, "--history")?.map(PathBuf::from).unwrap_or_else(default_history);
+    let list = select(&args)?;
+    let mut history = History::load(path)?;
+    let mut failed = 0usize;
+    let mut total = Duration::ZERO;
+    for entry in list.iter() {
+        let input = match entry.input() {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{} Day {:02}  Error: {}", entry.year, entry.day, err);
+                failed += 1; continue;
+            },
+        };
+        let report = entry.bench(&input, cfg);
+        println!("{} Day {:02}  ({} runs)", entry.year, entry.day, report.runs);
+        for (step, timing) in STEPS.iter().zip(report.steps.iter()) {
+            let prev = history.last(entry.year, entry.day, step)
+                .map(|r| format!("  ({} vs. {:.1?})",
+                    aoclib::change(r.timing.median, timing.median), r.timing.median))
+                .unwrap_or_default();
+            println!("    {:6} min {:>10.1?}  median {:>10.1?}{}",
+                step, timing.min, timing.median, prev);
+        }
+        total += report.total();
+        history.append(&report)?;
+    }
+    if list.len() > 1 {println!("Total median time: {:.1?}", total);}
+    println!("Results saved to {}", history.path().display());
+    if failed > 0 {Err(format!("{} day(s) failed", failed))} else {Ok(())}
+}
+
+fn list(args: &[String]) -> Result<(), String>
+{
+    let year = parse_sel(args.get(1), "YEAR")?;
+    for entry in registry().iter() {
+        if year.is_some() && year != Some(entry.year) {continue;}
+        println!("{} Day {:02}  {:?}", entry.year, entry.day, entry.source);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode
+{
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run")     => run(&args),
+        Some("verify")  => verify(&args),
+        Some("bench")   => bench(&args),
+        Some("list")    => list(&args),
+        Some("-h" | "--help" | "help") => {println!("{}", USAGE); Ok(())},
+        Some(cmd)       => Err(format!("Unknown command: {}\n\n{}", cmd, USAGE)),
+        None            => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {eprintln!("{}", msg); ExitCode::FAILURE},
+    }
+}
@@ -0,0 +1,118 @@
+//! Cache management: list, remove, and verify cached files.
+//! Copyright 2023 by Alex Utter
+
+use crate::error::FetchError;
+use crate::fetcher::Fetcher;
+use crate::leaderboard::Leaderboard;
+use std::path::PathBuf;
+
+/// Messages the server sends instead of puzzle input.
+/// If one of these ends up in the cache, something went wrong.
+const BAD_INPUT: [&str; 4] = [
+    "Puzzle inputs differ by user",
+    "Please don't repeatedly request this endpoint",
+    "404 Not Found",
+    "Internal Server Error",
+];
+
+/// File types that belong to a single day.  Others, such as leaderboards,
+/// apply to the whole year even if their name ends in a number.
+const DAILY: [&str; 3] = ["input", "puzzle", "answers"];
+
+/// One file in the cache for the current account.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheEntry {
+    pub year: usize,
+    /// Puzzle day, or None for files that apply to the whole year.
+    pub day: Option<usize>,
+    /// File type, e.g., "input", "puzzle", "answers", "leaderboard".
+    pub kind: String,
+    pub path: PathBuf,
+    /// File size in bytes.
+    pub size: u64,
+}
+
+impl CacheEntry {
+    /// Parse a cache filename, e.g., "input_07.txt".
+    fn new(year: usize, path: PathBuf) -> Option<CacheEntry> {
+        let size = std::fs::metadata(&path).ok()?.len();
+        let stem = path.file_stem()?.to_str()?.to_string();
+        let (kind, suffix) = stem.split_once('_')?;
+        let day = if DAILY.contains(&kind) {suffix.parse().ok()} else {None};
+        Some(CacheEntry {
+            year,
+            day,
+            kind: kind.to_string(),
+            path,
+            size,
+        })
+    }
+
+    /// Check the contents of this file, returning a description of any problem.
+    pub fn verify(&self) -> Option<String> {
+        let data = match std::fs::read_to_string(&self.path) {
+            Ok(data) => data,
+            Err(err) => return Some(format!("Unreadable: {}", err)),
+        };
+        match self.kind.as_str() {
+            "input" => {
+                if data.trim().is_empty() {return Some("Empty input".to_string());}
+                if data.trim_start().starts_with('<') {return Some("Input is HTML".to_string());}
+                if let Some(msg) = BAD_INPUT.iter().find(|msg| data.contains(*msg)) {
+                    return Some(format!("Server message: \"{}\"", msg));
+                }
+                if !data.ends_with('\n') {return Some("Missing final newline".to_string());}
+                None
+            },
+            "puzzle" if !data.contains("<article") =>
+                Some("No puzzle description".to_string()),
+            "leaderboard" if self.path.extension().is_some_and(|x| x == "json") =>
+                Leaderboard::parse(&data).err().map(|err| err.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Fetcher {
+    /// List all cached files for the current account, sorted by year, day, and type.
+    pub fn cache_list(&self) -> Result<Vec<CacheEntry>, FetchError> {
+        let mut result = Vec::new();
+        let root = self.account_dir();
+        if !root.exists() {return Ok(result);}
+        for ydir in std::fs::read_dir(&root)? {
+            let ydir = ydir?.path();
+            let year = match ydir.file_name().and_then(|x| x.to_str()).and_then(|x| x.parse().ok()) {
+                Some(year) if ydir.is_dir() => year,
+                _ => continue,
+            };
+            for file in std::fs::read_dir(&ydir)? {
+                if let Some(entry) = CacheEntry::new(year, file?.path()) {
+                    result.push(entry);
+                }
+            }
+        }
+        result.sort_by(|a, b| (a.year, a.day, &a.kind, &a.path).cmp(&(b.year, b.day, &b.kind, &b.path)));
+        Ok(result)
+    }
+
+    /// Remove cached files for a given year (and optionally, a single day).
+    /// Attempt logs are kept, since they can't be recovered from the server.
+    /// Returns the number of files removed.
+    pub fn cache_remove(&self, year: usize, day: Option<usize>) -> Result<usize, FetchError> {
+        let mut count = 0usize;
+        for entry in self.cache_list()? {
+            if entry.year != year || entry.kind == "answers" {continue;}
+            if day.is_some() && entry.day != day {continue;}
+            std::fs::remove_file(&entry.path)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Check every cached file, returning a list of problems.
+    pub fn cache_verify(&self) -> Result<Vec<(CacheEntry, String)>, FetchError> {
+        Ok(self.cache_list()?.into_iter()
+            .filter_map(|entry| entry.verify().map(|msg| (entry, msg)))
+            .collect())
+    }
+}
@@ -0,0 +1,170 @@
+//! Polite HTTP client for the Advent of Code server.
+//! Copyright 2023 by Alex Utter
+//!
+//! The AoC maintainers ask that automated tools throttle their requests
+//! and identify themselves with a User-Agent that includes contact info:
+//! <https://www.reddit.com/r/adventofcode/wiki/faqs/automation>
+//!
+//! All requests share one client per User-Agent string.  Requests are spaced
+//! out using a timestamp file in the cache folder, which is locked while in use,
+//! so the limit applies across processes (e.g., several solvers at once).
+//! The retry policy is shared with the async client (see "nonblocking.rs").
+
+use crate::error::FetchError;
+use crate::fetcher::Fetcher;
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// User-Agent prefix identifying this crate.
+const USER_AGENT: &str = "github.com/ooterness/AdventOfCode/tree/main/aocfetch";
+
+/// HTTP status codes that are worth retrying.
+/// (The AoC server returns 500 for a bad session token, so that's not one.)
+const TRANSIENT: [u16; 3] = [502, 503, 504];
+
+/// The retry delay stops doubling after this many attempts.
+const MAX_DOUBLINGS: usize = 16;
+
+/// Timeout for each request.
+pub(crate) const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Shared clients, indexed by User-Agent string.
+pub(crate) type Clients<C> = OnceLock<Mutex<HashMap<String, C>>>;
+static CLIENTS: Clients<Client> = OnceLock::new();
+
+/// Full User-Agent string, with optional contact info.
+pub(crate) fn user_agent(contact: &Option<String>) -> String
+{
+    match contact {
+        Some(who) => format!("{} by {}", USER_AGENT, who),
+        None => USER_AGENT.to_string(),
+    }
+}
+
+/// Fetch the shared client for a given User-Agent, or create it using "build".
+pub(crate) fn shared_client<C, F>(clients: &Clients<C>, agent: &str, build: F)
+    -> Result<C, FetchError>
+    where C: Clone, F: FnOnce(&str) -> reqwest::Result<C>
+{
+    let mut clients = clients.get_or_init(Default::default)
+        .lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(client) = clients.get(agent) {return Ok(client.clone());}
+    let client = build(agent)?;
+    clients.insert(agent.to_string(), client.clone());
+    Ok(client)
+}
+
+/// Milliseconds since the UNIX epoch.
+fn now_msec() -> u64
+{
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|dt| dt.as_millis() as u64).unwrap_or(0)
+}
+
+/// Parse the "Retry-After" header, if present (in seconds).
+fn retry_after(headers: &HeaderMap) -> Option<Duration>
+{
+    headers.get(reqwest::header::RETRY_AFTER)
+        .and_then(|val| val.to_str().ok())
+        .and_then(|val| val.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Check the HTTP status of a reply, flagging rate limits as an error.
+pub(crate) fn check_status(status: u16, headers: &HeaderMap) -> Result<u16, FetchError>
+{
+    if status == 429 {return Err(FetchError::RateLimited(retry_after(headers)));}
+    Ok(status)
+}
+
+/// Retry policy for transient errors, with exponential backoff.
+pub(crate) struct Retry {
+    attempt: usize,
+    retries: usize,
+    backoff: Duration,
+}
+
+impl Retry {
+    pub(crate) fn new(retries: usize, backoff: Duration) -> Retry {
+        Retry { attempt: 0, retries, backoff }
+    }
+
+    /// Given the outcome of an attempt (HTTP status or error), return the
+    /// delay before trying again, or None to return the outcome as-is.
+    pub(crate) fn delay(&mut self, outcome: Result<u16, &reqwest::Error>) -> Option<Duration> {
+        let transient = match outcome {
+            Ok(status) => TRANSIENT.contains(&status),
+            Err(err) => err.is_timeout() || err.is_connect(),
+        };
+        if !transient || self.attempt >= self.retries {return None;}
+        let delay = self.backoff.saturating_mul(1u32 << self.attempt.min(MAX_DOUBLINGS));
+        self.attempt += 1;
+        Some(delay)
+    }
+}
+
+impl Fetcher {
+    /// Wait until at least "min_interval" has passed since the last request,
+    /// by this or any other process sharing the same cache folder.
+    pub(crate) fn throttle(&self) -> Result<(), FetchError> {
+        if self.min_interval.is_zero() {return Ok(());}
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let mut file = std::fs::OpenOptions::new()
+            .read(true).write(true).create(true).truncate(false)
+            .open(self.cache_dir.join("throttle.lock"))?;
+        // The lock is held until the file is closed, including while we sleep.
+        file.lock()?;
+        let mut prev = String::new();
+        file.read_to_string(&mut prev)?;
+        if let Ok(prev) = prev.trim().parse::<u64>() {
+            let next = prev + self.min_interval.as_millis() as u64;
+            let now = now_msec();
+            if now < next {std::thread::sleep(Duration::from_millis(next - now));}
+        }
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        write!(file, "{}", now_msec())?;
+        Ok(())
+    }
+
+    /// Send a request to the server, with throttling and retries.
+    /// The request is rebuilt for each attempt.  Returns HTTP status and body.
+    /// Only use this for requests that are safe to repeat (i.e., GET).
+    pub(crate) fn send<F>(&self, build: F) -> Result<(u16, String), FetchError>
+        where F: Fn(&Client) -> RequestBuilder
+    {
+        self.send_with(build, self.retries)
+    }
+
+    /// As "send", but never retries.  Use this for requests that change
+    /// something on the server (i.e., POST), since a request that timed out
+    /// may still have been received.
+    pub(crate) fn send_once<F>(&self, build: F) -> Result<(u16, String), FetchError>
+        where F: Fn(&Client) -> RequestBuilder
+    {
+        self.send_with(build, 0)
+    }
+
+    fn send_with<F>(&self, build: F, retries: usize) -> Result<(u16, String), FetchError>
+        where F: Fn(&Client) -> RequestBuilder
+    {
+        let client = shared_client(&CLIENTS, &user_agent(&self.contact), |agent|
+            Client::builder().user_agent(agent).timeout(TIMEOUT).build())?;
+        let mut retry = Retry::new(retries, self.backoff);
+        loop {
+            self.throttle()?;
+            let result = build(&client).send();
+            if let Some(dt) = retry.delay(result.as_ref().map(|r| r.status().as_u16())) {
+                std::thread::sleep(dt);
+                continue;
+            }
+            let response = result?;
+            let status = check_status(response.status().as_u16(), response.headers())?;
+            return Ok((status, response.text()?));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Error types for the aocfetch crate.
+//! Copyright 2023 by Alex Utter
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+/// Everything that can go wrong while talking to the Advent of Code server.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The "AOC_SESSION" environment variable is not set.
+    MissingSession,
+    /// The server rejected the session token (HTTP 400 or 500).
+    /// Usually this means the token has expired.  Includes the server's message.
+    BadSession(String),
+    /// The puzzle is not available yet (HTTP 404).  Includes the unlock time.
+    NotYetReleased(SystemTime),
+    /// The server asked us to slow down.  Includes the suggested delay, if known.
+    RateLimited(Option<Duration>),
+    /// Error reading or writing the local cache.
+    Io(std::io::Error),
+    /// Error connecting to the server.
+    Network(reqwest::Error),
+    /// Any other unexpected reply.  Includes the HTTP status and message.
+    Server(u16, String),
+    /// The request itself is invalid (e.g., part 3).
+    InvalidRequest(String),
+    /// The data isn't in the cache, and we're not allowed to fetch it.
+    Offline,
+    /// The data isn't available from a test source (e.g., a missing fixture).
+    NotFound(String),
+}
+
+/// Human-readable duration, e.g., "1d 2h 3m 4s".
+pub(crate) fn format_duration(dt: &Duration) -> String
+{
+    let secs = dt.as_secs();
+    let (d, h, m, s) = (secs / 86400, (secs / 3600) % 24, (secs / 60) % 60, secs % 60);
+    let mut result = String::new();
+    if d > 0 {result.push_str(&format!("{}d ", d));}
+    if d > 0 || h > 0 {result.push_str(&format!("{}h ", h));}
+    if d > 0 || h > 0 || m > 0 {result.push_str(&format!("{}m ", m));}
+    result.push_str(&format!("{}s", s));
+    result
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession =>
+                write!(f, "Missing AOC_SESSION environment variable. Instructions here:\n\
+                           https://pypi.org/project/advent-of-code-data/"),
+            FetchError::BadSession(msg) =>
+                write!(f, "Session token rejected, it may have expired: {}", msg.trim()),
+            FetchError::NotYetReleased(unlock) => match unlock.duration_since(SystemTime::now()) {
+                Ok(dt) => write!(f, "Puzzle not yet released, unlocks in {}", format_duration(&dt)),
+                Err(_) => write!(f, "Puzzle not found"),
+            },
+            FetchError::RateLimited(Some(dt)) =>
+                write!(f, "Rate limited, try again in {}", format_duration(dt)),
+            FetchError::RateLimited(None) =>
+                write!(f, "Rate limited, try again later"),
+            FetchError::Io(err) =>
+                write!(f, "Cache error: {}", err),
+            FetchError::Network(err) =>
+                write!(f, "Network error: {}", err),
+            FetchError::Server(status, msg) =>
+                write!(f, "Unexpected reply (HTTP {}): {}", status, msg.trim()),
+            FetchError::InvalidRequest(msg) =>
+                write!(f, "Invalid request: {}", msg),
+            FetchError::Offline =>
+                write!(f, "Not in cache, and offline mode is enabled (AOC_OFFLINE)"),
+            FetchError::NotFound(msg) =>
+                write!(f, "Not found: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Io(err) => Some(err),
+            FetchError::Network(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for FetchError {
+    fn from(err: std::io::Error) -> Self {FetchError::Io(err)}
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> Self {FetchError::Network(err)}
+}
@@ -0,0 +1,352 @@
+//! Configurable client for the Advent of Code server, with cache.
+//! Copyright 2023 by Alex Utter
+//!
+//! The cache is namespaced by account, so several people can share
+//! one machine (or one cache folder) without their inputs colliding,
+//! e.g., `{cache_dir}/{account}/{year}/input_{day}.txt`.
+//! The account name is a hash of the session token, unless set explicitly.
+//! Inputs saved by older versions, as `input/input_{year}_{day}.txt`,
+//! are still read and copied into the new layout as needed.
+
+use crate::error::FetchError;
+use crate::release;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Default address of the Advent of Code server.
+const DEFAULT_SERVER: &str = "https://adventofcode.com";
+
+/// Default minimum time between requests.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default retry policy for transient errors.
+const DEFAULT_RETRIES: usize = 3;
+const DEFAULT_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Folder used by older versions for cached inputs, relative to the
+/// working directory.
+const LEGACY_DIR: &str = "input";
+
+/// Account name used when there is no session token.
+const ANONYMOUS: &str = "anonymous";
+
+/// Default cache root, from the first of:
+///  * The "AOC_CACHE_DIR" environment variable.
+///  * The XDG cache folder ("$XDG_CACHE_HOME/aocfetch" or "~/.cache/aocfetch").
+///  * The Windows cache folder ("%LOCALAPPDATA%/aocfetch").
+///  * The "input" folder in the current working directory.
+fn default_cache_dir() -> PathBuf
+{
+    let env_path = |name: &str| std::env::var_os(name).filter(|x| !x.is_empty()).map(PathBuf::from);
+    if let Some(dir) = env_path("AOC_CACHE_DIR") {
+        dir
+    } else if let Some(dir) = env_path("XDG_CACHE_HOME") {
+        dir.join("aocfetch")
+    } else if let Some(dir) = env_path("HOME") {
+        dir.join(".cache").join("aocfetch")
+    } else if let Some(dir) = env_path("LOCALAPPDATA") {
+        dir.join("aocfetch")
+    } else {
+        PathBuf::from("input")
+    }
+}
+
+/// Stable 64-bit FNV-1a hash, so cache folders never change between builds.
+/// (Unlike std::hash::DefaultHasher, which makes no such promise.)
+fn fnv1a(data: &str) -> u64
+{
+    data.bytes().fold(0xcbf29ce484222325u64, |hash, b|
+        (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Is the given environment variable set to a "true" value?
+fn env_flag(name: &str) -> bool
+{
+    match std::env::var(name) {
+        Ok(val) => !matches!(val.trim().to_lowercase().as_str(), "" | "0" | "false" | "no"),
+        Err(_) => false,
+    }
+}
+
+/// Cache namespace for a given session token.
+fn account_name(session: &Option<String>) -> String
+{
+    match session {
+        Some(tok) => format!("{:016x}", fnv1a(tok)),
+        None => ANONYMOUS.to_string(),
+    }
+}
+
+/// Write data to a local cache file, creating folders as needed.
+pub(crate) fn write_cache_file(filename: &Path, data: &str) -> Result<(), FetchError>
+{
+    if let Some(parent) = filename.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(filename, data)?;
+    Ok(())
+}
+
+/// Sort server replies by HTTP response code.
+/// We could use "error_from_response()", but custom error messages
+/// from the AoC server are much easier for end-users to understand.
+pub(crate) fn check_reply(year: &usize, day: &usize, status: u16, data: String)
+    -> Result<String, FetchError>
+{
+    match status {
+        // 200 OK indicates the message is the requested data.
+        200 => Ok(data),
+        // Bad or expired login token.
+        400 | 500 => Err(FetchError::BadSession(data)),
+        // Problem not yet posted.
+        404 => Err(FetchError::NotYetReleased(release::unlock_time(*year, *day))),
+        // Slow down!
+        429 => Err(FetchError::RateLimited(None)),
+        // Anything else is unexpected, but the message is human-readable.
+        _ => Err(FetchError::Server(status, data)),
+    }
+}
+
+/// Client for the Advent of Code server, with a local cache.
+///
+/// Defaults are taken from the environment, and can be overridden:
+/// ```no_run
+/// let fetcher = aocfetch::Fetcher::new()
+///     .cache_dir("/tmp/aoc")
+///     .session("0123abcd");
+/// let input = fetcher.get_data(2023, 1).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fetcher {
+    pub(crate) cache_dir: PathBuf,
+    legacy_dir: PathBuf,
+    session: Option<String>,
+    account: String,
+    server: String,
+    offline: bool,
+    wait: bool,
+    pub(crate) contact: Option<String>,
+    pub(crate) min_interval: Duration,
+    pub(crate) retries: usize,
+    pub(crate) backoff: Duration,
+}
+
+impl Default for Fetcher {
+    fn default() -> Self {Fetcher::new()}
+}
+
+impl Fetcher {
+    /// New Fetcher using the "AOC_SESSION", "AOC_CACHE_DIR", "AOC_SERVER",
+    /// "AOC_OFFLINE", and "AOC_CONTACT" environment variables, where present.
+    pub fn new() -> Fetcher {
+        let session = std::env::var("AOC_SESSION").ok()
+            .map(|tok| tok.trim().to_string())
+            .filter(|tok| !tok.is_empty());
+        let server = std::env::var("AOC_SERVER")
+            .unwrap_or(DEFAULT_SERVER.to_string());
+        Fetcher {
+            cache_dir: default_cache_dir(),
+            legacy_dir: PathBuf::from(LEGACY_DIR),
+            account: account_name(&session),
+            session,
+            server: server.trim().trim_end_matches('/').to_string(),
+            offline: env_flag("AOC_OFFLINE"),
+            wait: false,
+            contact: std::env::var("AOC_CONTACT").ok()
+                .map(|who| who.trim().to_string())
+                .filter(|who| !who.is_empty()),
+            min_interval: DEFAULT_INTERVAL,
+            retries: DEFAULT_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+        }
+    }
+
+    /// Set the root folder for the local cache.
+    pub fn cache_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.cache_dir = dir.into();
+        self
+    }
+
+    /// Set the folder for inputs cached by older versions of this crate
+    /// (default "input").  These are not namespaced by account.
+    pub fn legacy_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.legacy_dir = dir.into();
+        self
+    }
+
+    /// Set the session token, which also sets the default account name.
+    pub fn session(mut self, token: &str) -> Self {
+        let token = token.trim();
+        self.session = if token.is_empty() {None} else {Some(token.to_string())};
+        self.account = account_name(&self.session);
+        self
+    }
+
+    /// Override the account name used to namespace the cache.
+    pub fn account(mut self, name: &str) -> Self {
+        self.account = name.to_string();
+        self
+    }
+
+    /// Set the server address (e.g., a local stand-in for testing).
+    pub fn server(mut self, url: &str) -> Self {
+        self.server = url.trim().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Offline mode never contacts the server, only reading from the cache.
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
+    /// Allow network access, even if "AOC_OFFLINE" is set.
+    pub fn online(mut self) -> Self {
+        self.offline = false;
+        self
+    }
+
+    /// If enabled, requests for unreleased puzzles block until the unlock time.
+    /// Otherwise (default), they fail immediately with NotYetReleased.
+    pub fn wait_for_release(mut self, wait: bool) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// Contact info (e.g., email or GitHub username) for the User-Agent header,
+    /// so the AoC maintainers can get in touch if something goes wrong.
+    pub fn contact(mut self, who: &str) -> Self {
+        let who = who.trim();
+        self.contact = if who.is_empty() {None} else {Some(who.to_string())};
+        self
+    }
+
+    /// Minimum time between requests to the server (default 2 seconds).
+    /// This limit is shared by all processes using the same cache folder.
+    pub fn min_interval(mut self, delay: Duration) -> Self {
+        self.min_interval = delay;
+        self
+    }
+
+    /// Retry policy for transient errors (default 3 retries, starting at 2 seconds).
+    /// The delay doubles after each failed attempt, up to 16 times.
+    pub fn retries(mut self, count: usize, backoff: Duration) -> Self {
+        self.retries = count;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Is this Fetcher in offline mode?
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Cache folder for the current account.
+    pub fn account_dir(&self) -> PathBuf {
+        self.cache_dir.join(&self.account)
+    }
+
+    /// Cache filename for a given year/day and file type.
+    pub(crate) fn cache_path(&self, year: &usize, day: &usize, kind: &str, ext: &str) -> PathBuf {
+        self.account_dir()
+            .join(year.to_string())
+            .join(format!("{}_{:02}.{}", kind, day, ext))
+    }
+
+    /// Cache filename for a given year/day input, as used by older versions.
+    fn legacy_path(&self, year: &usize, day: &usize) -> PathBuf {
+        self.legacy_dir.join(format!("input_{}_{:02}.txt", year, day))
+    }
+
+    /// Full URL for a given path on the server.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{}", self.server, path)
+    }
+
+    /// Session cookie, if we have a session token.
+    pub(crate) fn cookie(&self) -> Result<String, FetchError> {
+        // Manually build header so we don't need reqwest::cookie.
+        match &self.session {
+            Some(tok) => Ok(format!("session={}", tok)),
+            None => Err(FetchError::MissingSession),
+        }
+    }
+
+    /// Check whether we're allowed to ask the server about a given puzzle.
+    /// Call this before any network access, after checking the cache.
+    pub(crate) fn check_online(&self, year: &usize, day: &usize) -> Result<(), FetchError> {
+        if let Some(dt) = self.release_wait(year, day)? {std::thread::sleep(dt);}
+        Ok(())
+    }
+
+    /// As "check_online", but returns the required wait instead of sleeping.
+    pub(crate) fn release_wait(&self, year: &usize, day: &usize)
+        -> Result<Option<Duration>, FetchError>
+    {
+        if self.offline {return Err(FetchError::Offline);}
+        match release::time_until_release(*year, *day)? {
+            Some(_) if !self.wait =>
+                Err(FetchError::NotYetReleased(release::unlock_time(*year, *day))),
+            // Allow a little extra time for clock skew.
+            Some(dt) => Ok(Some(dt + Duration::from_secs(1))),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch input for a given year/day from the local cache.
+    /// Inputs from the legacy cache are copied to the current layout,
+    /// leaving the original in place.
+    pub(crate) fn read_from_cache(&self, year: &usize, day: &usize) -> Option<String> {
+        let filename = self.cache_path(year, day, "input", "txt");
+        if let Ok(data) = std::fs::read_to_string(&filename) {return Some(data);}
+        let data = std::fs::read_to_string(self.legacy_path(year, day)).ok()?;
+        // Failure to copy is harmless, since the original is still there.
+        let _ = write_cache_file(&filename, &data);
+        Some(data)
+    }
+
+    /// Fetch input for a given year/day from the Advent of Code server.
+    pub(crate) fn read_from_web(&self, year: &usize, day: &usize) -> Result<String, FetchError> {
+        let tok = self.cookie()?;
+        let url = self.url(&format!("/{}/day/{}/input", year, day));
+
+        // Attempt to fetch the input data from the AoC server.
+        let (status, data) = self.send(|client| client.get(&url)
+            .header(reqwest::header::COOKIE, &tok))?;
+        check_reply(year, day, status, data)
+    }
+
+    /// Fetch input for a given year/day from cache if available.
+    /// Otherwise, download from server and update local cache.
+    pub fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        // Use local cache if possible.
+        release::release_time(year, day)?;
+        if let Some(data) = self.read_from_cache(&year, &day) {return Ok(data);}
+
+        // Attempt to fetch from server...
+        self.check_online(&year, &day)?;
+        let data = self.read_from_web(&year, &day)?;
+
+        // If successful, update cache before returning.
+        write_cache_file(&self.cache_path(&year, &day, "input", "txt"), &data)?;
+        Ok(data)
+    }
+
+    /// Check the session token, returning the user name shown by the server.
+    /// Anonymous users are shown as "(anonymous user #1234)".
+    pub fn whoami(&self) -> Result<String, FetchError> {
+        if self.offline {return Err(FetchError::Offline);}
+        let tok = self.cookie()?;
+        let url = self.url("/");
+        let (status, page) = self.send(|client| client.get(&url)
+            .header(reqwest::header::COOKIE, &tok))?;
+        let page = check_reply(&release::FIRST_YEAR, &1, status, page)?;
+        // Logged-in users have a header like:
+        //  <div class="user">Name <span class="star-count">42*</span></div>
+        let name = page.split_once("<div class=\"user\">")
+            .map(|(_, rest)| rest.split(['<', '\n']).next().unwrap_or(""))
+            .map(|name| crate::html::decode_entities(name).trim().to_string())
+            .filter(|name| !name.is_empty());
+        name.ok_or(FetchError::BadSession("Not logged in".to_string()))
+    }
+}
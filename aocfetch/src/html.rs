@@ -0,0 +1,134 @@
+//! Minimal HTML-to-text conversion for Advent of Code pages.
+//! Copyright 2023 by Alex Utter
+//!
+//! The puzzle pages use a small and very regular subset of HTML,
+//! so there's no need for a full parser.
+
+/// Replace character entities (e.g., "&lt;") with the equivalent text.
+pub fn decode_entities(html: &str) -> String
+{
+    let mut result = String::new();
+    let mut rest = html;
+    while let Some(n) = rest.find('&') {
+        result.push_str(&rest[..n]);
+        rest = &rest[n..];
+        let semi = rest.find(';').filter(|&m| m < 10);
+        let decoded = semi.and_then(|m| decode_one(&rest[1..m]));
+        if let (Some(m), Some(ch)) = (semi, decoded) {
+            result.push(ch);
+            rest = &rest[m+1..];
+        } else {
+            result.push('&');
+            rest = &rest[1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Decode a single entity name, not including the "&" and ";".
+fn decode_one(name: &str) -> Option<char>
+{
+    match name {
+        "lt"    => Some('<'),
+        "gt"    => Some('>'),
+        "amp"   => Some('&'),
+        "quot"  => Some('"'),
+        "apos"  => Some('\''),
+        "nbsp"  => Some(' '),
+        _ => {
+            let num = name.strip_prefix('#')?;
+            let code = if let Some(hex) = num.strip_prefix('x') {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                num.parse::<u32>().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Remove all tags, keeping only the enclosed text.
+pub fn strip_tags(html: &str) -> String
+{
+    let mut result = String::new();
+    let mut rest = html;
+    while let Some(n) = rest.find('<') {
+        result.push_str(&rest[..n]);
+        match rest[n..].find('>') {
+            Some(m) => rest = &rest[n+m+1..],
+            None    => {rest = ""; break;}
+        }
+    }
+    result.push_str(rest);
+    decode_entities(&result)
+}
+
+/// Lowercase name of a tag, e.g., "<p class=x>" -> "p", "</pre>" -> "/pre".
+fn tag_name(tag: &str) -> String
+{
+    tag.trim_start_matches('<')
+        .trim_end_matches('>')
+        .split(|c: char| c.is_whitespace() || c == '>')
+        .next().unwrap_or("")
+        .trim_end_matches('/')
+        .to_lowercase()
+}
+
+/// Convert HTML to plain text, keeping line breaks and preformatted blocks.
+pub fn to_text(html: &str) -> String
+{
+    let mut result = String::new();
+    let mut in_pre = 0usize;
+    let mut rest = html;
+    loop {
+        // Copy text up to the next tag.
+        let n = rest.find('<').unwrap_or(rest.len());
+        let text = decode_entities(&rest[..n]);
+        if in_pre > 0 {
+            result.push_str(&text);
+        } else {
+            // Outside <pre>, runs of whitespace collapse to a single space.
+            let words: Vec<&str> = text.split_whitespace().collect();
+            if text.starts_with(char::is_whitespace) {push_space(&mut result);}
+            result.push_str(&words.join(" "));
+            if !words.is_empty() && text.ends_with(char::is_whitespace) {push_space(&mut result);}
+        }
+        if n == rest.len() {break;}
+        // Interpret the tag itself.
+        let m = rest[n..].find('>').map(|m| n + m + 1).unwrap_or(rest.len());
+        match tag_name(&rest[n..m]).as_str() {
+            "pre"   => {in_pre += 1; new_paragraph(&mut result);},
+            "/pre"  => {in_pre = in_pre.saturating_sub(1); new_paragraph(&mut result);},
+            "br"    => new_line(&mut result),
+            "li"    => {new_line(&mut result); result.push_str("  - ");},
+            "p" | "/p" | "h2" | "/h2" | "ul" | "/ul" | "article" | "/article" | "div" | "/div"
+                    => new_paragraph(&mut result),
+            _       => (),
+        }
+        rest = &rest[m..];
+    }
+    // Tidy up trailing spaces on each line.
+    let lines: Vec<&str> = result.lines().map(|line| line.trim_end()).collect();
+    lines.join("\n").trim().to_string() + "\n"
+}
+
+/// Add a single space, unless we're at the start of a line.
+fn push_space(result: &mut String)
+{
+    if !result.is_empty() && !result.ends_with(char::is_whitespace) {result.push(' ');}
+}
+
+/// Start a new line, if we're not already at the start of one.
+fn new_line(result: &mut String)
+{
+    while result.ends_with(' ') {result.pop();}
+    if !result.is_empty() && !result.ends_with('\n') {result.push('\n');}
+}
+
+/// Start a new paragraph, separated from the previous one by a blank line.
+fn new_paragraph(result: &mut String)
+{
+    new_line(result);
+    if !result.is_empty() && !result.ends_with("\n\n") {result.push('\n');}
+}
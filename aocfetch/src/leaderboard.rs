@@ -0,0 +1,241 @@
+//! Private leaderboards and personal statistics, with cache.
+//! Copyright 2023 by Alex Utter
+//!
+//! The AoC maintainers ask that the leaderboard API is not polled more than
+//! once every 15 minutes, so both pages are cached for at least that long.
+
+use crate::error::FetchError;
+use crate::fetcher::{check_reply, write_cache_file, Fetcher};
+use crate::html;
+use crate::release;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Minimum time between refreshes of any leaderboard page.
+pub const MIN_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// One star, as reported by the private leaderboard.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Star {
+    /// Time the star was earned, in seconds since the UNIX epoch.
+    pub get_star_ts: u64,
+    /// Server-wide sequence number, useful for breaking ties.
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+/// One member of a private leaderboard.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Display name, or None for anonymous users.
+    pub name: Option<String>,
+    pub stars: usize,
+    pub local_score: u64,
+    #[serde(default)]
+    pub global_score: u64,
+    /// Time of the most recent star (or zero), in seconds since the UNIX epoch.
+    pub last_star_ts: u64,
+    /// Stars earned, indexed by day and then by part.
+    pub completion_day_level: BTreeMap<usize, BTreeMap<usize, Star>>,
+}
+
+impl Member {
+    /// Display name, using the same format as the website for anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// The star for a given day and part, if earned.
+    pub fn star(&self, day: usize, part: usize) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part)
+    }
+
+    /// Time a given star was earned, if any.
+    pub fn star_time(&self, day: usize, part: usize) -> Option<SystemTime> {
+        self.star(day, part).map(|s| SystemTime::UNIX_EPOCH + Duration::from_secs(s.get_star_ts))
+    }
+
+    /// Time from puzzle release to earning a given star.
+    pub fn solve_time(&self, year: usize, day: usize, part: usize) -> Option<Duration> {
+        let unlock = release::release_time(year, day).ok()?;
+        self.star_time(day, part)?.duration_since(unlock).ok()
+    }
+
+    /// Time between the first and second star on a given day.
+    pub fn delta(&self, day: usize) -> Option<Duration> {
+        let t1 = self.star(day, 1)?.get_star_ts;
+        let t2 = self.star(day, 2)?.get_star_ts;
+        Some(Duration::from_secs(t2.saturating_sub(t1)))
+    }
+}
+
+/// A private leaderboard, as reported by the JSON API.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    /// Members indexed by user ID.
+    pub members: BTreeMap<u64, Member>,
+}
+
+impl Leaderboard {
+    /// Parse the JSON leaderboard data.
+    pub fn parse(json: &str) -> Result<Leaderboard, FetchError> {
+        serde_json::from_str(json)
+            .map_err(|err| FetchError::Server(200, format!("Bad leaderboard data: {}", err)))
+    }
+
+    /// Members sorted by local score, highest first.
+    /// Ties go to whoever reached that score first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut list: Vec<&Member> = self.members.values().collect();
+        list.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        list
+    }
+
+    /// Recalculate everyone's local score from the star timestamps.
+    /// For each star, the first member to earn it receives N points (where N is
+    /// the number of members), the second receives N-1 points, and so on.
+    pub fn local_scores(&self) -> BTreeMap<u64, u64> {
+        let mut scores: BTreeMap<u64, u64> = self.members.keys().map(|id| (*id, 0)).collect();
+        let size = self.members.len() as u64;
+        let days: std::collections::BTreeSet<usize> = self.members.values()
+            .flat_map(|m| m.completion_day_level.keys().cloned()).collect();
+        for day in days.iter() {
+            for part in 1..3 {
+                let mut solved: Vec<(&Star, u64)> = self.members.values()
+                    .filter_map(|m| m.star(*day, part).map(|s| (s, m.id))).collect();
+                solved.sort_by_key(|(s, id)| (s.get_star_ts, s.star_index, *id));
+                for (rank, (_, id)) in solved.iter().enumerate() {
+                    *scores.get_mut(id).unwrap() += size - rank as u64;
+                }
+            }
+        }
+        scores
+    }
+}
+
+/// Statistics for one part of one puzzle, from the personal leaderboard.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartStats {
+    /// Time from release to solution, or None if more than 24 hours.
+    pub time: Option<Duration>,
+    /// Global rank, if shown.
+    pub rank: Option<u64>,
+    /// Global leaderboard points, if shown.
+    pub score: Option<u64>,
+}
+
+/// Statistics for one day, from the personal leaderboard.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DayStats {
+    pub day: usize,
+    pub part1: Option<PartStats>,
+    pub part2: Option<PartStats>,
+}
+
+/// Parse a time from the personal leaderboard, e.g., "01:02:03" or ">24h".
+fn parse_time(tok: &str) -> Option<Duration>
+{
+    let hms: Vec<u64> = tok.split(':').filter_map(|x| x.parse().ok()).collect();
+    if hms.len() == 3 {
+        Some(Duration::from_secs(3600 * hms[0] + 60 * hms[1] + hms[2]))
+    } else {
+        None
+    }
+}
+
+/// Parse one part's columns, or None if it hasn't been solved ("-").
+fn parse_part(tok: &[&str]) -> Option<PartStats>
+{
+    if tok.is_empty() || tok[0] == "-" {return None;}
+    Some(PartStats {
+        time: parse_time(tok[0]),
+        rank: tok.get(1).and_then(|x| x.parse().ok()),
+        score: tok.get(2).and_then(|x| x.parse().ok()),
+    })
+}
+
+/// Parse the personal leaderboard page, i.e., "/{year}/leaderboard/self".
+/// Days are listed in the same order as the website (most recent first).
+pub fn parse_personal_stats(page: &str) -> Vec<DayStats>
+{
+    let text = html::strip_tags(page);
+    text.lines().filter_map(|line| {
+        let tok: Vec<&str> = line.split_whitespace().collect();
+        let day: usize = tok.first()?.parse().ok()?;
+        // Each part has the same number of columns: Time, Rank, Score
+        // (Recent events only show the time, so allow for that too.)
+        let width = (tok.len() - 1) / 2;
+        if !(1..=3).contains(&width) || tok.len() != 2 * width + 1 {return None;}
+        Some(DayStats {
+            day,
+            part1: parse_part(&tok[1..width+1]),
+            part2: parse_part(&tok[width+1..]),
+        })
+    }).collect()
+}
+
+/// Is the given cache file less than MIN_REFRESH old?
+fn is_fresh(path: &Path) -> bool
+{
+    let modified = std::fs::metadata(path).and_then(|m| m.modified());
+    match modified.map(|t| t.elapsed()) {
+        Ok(Ok(age)) => age < MIN_REFRESH,
+        Ok(Err(_)) => true,     // Modified in the future?
+        Err(_) => false,        // Not in cache
+    }
+}
+
+impl Fetcher {
+    /// Fetch a leaderboard page from the cache if it is recent enough,
+    /// or in offline mode.  Otherwise, download and update local cache.
+    fn read_leaderboard(&self, year: usize, path: &str, filename: &Path)
+        -> Result<String, FetchError>
+    {
+        release::release_time(year, 1)?;
+        if is_fresh(filename) || self.is_offline() {
+            if let Ok(data) = std::fs::read_to_string(filename) {return Ok(data);}
+        }
+        self.check_online(&year, &1)?;
+        let tok = self.cookie()?;
+        let url = self.url(path);
+        let (status, data) = self.send(|client| client.get(&url)
+            .header(reqwest::header::COOKIE, &tok))?;
+        let data = check_reply(&year, &1, status, data)?;
+        write_cache_file(filename, &data)?;
+        Ok(data)
+    }
+
+    /// Fetch and parse a private leaderboard, with the ID from its URL.
+    /// Requires a session token with access to that leaderboard.
+    pub fn get_leaderboard(&self, year: usize, id: u64) -> Result<Leaderboard, FetchError> {
+        let filename = self.account_dir()
+            .join(year.to_string())
+            .join(format!("leaderboard_{}.json", id));
+        let path = format!("/{}/leaderboard/private/view/{}.json", year, id);
+        let json = self.read_leaderboard(year, &path, &filename)?;
+        // Without access, the server redirects to an HTML page instead.
+        if json.trim_start().starts_with('<') {
+            let _ = std::fs::remove_file(&filename);
+            return Err(FetchError::BadSession(format!("No access to leaderboard {}", id)));
+        }
+        Leaderboard::parse(&json)
+    }
+
+    /// Fetch and parse personal statistics for a given year.
+    pub fn get_personal_stats(&self, year: usize) -> Result<Vec<DayStats>, FetchError> {
+        let filename = self.account_dir()
+            .join(year.to_string())
+            .join("leaderboard_self.html");
+        let path = format!("/{}/leaderboard/self", year);
+        let page = self.read_leaderboard(year, &path, &filename)?;
+        Ok(parse_personal_stats(&page))
+    }
+}
@@ -0,0 +1,153 @@
+//! Command-line interface for the aocfetch crate.
+//! Copyright 2023 by Alex Utter
+//!
+//! Lets scripts and editors warm the cache without compiling a solver.
+//! Uses the same environment variables as the library (AOC_SESSION, etc.)
+
+use aocfetch::{FetchError, Fetcher, Verdict};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: aocfetch [--offline] COMMAND [ARGS]
+
+Commands:
+    fetch YEAR DAY [-o FILE]        Print puzzle input, or save it to FILE.
+    prefetch YEAR                   Download input for every unlocked day.
+    submit YEAR DAY PART ANSWER     Submit an answer and print the verdict.
+    cache ls [YEAR]                 List cached files for this account.
+    cache rm YEAR [DAY]             Remove cached files (except attempt logs).
+    cache verify                    Check cached files for server errors.
+    whoami                          Check the session token.
+
+Options:
+    --offline       Read from the cache only (same as AOC_OFFLINE=1).";
+
+/// Parse a numeric argument, with a helpful error message.
+fn parse_num(args: &[String], idx: usize, name: &str) -> Result<usize, String>
+{
+    let arg = args.get(idx).ok_or(format!("Missing {}", name))?;
+    arg.parse().map_err(|_| format!("Invalid {}: {}", name, arg))
+}
+
+/// Parse an optional numeric argument.
+fn parse_opt(args: &[String], idx: usize, name: &str) -> Result<Option<usize>, String>
+{
+    if idx < args.len() {parse_num(args, idx, name).map(Some)} else {Ok(None)}
+}
+
+fn fetch(fetcher: &Fetcher, args: &[String]) -> Result<(), String>
+{
+    let year = parse_num(args, 1, "YEAR")?;
+    let day = parse_num(args, 2, "DAY")?;
+    let data = fetcher.get_data(year, day).map_err(|e| e.to_string())?;
+    match args.get(3).map(String::as_str) {
+        None => print!("{}", data),
+        Some("-o") => {
+            let path = args.get(4).ok_or("Missing FILE")?;
+            std::fs::write(path, &data).map_err(|e| format!("{}: {}", path, e))?;
+        },
+        Some(arg) => return Err(format!("Unexpected argument: {}", arg)),
+    }
+    Ok(())
+}
+
+fn prefetch(fetcher: &Fetcher, args: &[String]) -> Result<(), String>
+{
+    let year = parse_num(args, 1, "YEAR")?;
+    aocfetch::release_time(year, 1).map_err(|e| e.to_string())?;
+    let mut failed = 0usize;
+    for day in 1..=aocfetch::last_day(year) {
+        if !aocfetch::is_released(year, day) {break;}
+        match fetcher.get_data(year, day) {
+            Ok(data) => println!("{} Day {:02}: OK ({} bytes)", year, day, data.len()),
+            // No point continuing if the session token is bad.
+            Err(err @ (FetchError::MissingSession | FetchError::BadSession(_)
+                | FetchError::RateLimited(_))) => return Err(err.to_string()),
+            Err(err) => {println!("{} Day {:02}: {}", year, day, err); failed += 1;},
+        }
+    }
+    if failed > 0 {Err(format!("{} day(s) failed", failed))} else {Ok(())}
+}
+
+fn submit(fetcher: &Fetcher, args: &[String]) -> Result<(), String>
+{
+    let year = parse_num(args, 1, "YEAR")?;
+    let day = parse_num(args, 2, "DAY")?;
+    let part = parse_num(args, 3, "PART")?;
+    let answer = args.get(4).ok_or("Missing ANSWER")?;
+    let verdict = fetcher.submit(year, day, part, answer).map_err(|e| e.to_string())?;
+    match verdict {
+        Verdict::Correct        => println!("Correct!"),
+        Verdict::TooHigh        => return Err("Wrong, answer is too high".to_string()),
+        Verdict::TooLow         => return Err("Wrong, answer is too low".to_string()),
+        Verdict::Wrong          => return Err("Wrong answer".to_string()),
+        Verdict::RateLimited(dt) =>
+            return Err(FetchError::RateLimited(Some(dt)).to_string()),
+        Verdict::AlreadySolved  => println!("Already solved"),
+    }
+    Ok(())
+}
+
+fn cache(fetcher: &Fetcher, args: &[String]) -> Result<(), String>
+{
+    match args.get(1).map(String::as_str) {
+        Some("ls") => {
+            let year = parse_opt(args, 2, "YEAR")?;
+            for entry in fetcher.cache_list().map_err(|e| e.to_string())? {
+                if year.is_some() && year != Some(entry.year) {continue;}
+                println!("{:>8}  {}", entry.size, entry.path.display());
+            }
+        },
+        Some("rm") => {
+            let year = parse_num(args, 2, "YEAR")?;
+            let day = parse_opt(args, 3, "DAY")?;
+            let count = fetcher.cache_remove(year, day).map_err(|e| e.to_string())?;
+            println!("Removed {} file(s)", count);
+        },
+        Some("verify") => {
+            let problems = fetcher.cache_verify().map_err(|e| e.to_string())?;
+            for (entry, msg) in problems.iter() {
+                println!("{}: {}", entry.path.display(), msg);
+            }
+            if !problems.is_empty() {
+                return Err(format!("{} problem(s) found, use \"cache rm\" to refetch", problems.len()));
+            }
+            println!("Cache OK");
+        },
+        Some(cmd) => return Err(format!("Unknown cache command: {}", cmd)),
+        None => return Err("Missing cache command".to_string()),
+    }
+    Ok(())
+}
+
+fn whoami(fetcher: &Fetcher) -> Result<(), String>
+{
+    let name = fetcher.whoami().map_err(|e| e.to_string())?;
+    println!("{}", name);
+    println!("Cache: {}", fetcher.account_dir().display());
+    Ok(())
+}
+
+fn main() -> ExitCode
+{
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let mut fetcher = Fetcher::new();
+    if let Some(idx) = args.iter().position(|x| x == "--offline") {
+        args.remove(idx);
+        fetcher = fetcher.offline();
+    }
+    let result = match args.first().map(String::as_str) {
+        Some("fetch")       => fetch(&fetcher, &args),
+        Some("prefetch")    => prefetch(&fetcher, &args),
+        Some("submit")      => submit(&fetcher, &args),
+        Some("cache")       => cache(&fetcher, &args),
+        Some("whoami")      => whoami(&fetcher),
+        Some("-h" | "--help" | "help") => {println!("{}", USAGE); Ok(())},
+        Some(cmd)           => Err(format!("Unknown command: {}\n\n{}", cmd, USAGE)),
+        None                => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {eprintln!("{}", msg); ExitCode::FAILURE},
+    }
+}
@@ -0,0 +1,89 @@
+//! Async API for the Advent of Code server, enabled by the "async" feature.
+//! Copyright 2023 by Alex Utter
+//!
+//! Uses the same cache, session handling, throttling, and retry policy as
+//! the blocking API, so the two can be mixed freely.  Requires a Tokio runtime.
+
+use crate::client::{check_status, shared_client, user_agent, Clients, Retry, TIMEOUT};
+use crate::error::FetchError;
+use crate::fetcher::{check_reply, write_cache_file, Fetcher};
+use crate::release;
+use crate::source::{self, DataSource};
+use reqwest::{Client, RequestBuilder};
+use std::sync::OnceLock;
+
+/// Shared async clients, indexed by User-Agent string.
+static CLIENTS: Clients<Client> = OnceLock::new();
+
+/// Run blocking work (e.g., file locks or the blocking client) on a
+/// separate thread, so the runtime isn't stalled.
+async fn unblock<T, F>(work: F) -> Result<T, FetchError>
+    where T: Send + 'static, F: FnOnce() -> Result<T, FetchError> + Send + 'static
+{
+    tokio::task::spawn_blocking(work).await
+        .map_err(|err| FetchError::Io(std::io::Error::other(err)))?
+}
+
+impl Fetcher {
+    /// As "throttle", but waits on a blocking thread so the runtime isn't stalled.
+    async fn throttle_async(&self) -> Result<(), FetchError> {
+        if self.min_interval.is_zero() {return Ok(());}
+        let fetcher = self.clone();
+        unblock(move || fetcher.throttle()).await
+    }
+
+    /// Send a request to the server, with throttling and retries.
+    /// The request is rebuilt for each attempt.  Returns HTTP status and body.
+    async fn send_async<F>(&self, build: F) -> Result<(u16, String), FetchError>
+        where F: Fn(&Client) -> RequestBuilder
+    {
+        let client = shared_client(&CLIENTS, &user_agent(&self.contact), |agent|
+            Client::builder().user_agent(agent).timeout(TIMEOUT).build())?;
+        let mut retry = Retry::new(self.retries, self.backoff);
+        loop {
+            self.throttle_async().await?;
+            let result = build(&client).send().await;
+            if let Some(dt) = retry.delay(result.as_ref().map(|r| r.status().as_u16())) {
+                tokio::time::sleep(dt).await;
+                continue;
+            }
+            let response = result?;
+            let status = check_status(response.status().as_u16(), response.headers())?;
+            return Ok((status, response.text().await?));
+        }
+    }
+
+    /// Fetch input for a given year/day from cache if available.
+    /// Otherwise, download from server and update local cache.
+    pub async fn get_data_async(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        // Use local cache if possible.
+        release::release_time(year, day)?;
+        if let Some(data) = self.read_from_cache(&year, &day) {return Ok(data);}
+
+        // Attempt to fetch from server...
+        if let Some(dt) = self.release_wait(&year, &day)? {tokio::time::sleep(dt).await;}
+        let tok = self.cookie()?;
+        let url = self.url(&format!("/{}/day/{}/input", year, day));
+        let (status, data) = self.send_async(|client| client.get(&url)
+            .header(reqwest::header::COOKIE, &tok)).await?;
+        let data = check_reply(&year, &day, status, data)?;
+
+        // If successful, update cache before returning.
+        write_cache_file(&self.cache_path(&year, &day, "input", "txt"), &data)?;
+        Ok(data)
+    }
+}
+
+/// Async version of `get_data`, including redirection to test fixtures.
+/// Those sources are blocking, so they run on a separate thread.
+pub async fn get_data_async(year: usize, day: usize) -> Result<String, FetchError>
+{
+    if let Some(source) = source::source_override() {
+        return unblock(move || source.get_data(year, day)).await;
+    }
+    if let Some(fixture) = source::env_fixtures() {
+        let data = unblock(move || fixture.get_data(year, day)).await;
+        if data.is_ok() {return data;}
+    }
+    Fetcher::new().get_data_async(year, day).await
+}
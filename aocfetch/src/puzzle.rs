@@ -0,0 +1,181 @@
+//! Fetch puzzle descriptions from the Advent of Code server, with cache.
+//! Copyright 2023 by Alex Utter
+//!
+//! The examples in each puzzle description are the `<pre><code>` blocks,
+//! and the expected results are usually the emphasized `<code><em>` values
+//! that follow each block.  Extracting both lets a solver check itself
+//! against the examples without copying them by hand.
+
+use crate::html;
+use crate::error::FetchError;
+use crate::fetcher::{check_reply, write_cache_file, Fetcher};
+use crate::release;
+
+/// One candidate example from the puzzle description.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    /// Contents of the `<pre><code>` block.
+    pub input: String,
+    /// Emphasized values between this block and the next one.
+    /// The last one is usually the expected answer.
+    pub answers: Vec<String>,
+}
+
+impl Example {
+    /// The most likely expected answer for this example, if any.
+    pub fn answer(&self) -> Option<&str> {
+        self.answers.last().map(|s| s.as_str())
+    }
+}
+
+/// Puzzle description for a given year/day.
+#[derive(Clone, Debug)]
+pub struct Puzzle {
+    pub year: usize,
+    pub day: usize,
+    /// Puzzle title, e.g., "Not Quite Lisp".
+    pub title: String,
+    /// Raw HTML of the `<article>` blocks (one per unlocked part).
+    pub html: String,
+    /// Plain-text version of the puzzle description.
+    pub text: String,
+    /// Candidate example inputs, in order of appearance.
+    pub examples: Vec<Example>,
+}
+
+impl Puzzle {
+    /// Parse the puzzle description from the `<article>` blocks.
+    pub fn new(year: usize, day: usize, html: &str) -> Puzzle {
+        let html = articles(html);
+        Puzzle {
+            year,
+            day,
+            title: parse_title(&html),
+            text: html::to_text(&html),
+            examples: parse_examples(&html),
+            html,
+        }
+    }
+
+    /// Number of puzzle parts currently visible (usually 1 or 2).
+    pub fn parts(&self) -> usize {
+        self.html.matches("<article").count()
+    }
+}
+
+/// Extract and concatenate all `<article>` blocks, discarding the rest.
+/// (If there are none, assume the input is already trimmed.)
+fn articles(html: &str) -> String
+{
+    let mut result = String::new();
+    let mut rest = html;
+    while let Some(a) = rest.find("<article") {
+        let b = rest[a..].find("</article>").map(|b| a + b + 10).unwrap_or(rest.len());
+        result.push_str(&rest[a..b]);
+        result.push('\n');
+        rest = &rest[b..];
+    }
+    if result.is_empty() {html.to_string()} else {result}
+}
+
+/// Extract the title from the first heading, "--- Day 1: Title ---".
+fn parse_title(html: &str) -> String
+{
+    let a = html.find("<h2").and_then(|a| html[a..].find('>').map(|n| a + n + 1));
+    let b = html.find("</h2>");
+    if let (Some(a), Some(b)) = (a, b) {
+        let title = html::strip_tags(&html[a..b]);
+        let title = title.trim().trim_matches('-').trim();
+        match title.split_once(": ") {
+            Some((_, name)) => name.to_string(),
+            None => title.to_string(),
+        }
+    } else {
+        String::new()
+    }
+}
+
+/// Find all emphasized values, i.e., `<code><em>X</em></code>` or `<em><code>X</code></em>`.
+fn parse_answers(html: &str) -> Vec<String>
+{
+    let mut result = Vec::new();
+    let mut rest = html;
+    loop {
+        let p1 = rest.find("<code><em>").map(|n| (n, "<code><em>", "</em></code>"));
+        let p2 = rest.find("<em><code>").map(|n| (n, "<em><code>", "</code></em>"));
+        let next = match (p1, p2) {
+            (Some(x), Some(y)) => if x.0 < y.0 {x} else {y},
+            (Some(x), None) => x,
+            (None, Some(y)) => y,
+            (None, None) => break,
+        };
+        let (n, open, close) = next;
+        let start = n + open.len();
+        if let Some(len) = rest[start..].find(close) {
+            result.push(html::strip_tags(&rest[start..start+len]).trim().to_string());
+            rest = &rest[start+len+close.len()..];
+        } else {
+            break;
+        }
+    }
+    result
+}
+
+/// Find all `<pre>` blocks and the emphasized values that follow each one.
+fn parse_examples(html: &str) -> Vec<Example>
+{
+    // Locate the start and end of each <pre> block.
+    let mut blocks: Vec<(usize, usize)> = Vec::new();
+    let mut posn = 0usize;
+    while let Some(a) = html[posn..].find("<pre>").map(|a| posn + a) {
+        let b = html[a..].find("</pre>").map(|b| a + b).unwrap_or(html.len());
+        blocks.push((a, b));
+        posn = b;
+    }
+    // Answers for each block appear between it and the next block.
+    blocks.iter().enumerate().map(|(n, &(a, b))| {
+        let next = blocks.get(n+1).map(|x| x.0).unwrap_or(html.len());
+        let after = if b < next {&html[b..next]} else {""};
+        Example {
+            input: html::strip_tags(&html[a..b]),
+            answers: parse_answers(after),
+        }
+    }).collect()
+}
+
+impl Fetcher {
+    /// Fetch the puzzle description from the Advent of Code server.
+    /// The session token is optional, but required to see Part 2.
+    fn read_puzzle_from_web(&self, year: &usize, day: &usize) -> Result<String, FetchError> {
+        let url = self.url(&format!("/{}/day/{}", year, day));
+        let tok = self.cookie().ok();
+        let (status, data) = self.send(|client| match &tok {
+            Some(tok) => client.get(&url).header(reqwest::header::COOKIE, tok),
+            None => client.get(&url),
+        })?;
+        let data = check_reply(year, day, status, data)?;
+        Ok(articles(&data))
+    }
+
+    /// Fetch the puzzle description for a given year/day from cache if available.
+    /// Otherwise, download from server and update local cache.  The cache is
+    /// also refreshed once Part 1 has been solved, to reveal Part 2.
+    pub fn get_puzzle(&self, year: usize, day: usize) -> Result<Puzzle, FetchError> {
+        // Use local cache if possible.
+        release::release_time(year, day)?;
+        let filename = self.cache_path(&year, &day, "puzzle", "html");
+        if let Ok(html) = std::fs::read_to_string(&filename) {
+            let puzzle = Puzzle::new(year, day, &html);
+            let stale = puzzle.parts() < 2 && self.is_solved(&year, &day, &1);
+            if !stale || self.is_offline() {return Ok(puzzle);}
+        }
+
+        // Attempt to fetch from server...
+        self.check_online(&year, &day)?;
+        let html = self.read_puzzle_from_web(&year, &day)?;
+
+        // If successful, update cache before returning.
+        write_cache_file(&filename, &html)?;
+        Ok(Puzzle::new(year, day, &html))
+    }
+}
@@ -0,0 +1,61 @@
+//! Puzzle release times.
+//! Copyright 2023 by Alex Utter
+//!
+//! Each puzzle unlocks at midnight US-Eastern (UTC-5, since there's no
+//! daylight saving time in December).  Events run from December 1st to
+//! 25th, except that from 2025 onward there are only twelve puzzles.
+
+use crate::error::FetchError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The first Advent of Code event.
+pub const FIRST_YEAR: usize = 2015;
+
+/// Days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+/// (Howard Hinnant's "days_from_civil" algorithm.)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64
+{
+    let y = if month <= 2 {year - 1} else {year};
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Unlock time for a given year/day, without any range checks.
+pub(crate) fn unlock_time(year: usize, day: usize) -> SystemTime
+{
+    let days = days_from_civil(year as i64, 12, day as i64);
+    let secs = days * 86400 + 5 * 3600;
+    UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64)
+}
+
+/// Number of puzzles in a given year's event.
+pub fn last_day(year: usize) -> usize
+{
+    if year < 2025 {25} else {12}
+}
+
+/// Release time for a given year/day, or an error if there is no such puzzle.
+pub fn release_time(year: usize, day: usize) -> Result<SystemTime, FetchError>
+{
+    if year < FIRST_YEAR || day < 1 || day > last_day(year) {
+        Err(FetchError::InvalidRequest(format!("No puzzle for {} day {}", year, day)))
+    } else {
+        Ok(unlock_time(year, day))
+    }
+}
+
+/// Time remaining until a given puzzle is released, or None if it is already out.
+pub fn time_until_release(year: usize, day: usize) -> Result<Option<Duration>, FetchError>
+{
+    let unlock = release_time(year, day)?;
+    Ok(unlock.duration_since(SystemTime::now()).ok())
+}
+
+/// Has the given puzzle been released?
+pub fn is_released(year: usize, day: usize) -> bool
+{
+    matches!(time_until_release(year, day), Ok(None))
+}
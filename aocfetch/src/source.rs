@@ -0,0 +1,173 @@
+//! Pluggable sources for puzzle input, for testing without network access.
+//! Copyright 2023 by Alex Utter
+//!
+//! The `get_data` shortcut normally uses the default Fetcher.  It can be
+//! redirected to recorded inputs by calling `set_source`, or by setting the
+//! "AOC_FIXTURES" environment variable to a folder laid out like the cache,
+//! i.e., `{folder}/{year}/input_{day}.txt`.
+
+use crate::error::FetchError;
+use crate::fetcher::Fetcher;
+use crate::release;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+/// Anything that can provide puzzle input for a given year/day.
+pub trait DataSource: Send + Sync {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError>;
+}
+
+/// The Fetcher itself is a source, reading from the cache if possible.
+impl DataSource for Fetcher {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        Fetcher::get_data(self, year, day)
+    }
+}
+
+/// Always download from the server, never reading or writing the cache.
+#[derive(Clone, Debug, Default)]
+pub struct Web {
+    pub fetcher: Fetcher,
+}
+
+impl Web {
+    pub fn new(fetcher: Fetcher) -> Web {
+        Web { fetcher }
+    }
+}
+
+impl DataSource for Web {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        release::release_time(year, day)?;
+        self.fetcher.check_online(&year, &day)?;
+        self.fetcher.read_from_web(&year, &day)
+    }
+}
+
+/// Only read from the cache, never contacting the server.
+#[derive(Clone, Debug, Default)]
+pub struct Cache {
+    pub fetcher: Fetcher,
+}
+
+impl Cache {
+    pub fn new(fetcher: Fetcher) -> Cache {
+        Cache { fetcher }
+    }
+}
+
+impl DataSource for Cache {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        release::release_time(year, day)?;
+        self.fetcher.read_from_cache(&year, &day)
+            .ok_or(FetchError::NotFound(format!("{} day {} is not in the cache", year, day)))
+    }
+}
+
+/// Recorded inputs, held in memory or read from a folder.
+#[derive(Clone, Debug, Default)]
+pub struct Fixture {
+    dir: Option<PathBuf>,
+    data: HashMap<(usize, usize), String>,
+}
+
+impl Fixture {
+    /// Empty in-memory fixture.
+    pub fn new() -> Fixture {
+        Fixture::default()
+    }
+
+    /// Read files from a folder with the same layout as the cache,
+    /// i.e., `{dir}/{year}/input_{day}.txt`.
+    pub fn dir<P: Into<PathBuf>>(dir: P) -> Fixture {
+        Fixture { dir: Some(dir.into()), data: HashMap::new() }
+    }
+
+    /// Add input for a given year/day, which takes priority over any files.
+    pub fn with(mut self, year: usize, day: usize, data: &str) -> Self {
+        self.data.insert((year, day), data.to_string());
+        self
+    }
+}
+
+impl DataSource for Fixture {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        if let Some(data) = self.data.get(&(year, day)) {return Ok(data.clone());}
+        let path = self.dir.as_ref().map(|dir| dir
+            .join(year.to_string())
+            .join(format!("input_{:02}.txt", day)));
+        path.and_then(|path| std::fs::read_to_string(path).ok())
+            .ok_or(FetchError::NotFound(format!("No fixture for {} day {}", year, day)))
+    }
+}
+
+/// Try each source in order, returning the first success.
+/// If they all fail, returns the error from the last source.
+#[derive(Clone, Default)]
+pub struct Chain {
+    sources: Vec<Arc<dyn DataSource>>,
+}
+
+impl Chain {
+    pub fn new() -> Chain {
+        Chain::default()
+    }
+
+    /// Add another source to the end of the chain.
+    pub fn then<S: DataSource + 'static>(mut self, source: S) -> Self {
+        self.sources.push(Arc::new(source));
+        self
+    }
+}
+
+impl DataSource for Chain {
+    fn get_data(&self, year: usize, day: usize) -> Result<String, FetchError> {
+        let mut result = Err(FetchError::NotFound("Empty chain".to_string()));
+        for source in self.sources.iter() {
+            result = source.get_data(year, day);
+            if result.is_ok() {break;}
+        }
+        result
+    }
+}
+
+/// Override for the `get_data` shortcut, if any.
+static SOURCE: RwLock<Option<Arc<dyn DataSource>>> = RwLock::new(None);
+
+/// Redirect the `get_data` shortcut to a different source, for every thread.
+pub fn set_source<S: DataSource + 'static>(source: S)
+{
+    *SOURCE.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(source));
+}
+
+/// Restore the default source for the `get_data` shortcut.
+pub fn reset_source()
+{
+    *SOURCE.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// The override from `set_source`, if any.
+pub(crate) fn source_override() -> Option<Arc<dyn DataSource>>
+{
+    SOURCE.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Fixtures from the "AOC_FIXTURES" folder, if set.
+pub(crate) fn env_fixtures() -> Option<Fixture>
+{
+    std::env::var_os("AOC_FIXTURES").filter(|x| !x.is_empty()).map(Fixture::dir)
+}
+
+/// The source used by the `get_data` shortcut:
+///  * The override from `set_source`, if any.
+///  * Fixtures from the "AOC_FIXTURES" folder, then the default Fetcher.
+///  * Otherwise, the default Fetcher.
+pub fn default_source() -> Arc<dyn DataSource>
+{
+    if let Some(source) = source_override() {return source;}
+    match env_fixtures() {
+        Some(fixture) => Arc::new(Chain::new().then(fixture).then(Fetcher::new())),
+        None => Arc::new(Fetcher::new()),
+    }
+}
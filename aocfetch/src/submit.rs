@@ -0,0 +1,200 @@
+//! Submit puzzle answers to the Advent of Code server, with cache.
+//! Copyright 2023 by Alex Utter
+//!
+//! Every conclusive attempt is logged to the local cache, so that an answer
+//! which is already known to be right or wrong is never sent twice.
+
+use crate::error::FetchError;
+use crate::fetcher::{check_reply, write_cache_file, Fetcher};
+use crate::release;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// That's the right answer!
+    Correct,
+    /// Incorrect, and the answer is too high.
+    TooHigh,
+    /// Incorrect, and the answer is too low.
+    TooLow,
+    /// Incorrect, with no further hints.
+    Wrong,
+    /// Answer was not checked; try again after the indicated delay.
+    RateLimited(Duration),
+    /// Answer was not checked; this part has already been solved.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Short label for the attempt log, or None if it shouldn't be logged.
+    fn label(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct    => Some("correct"),
+            Verdict::TooHigh    => Some("high"),
+            Verdict::TooLow     => Some("low"),
+            Verdict::Wrong      => Some("wrong"),
+            _                   => None,
+        }
+    }
+
+    /// Inverse of "label()".
+    fn from_label(lbl: &str) -> Option<Verdict> {
+        match lbl {
+            "correct"   => Some(Verdict::Correct),
+            "high"      => Some(Verdict::TooHigh),
+            "low"       => Some(Verdict::TooLow),
+            "wrong"     => Some(Verdict::Wrong),
+            _           => None,
+        }
+    }
+
+    /// Parse the HTML reply from the "/answer" endpoint.
+    fn parse(html: &str) -> Option<Verdict> {
+        // The verdict is always inside the <article> block.
+        let msg = match (html.find("<article"), html.find("</article>")) {
+            (Some(a), Some(b)) if a < b => &html[a..b],
+            _ => html,
+        };
+        if msg.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if msg.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if msg.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if msg.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if msg.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(parse_wait(msg)))
+        } else if msg.contains("Did you already complete it?") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse the wait time from "You have 1m 23s left to wait."
+fn parse_wait(msg: &str) -> Duration {
+    let start = msg.find("You have ").map(|n| n + 9).unwrap_or(0);
+    let stop = msg[start..].find(" left to wait").map(|n| start + n);
+    let mut secs = 0u64;
+    for tok in msg[start..stop.unwrap_or(start)].split_whitespace() {
+        // Split off the last character, which may not be ASCII.
+        let last = tok.char_indices().last().map_or(0, |(n, _)| n);
+        let (num, unit) = tok.split_at(last);
+        let scale = match unit {"h" => 3600, "m" => 60, "s" => 1, _ => 0};
+        secs += scale * num.parse::<u64>().unwrap_or(0);
+    }
+    // If we can't parse the message, fall back to the usual one minute.
+    if secs == 0 {secs = 60;}
+    Duration::from_secs(secs)
+}
+
+/// A previously logged attempt.
+struct Attempt {
+    part: usize,
+    verdict: Verdict,
+    answer: String,
+}
+
+/// Can we determine the verdict without asking the server?
+fn check_log(log: &[Attempt], part: &usize, answer: &str) -> Option<Verdict>
+{
+    let num = answer.parse::<i128>().ok();
+    for prev in log.iter().filter(|a| a.part == *part) {
+        // Exact repeat of a previous attempt?
+        if prev.answer == answer {return Some(prev.verdict);}
+        // Otherwise, numeric answers can be compared against known bounds.
+        let pnum = prev.answer.parse::<i128>().ok();
+        match (prev.verdict, num, pnum) {
+            (Verdict::Correct, Some(x), Some(y)) if x > y => return Some(Verdict::TooHigh),
+            (Verdict::Correct, Some(x), Some(y)) if x < y => return Some(Verdict::TooLow),
+            (Verdict::Correct, _, _) => return Some(Verdict::Wrong),
+            (Verdict::TooHigh, Some(x), Some(y)) if x >= y => return Some(Verdict::TooHigh),
+            (Verdict::TooLow, Some(x), Some(y)) if x <= y => return Some(Verdict::TooLow),
+            _ => (),
+        }
+    }
+    None
+}
+
+impl Fetcher {
+    /// Read all previous attempts for a given year/day.
+    fn read_log(&self, year: &usize, day: &usize) -> Vec<Attempt> {
+        let filename = self.cache_path(year, day, "answers", "txt");
+        let log = std::fs::read_to_string(filename).unwrap_or_default();
+        log.lines().filter_map(|line| {
+            let tok: Vec<&str> = line.splitn(3, '\t').collect();
+            if tok.len() < 3 {return None;}
+            Some(Attempt {
+                part: tok[0].parse().ok()?,
+                verdict: Verdict::from_label(tok[1])?,
+                answer: tok[2].to_string(),
+            })
+        }).collect()
+    }
+
+    /// Append a new attempt to the log, if it was conclusive.
+    fn append_log(&self, year: &usize, day: &usize, part: &usize, answer: &str, verdict: &Verdict)
+        -> Result<(), FetchError>
+    {
+        if let Some(lbl) = verdict.label() {
+            let filename = self.cache_path(year, day, "answers", "txt");
+            let mut log = std::fs::read_to_string(&filename).unwrap_or_default();
+            log.push_str(&format!("{}\t{}\t{}\n", part, lbl, answer));
+            write_cache_file(&filename, &log)?;
+        }
+        Ok(())
+    }
+
+    /// Has the given part been solved, according to the attempt log?
+    pub(crate) fn is_solved(&self, year: &usize, day: &usize, part: &usize) -> bool {
+        self.read_log(year, day).iter()
+            .any(|a| a.part == *part && a.verdict == Verdict::Correct)
+    }
+
+    /// Post an answer to the Advent of Code server.
+    fn post_answer(&self, year: &usize, day: &usize, part: &usize, answer: &str)
+        -> Result<Verdict, FetchError>
+    {
+        let tok = self.cookie()?;
+        let url = self.url(&format!("/{}/day/{}/answer", year, day));
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+
+        // Never retry, since a repeated answer could count as a second attempt.
+        let (status, html) = self.send_once(|client| client.post(&url)
+            .header(reqwest::header::COOKIE, &tok)
+            .form(&form))?;
+        let html = check_reply(year, day, status, html)?;
+        Verdict::parse(&html).ok_or(FetchError::Server(status, html))
+    }
+
+    /// Submit the answer for a given year/day/part (1 or 2).
+    /// If the answer has already been checked, returns the logged verdict.
+    /// Otherwise, post it to the server and log the result.
+    pub fn submit<T: Display>(&self, year: usize, day: usize, part: usize, answer: T)
+        -> Result<Verdict, FetchError>
+    {
+        release::release_time(year, day)?;
+        if part != 1 && part != 2 {
+            return Err(FetchError::InvalidRequest(format!("No such part: {}", part)));
+        }
+        let answer = answer.to_string().trim().to_string();
+        if answer.is_empty() || answer.contains('\n') {
+            return Err(FetchError::InvalidRequest(format!("Bad answer: {:?}", answer)));
+        }
+
+        // Use the attempt log if possible.
+        if let Some(verdict) = check_log(&self.read_log(&year, &day), &part, &answer) {
+            return Ok(verdict);
+        }
+
+        // Otherwise, ask the server and log the result.
+        self.check_online(&year, &day)?;
+        let verdict = self.post_answer(&year, &day, &part, &answer)?;
+        self.append_log(&year, &day, &part, &answer, &verdict)?;
+        Ok(verdict)
+    }
+}
@@ -0,0 +1,94 @@
+//! Test the cache layout using a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::Fetcher;
+use common::{fetcher, temp_cache, StandIn};
+
+#[test]
+fn cache_per_account() {
+    let cache = temp_cache("cache");
+    let server = StandIn::new(vec![(200, "alice\n"), (200, "bob\n")]);
+    let alice = fetcher(&cache, &server).session("alice-token");
+    let bob = fetcher(&cache, &server).session("bob-token");
+    assert_ne!(alice.account_dir(), bob.account_dir());
+
+    // Each account fetches its own input, once.
+    assert_eq!(alice.get_data(2016, 7).unwrap(), "alice\n");
+    assert_eq!(bob.get_data(2016, 7).unwrap(), "bob\n");
+    assert_eq!(alice.get_data(2016, 7).unwrap(), "alice\n");
+    assert_eq!(bob.get_data(2016, 7).unwrap(), "bob\n");
+    assert_eq!(server.count(), 2);
+    assert_eq!(server.last().header("cookie"), Some("session=bob-token"));
+
+    // The account folder is a stable hash of the session token.
+    assert_eq!(alice.account_dir(), cache.join("b2194c301e5aa62b"));
+    assert!(alice.account_dir().join("2016").join("input_07.txt").exists());
+
+    // Accounts can also be named explicitly.
+    let named = Fetcher::new().cache_dir(&cache).account("alice");
+    assert_eq!(named.account_dir(), cache.join("alice"));
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn cache_legacy() {
+    let cache = temp_cache("legacy");
+    let legacy = cache.join("input");
+    std::fs::create_dir_all(&legacy).unwrap();
+    std::fs::write(legacy.join("input_2016_07.txt"), "legacy\n").unwrap();
+    let server = StandIn::new(vec![(200, "fresh\n")]);
+    let fetcher = fetcher(&cache, &server).legacy_dir(&legacy);
+
+    // Inputs from the old layout are used without contacting the server...
+    assert_eq!(fetcher.get_data(2016, 7).unwrap(), "legacy\n");
+    assert_eq!(server.count(), 0);
+
+    // ...and copied into the new layout, leaving the original in place.
+    let path = fetcher.account_dir().join("2016").join("input_07.txt");
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "legacy\n");
+    assert!(legacy.join("input_2016_07.txt").exists());
+
+    // Anything else still comes from the server.
+    assert_eq!(fetcher.get_data(2016, 8).unwrap(), "fresh\n");
+    assert_eq!(server.count(), 1);
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn cache_manage() {
+    let cache = temp_cache("manage");
+    let server = StandIn::new(vec![
+        (200, "1\n"), (200, "2\n"), (200, "Puzzle inputs differ by user.\n")]);
+    let fetcher = fetcher(&cache, &server);
+    fetcher.get_data(2016, 1).unwrap();
+    fetcher.get_data(2016, 2).unwrap();
+    fetcher.get_data(2017, 3).unwrap();
+
+    // Listing is sorted by year and day.
+    let list = fetcher.cache_list().unwrap();
+    let days: Vec<(usize, Option<usize>)> = list.iter().map(|e| (e.year, e.day)).collect();
+    assert_eq!(days, vec![(2016, Some(1)), (2016, Some(2)), (2017, Some(3))]);
+    assert!(list.iter().all(|e| e.kind == "input" && e.size == 2 || e.year == 2017));
+
+    // Only the server message is flagged.
+    let problems = fetcher.cache_verify().unwrap();
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].0.year, 2017);
+
+    // Leaderboards aren't tied to a day, even if the ID looks like one.
+    let board = fetcher.account_dir().join("2016").join("leaderboard_2.json");
+    std::fs::write(&board, "{}").unwrap();
+    let entry = fetcher.cache_list().unwrap().into_iter()
+        .find(|e| e.kind == "leaderboard").unwrap();
+    assert_eq!((entry.year, entry.day), (2016, None));
+
+    // Remove a single day, then a whole year.
+    assert_eq!(fetcher.cache_remove(2016, Some(2)).unwrap(), 1);
+    assert!(board.exists());
+    std::fs::remove_file(&board).unwrap();
+    assert_eq!(fetcher.cache_remove(2017, None).unwrap(), 1);
+    assert_eq!(fetcher.cache_list().unwrap().len(), 1);
+    assert!(fetcher.cache_verify().unwrap().is_empty());
+    let _ = std::fs::remove_dir_all(&cache);
+}
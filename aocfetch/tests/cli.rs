@@ -0,0 +1,41 @@
+//! Test the command-line interface, using offline mode only.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use common::temp_cache;
+use std::process::Command;
+
+fn aocfetch(cache: &std::path::Path, args: &[&str]) -> (bool, String) {
+    let out = Command::new(env!("CARGO_BIN_EXE_aocfetch"))
+        .env("AOC_CACHE_DIR", cache)
+        .env("AOC_SESSION", "0123abcd")
+        .arg("--offline")
+        .args(args)
+        .output().unwrap();
+    (out.status.success(), String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+#[test]
+fn cli_offline() {
+    let cache = temp_cache("cli");
+    let fetcher = aocfetch::Fetcher::new().cache_dir(&cache).session("0123abcd");
+    let path = fetcher.account_dir().join("2016").join("input_05.txt");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "abc\n").unwrap();
+
+    assert_eq!(aocfetch(&cache, &["fetch", "2016", "5"]), (true, "abc\n".to_string()));
+    assert!(!aocfetch(&cache, &["fetch", "2016", "6"]).0);
+    assert!(!aocfetch(&cache, &["fetch", "2016"]).0);
+
+    let out = cache.join("saved.txt");
+    assert!(aocfetch(&cache, &["fetch", "2016", "5", "-o", out.to_str().unwrap()]).0);
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "abc\n");
+
+    let (ok, list) = aocfetch(&cache, &["cache", "ls", "2016"]);
+    assert!(ok && list.contains("input_05.txt"));
+    assert!(aocfetch(&cache, &["cache", "verify"]).0);
+    assert!(aocfetch(&cache, &["cache", "rm", "2016"]).0);
+    assert!(!path.exists());
+    assert!(!aocfetch(&cache, &["whoami"]).0);
+    let _ = std::fs::remove_dir_all(&cache);
+}
@@ -0,0 +1,70 @@
+//! Test throttling, retries, and User-Agent against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use common::{fetcher, temp_cache, StandIn};
+use std::time::{Duration, Instant};
+
+#[test]
+fn retry_transient() {
+    let cache = temp_cache("retry");
+    let server = StandIn::new(vec![
+        (503, "Service Unavailable"), (502, "Bad Gateway"), (200, "12345\n")]);
+    let fetcher = fetcher(&cache, &server);
+    assert_eq!(fetcher.get_data(2016, 1).unwrap(), "12345\n");
+    assert_eq!(server.count(), 3);
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn retry_many() {
+    // The backoff must not overflow, however many retries are allowed.
+    let cache = temp_cache("retry-many");
+    let mut replies = vec![(503, "Service Unavailable"); 34];
+    replies.push((200, "12345\n"));
+    let server = StandIn::new(replies);
+    let fetcher = fetcher(&cache, &server).retries(40, Duration::ZERO);
+    assert_eq!(fetcher.get_data(2016, 1).unwrap(), "12345\n");
+    assert_eq!(server.count(), 35);
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn throttle() {
+    let cache = temp_cache("throttle");
+    let server = StandIn::new(vec![(200, "1\n"), (200, "2\n"), (200, "3\n")]);
+    let fetcher = fetcher(&cache, &server).min_interval(Duration::from_millis(300));
+    let time = Instant::now();
+    for day in 1..4 {
+        assert_eq!(fetcher.get_data(2016, day).unwrap(), format!("{}\n", day));
+    }
+    assert!(time.elapsed() >= Duration::from_millis(600));
+    assert!(cache.join("throttle.lock").exists());
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn user_agent() {
+    let cache = temp_cache("agent");
+    let server = StandIn::new(vec![(200, "1\n")]);
+    let fetcher = fetcher(&cache, &server).contact("someone@example.com");
+    fetcher.get_data(2016, 1).unwrap();
+    let agent = server.last().header("user-agent").unwrap().to_string();
+    assert!(agent.starts_with("github.com/ooterness/AdventOfCode"));
+    assert!(agent.ends_with(" by someone@example.com"));
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn whoami() {
+    let cache = temp_cache("whoami");
+    let server = StandIn::new(vec![
+        (200, "<header><div class=\"user\">Some &amp; One <span class=\"star-count\">42*</span></div></header>"),
+        (200, "<header><a href=\"/auth/login\">[Log In]</a></header>")]);
+    let fetcher = fetcher(&cache, &server);
+    assert_eq!(fetcher.whoami().unwrap(), "Some & One");
+    assert_eq!(server.last().path, "/");
+    assert!(matches!(fetcher.whoami(), Err(aocfetch::FetchError::BadSession(_))));
+    assert!(matches!(fetcher.offline().whoami(), Err(aocfetch::FetchError::Offline)));
+    let _ = std::fs::remove_dir_all(&cache);
+}
@@ -0,0 +1,112 @@
+//! Local stand-in for the Advent of Code server, for testing.
+//! Copyright 2023 by Alex Utter
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A single HTTP request, as received by the stand-in server.
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<String>,
+    pub body: String,
+}
+
+#[allow(dead_code)]
+impl Request {
+    /// Find the value of a given header, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let prefix = format!("{}:", name.to_lowercase());
+        self.headers.iter()
+            .find(|h| h.to_lowercase().starts_with(&prefix))
+            .map(|h| h[prefix.len()..].trim())
+    }
+}
+
+/// Serves a fixed list of (status, body) replies, one per connection.
+pub struct StandIn {
+    pub url: String,
+    pub log: Arc<Mutex<Vec<Request>>>,
+}
+
+#[allow(dead_code)]
+impl StandIn {
+    pub fn new(replies: Vec<(u16, &str)>) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let replies: Vec<(u16, String)> = replies.into_iter()
+            .map(|(s, b)| (s, b.to_string())).collect();
+        let thread_log = log.clone();
+        thread::spawn(move || {
+            for (status, body) in replies.into_iter() {
+                let (mut stream, _) = listener.accept().unwrap();
+                let req = read_request(&mut BufReader::new(&mut stream));
+                thread_log.lock().unwrap().push(req);
+                let reply = format!(
+                    "HTTP/1.1 {} Stand-In\r\nContent-Type: text/html\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body);
+                stream.write_all(reply.as_bytes()).unwrap();
+            }
+        });
+        StandIn { url, log }
+    }
+
+    /// Number of requests received so far.
+    pub fn count(&self) -> usize {
+        self.log.lock().unwrap().len()
+    }
+
+    /// Copy of the most recent request.
+    pub fn last(&self) -> Request {
+        self.log.lock().unwrap().last().unwrap().clone()
+    }
+}
+
+fn read_request<R: BufRead>(rd: &mut R) -> Request {
+    let mut line = String::new();
+    rd.read_line(&mut line).unwrap();
+    let tok: Vec<&str> = line.split_whitespace().collect();
+    let (method, path) = (tok[0].to_string(), tok[1].to_string());
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        rd.read_line(&mut line).unwrap();
+        if line.trim().is_empty() {break;}
+        headers.push(line.trim().to_string());
+    }
+    let mut req = Request { method, path, headers, body: String::new() };
+    let len: usize = req.header("content-length")
+        .and_then(|n| n.parse().ok()).unwrap_or(0);
+    let mut body = vec![0u8; len];
+    rd.read_exact(&mut body).unwrap();
+    req.body = String::from_utf8_lossy(&body).to_string();
+    req
+}
+
+/// Empty cache folder for a given test, in the system's temporary folder.
+#[allow(dead_code)]
+pub fn temp_cache(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("aocfetch-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Fetcher for a given cache folder and stand-in server, with no throttling
+/// and quick retries, so tests run quickly.
+#[allow(dead_code)]
+pub fn fetcher(cache: &std::path::Path, server: &StandIn) -> aocfetch::Fetcher {
+    aocfetch::Fetcher::new()
+        .cache_dir(cache)
+        .server(&server.url)
+        .session("0123abcd")
+        .online()
+        .min_interval(std::time::Duration::ZERO)
+        .retries(2, std::time::Duration::from_millis(10))
+}
@@ -0,0 +1,43 @@
+//! Test error reporting against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::FetchError;
+use common::{fetcher, temp_cache, StandIn};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn error_kinds() {
+    let cache = temp_cache("errors");
+    let server = StandIn::new(vec![
+        (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+        (500, "Internal Server Error"),
+        (404, "Please don't repeatedly request this endpoint before it unlocks!"),
+        (429, "Too Many Requests"),
+        (503, "Service Unavailable"),
+        (503, "Service Unavailable"),
+        (503, "Service Unavailable")]);
+    let fetcher = fetcher(&cache, &server);
+
+    let err = fetcher.clone().session("").get_data(2015, 2).unwrap_err();
+    assert!(matches!(err, FetchError::MissingSession));
+    assert!(err.to_string().contains("AOC_SESSION"));
+    assert_eq!(server.count(), 0);
+
+    let fetcher = fetcher.session("0123abcd");
+    match fetcher.get_data(2015, 2).unwrap_err() {
+        FetchError::BadSession(msg) => assert!(msg.contains("Please log in")),
+        err => panic!("Unexpected error: {:?}", err),
+    }
+    assert!(matches!(fetcher.get_data(2015, 2).unwrap_err(), FetchError::BadSession(_)));
+    match fetcher.get_data(2015, 2).unwrap_err() {
+        FetchError::NotYetReleased(t) =>
+            assert_eq!(t, UNIX_EPOCH + Duration::from_secs(1449032400)),
+        err => panic!("Unexpected error: {:?}", err),
+    }
+    assert!(matches!(fetcher.get_data(2015, 2).unwrap_err(), FetchError::RateLimited(_)));
+    // Transient errors are retried, but eventually give up.
+    assert!(matches!(fetcher.get_data(2015, 2).unwrap_err(), FetchError::Server(503, _)));
+    assert_eq!(server.count(), 7);
+    assert!(!cache.exists());
+}
@@ -0,0 +1,93 @@
+//! Test leaderboard parsing against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::{FetchError, PartStats};
+use common::{fetcher, temp_cache, StandIn};
+use std::time::Duration;
+
+// Three members, two of whom have solved Day 1 (released at 1701406800).
+const JSON: &str = r#"{
+  "owner_id": 101,
+  "event": "2023",
+  "members": {
+    "101": {"id": 101, "name": "alice", "stars": 3, "local_score": 8,
+            "global_score": 0, "last_star_ts": 1701496800,
+            "completion_day_level": {
+              "1": {"1": {"get_star_ts": 1701407100, "star_index": 5},
+                    "2": {"get_star_ts": 1701407700, "star_index": 9}},
+              "2": {"1": {"get_star_ts": 1701496800, "star_index": 20}}}},
+    "202": {"id": 202, "name": null, "stars": 2, "local_score": 5,
+            "global_score": 0, "last_star_ts": 1701410000,
+            "completion_day_level": {
+              "1": {"1": {"get_star_ts": 1701407000, "star_index": 3},
+                    "2": {"get_star_ts": 1701410000, "star_index": 12}}}},
+    "303": {"id": 303, "name": "carol", "stars": 0, "local_score": 0,
+            "global_score": 0, "last_star_ts": 0, "completion_day_level": {}}
+  }
+}"#;
+
+const SELF: &str = r#"<html><body><main><article>
+<p>These are your personal leaderboard statistics.</p>
+<pre>      <span class="leaderboard-daydesc-first">--------Part 1--------</span>   <span class="leaderboard-daydesc-both">--------Part 2--------</span>
+Day   <span class="leaderboard-daydesc-first">    Time   Rank  Score</span>   <span class="leaderboard-daydesc-both">    Time   Rank  Score</span>
+  3       &gt;24h  30123      0          -      -      -
+  2   00:28:29   1049      0   01:02:03    900      0
+  1   00:02:54     42     59   00:05:38     61     40
+</pre>
+</article></main></body></html>"#;
+
+#[test]
+fn private_leaderboard() {
+    let cache = temp_cache("leaderboard");
+    let server = StandIn::new(vec![(200, JSON)]);
+    let fetcher = fetcher(&cache, &server);
+    let board = fetcher.get_leaderboard(2023, 101).unwrap();
+    assert_eq!(server.last().path, "/2023/leaderboard/private/view/101.json");
+    assert_eq!(board.owner_id, 101);
+    assert_eq!(board.members.len(), 3);
+
+    // Standings and member details.
+    let names: Vec<String> = board.standings().iter().map(|m| m.display_name()).collect();
+    assert_eq!(names, vec!["alice", "(anonymous user #202)", "carol"]);
+    let alice = &board.members[&101];
+    assert_eq!(alice.delta(1), Some(Duration::from_secs(600)));
+    assert_eq!(alice.delta(2), None);
+    assert_eq!(alice.solve_time(2023, 1, 1), Some(Duration::from_secs(300)));
+
+    // Recalculated scores should match the server's.
+    let scores = board.local_scores();
+    for member in board.members.values() {
+        assert_eq!(scores[&member.id], member.local_score);
+    }
+
+    // Second request within 15 minutes uses the cache.
+    fetcher.get_leaderboard(2023, 101).unwrap();
+    assert_eq!(server.count(), 1);
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn no_access() {
+    let cache = temp_cache("noaccess");
+    let server = StandIn::new(vec![(200, "<html>Private Leaderboard</html>")]);
+    let fetcher = fetcher(&cache, &server);
+    assert!(matches!(fetcher.get_leaderboard(2023, 999), Err(FetchError::BadSession(_))));
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn personal_stats() {
+    let cache = temp_cache("stats");
+    let server = StandIn::new(vec![(200, SELF)]);
+    let fetcher = fetcher(&cache, &server);
+    let stats = fetcher.get_personal_stats(2022).unwrap();
+    assert_eq!(server.last().path, "/2022/leaderboard/self");
+    assert_eq!(stats.len(), 3);
+    assert_eq!(stats[0].day, 3);
+    assert_eq!(stats[0].part1, Some(PartStats {time: None, rank: Some(30123), score: Some(0)}));
+    assert_eq!(stats[0].part2, None);
+    assert_eq!(stats[1].part2.as_ref().unwrap().time, Some(Duration::from_secs(3723)));
+    assert_eq!(stats[2].part1.as_ref().unwrap().score, Some(59));
+    let _ = std::fs::remove_dir_all(&cache);
+}
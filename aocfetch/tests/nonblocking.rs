@@ -0,0 +1,51 @@
+//! Test the async API against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+#![cfg(feature = "async")]
+
+mod common;
+use common::{fetcher, temp_cache, StandIn};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all().build().unwrap().block_on(future)
+}
+
+#[test]
+fn async_cache() {
+    let cache = temp_cache("async");
+    let server = StandIn::new(vec![(503, "Service Unavailable"), (200, "1\n"), (200, "2\n")]);
+    let fetcher = fetcher(&cache, &server);
+
+    // Fetch concurrently, using the same shared client.
+    let (f1, f2) = (fetcher.clone(), fetcher.clone());
+    let (a, b) = block_on(async {
+        let a = tokio::spawn(async move {f1.get_data_async(2016, 1).await});
+        let b = tokio::spawn(async move {f2.get_data_async(2016, 2).await});
+        (a.await.unwrap().unwrap(), b.await.unwrap().unwrap())
+    });
+    // (Replies may arrive in either order, since the requests are concurrent.)
+    assert_eq!(format!("{}{}", a, b).len(), 4);
+    assert_ne!(a, b);
+    assert_eq!(server.count(), 3);
+
+    // The blocking API shares the same cache, and vice versa.
+    assert_eq!(fetcher.get_data(2016, 2).unwrap(), b);
+    assert_eq!(block_on(fetcher.get_data_async(2016, 1)).unwrap(), a);
+    assert_eq!(server.count(), 3);
+    assert!(matches!(block_on(fetcher.clone().offline().get_data_async(2016, 3)),
+        Err(aocfetch::FetchError::Offline)));
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn async_override() {
+    // A blocking source, such as the Fetcher itself, must not stall the runtime.
+    let cache = temp_cache("async-override");
+    let server = StandIn::new(vec![(200, "1\n")]);
+    aocfetch::set_source(fetcher(&cache, &server));
+    let data = block_on(aocfetch::get_data_async(2016, 1));
+    aocfetch::reset_source();
+    assert_eq!(data.unwrap(), "1\n");
+    assert_eq!(server.count(), 1);
+    let _ = std::fs::remove_dir_all(&cache);
+}
@@ -0,0 +1,63 @@
+//! Test puzzle-description parsing against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use common::{fetcher, temp_cache, StandIn};
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2015</title></head>
+<body>
+<header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Not Quite Lisp ---</h2><p>Santa is trying to deliver presents &amp; he's lost.</p>
+<p>An opening parenthesis, <code>(</code>, means he should go up one floor.</p>
+<p>For example:</p>
+<ul>
+<li><code>(())</code> and <code>()()</code> both result in floor <code>0</code>.</li>
+</ul>
+<pre><code>((()))
+)())())
+</code></pre>
+<p>The first example ends on floor <code><em>3</em></code>, and the second on <em><code>-3</code></em>.</p>
+<p>Next, an example with <em>highlights</em>:</p>
+<pre><code>a &lt;<em>b</em>&gt; c
+</code></pre>
+<p>This one has no answer.</p>
+</article>
+<p>To begin, <a href="1/input" target="_blank">get your puzzle input</a>.</p>
+</main>
+</body>
+</html>
+"#;
+
+#[test]
+fn fetch_and_parse() {
+    let (year, day) = (2015, 1);
+    let cache = temp_cache("puzzle");
+    let server = StandIn::new(vec![(200, PAGE)]);
+    let fetcher = fetcher(&cache, &server);
+
+    let puzzle = fetcher.get_puzzle(year, day).unwrap();
+    assert_eq!(server.count(), 1);
+    assert_eq!(server.last().path, "/2015/day/1");
+    assert_eq!(puzzle.title, "Not Quite Lisp");
+    assert_eq!(puzzle.parts(), 1);
+    assert!(puzzle.text.starts_with("--- Day 1: Not Quite Lisp ---\n\n"));
+    assert!(puzzle.text.contains("presents & he's lost."));
+    assert!(puzzle.text.contains("\n  - (()) and ()() both result in floor 0.\n"));
+    assert!(!puzzle.text.contains("get your puzzle input"));
+
+    assert_eq!(puzzle.examples.len(), 2);
+    assert_eq!(puzzle.examples[0].input, "((()))\n)())())\n");
+    assert_eq!(puzzle.examples[0].answers, vec!["3", "-3"]);
+    assert_eq!(puzzle.examples[0].answer(), Some("-3"));
+    assert_eq!(puzzle.examples[1].input, "a <b> c\n");
+    assert_eq!(puzzle.examples[1].answer(), None);
+
+    // Second request is served from the cache.
+    let cached = fetcher.get_puzzle(year, day).unwrap();
+    assert_eq!(server.count(), 1);
+    assert_eq!(cached.examples, puzzle.examples);
+    let _ = std::fs::remove_dir_all(&cache);
+}
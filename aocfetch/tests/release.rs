@@ -0,0 +1,61 @@
+//! Test release-time calculations and offline mode.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::{is_released, last_day, release_time, FetchError};
+use common::{fetcher, temp_cache, StandIn};
+use std::time::{Duration, UNIX_EPOCH};
+
+fn epoch(secs: u64) -> std::time::SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[test]
+fn release_times() {
+    assert_eq!(release_time(2015, 1).unwrap(), epoch(1448946000));
+    assert_eq!(release_time(2024, 25).unwrap(), epoch(1735102800));
+    assert_eq!(release_time(2025, 12).unwrap(), epoch(1765515600));
+    assert_eq!(last_day(2024), 25);
+    assert_eq!(last_day(2025), 12);
+    assert!(matches!(release_time(2014, 1), Err(FetchError::InvalidRequest(_))));
+    assert!(matches!(release_time(2015, 0), Err(FetchError::InvalidRequest(_))));
+    assert!(matches!(release_time(2024, 26), Err(FetchError::InvalidRequest(_))));
+    assert!(matches!(release_time(2025, 13), Err(FetchError::InvalidRequest(_))));
+    assert!(is_released(2015, 1));
+    assert!(!is_released(2999, 1));
+    assert!(!is_released(2015, 26));
+}
+
+#[test]
+fn fail_fast() {
+    // None of these requests should reach the server.
+    let cache = temp_cache("release");
+    let server = StandIn::new(vec![]);
+    let fetcher = fetcher(&cache, &server);
+    assert!(matches!(fetcher.get_data(2999, 1), Err(FetchError::NotYetReleased(_))));
+    assert!(matches!(fetcher.get_data(2025, 13), Err(FetchError::InvalidRequest(_))));
+    assert!(matches!(fetcher.get_puzzle(2999, 1), Err(FetchError::NotYetReleased(_))));
+    assert!(matches!(fetcher.submit(2999, 1, 1, 42), Err(FetchError::NotYetReleased(_))));
+    assert_eq!(server.count(), 0);
+}
+
+#[test]
+fn offline_mode() {
+    let cache = temp_cache("offline");
+    let server = StandIn::new(vec![(200, "12345\n")]);
+    let online = fetcher(&cache, &server);
+    let offline = online.clone().offline();
+    assert!(offline.is_offline());
+
+    // Cache miss fails without contacting the server.
+    assert!(matches!(offline.get_data(2017, 3), Err(FetchError::Offline)));
+    assert!(matches!(offline.get_puzzle(2017, 3), Err(FetchError::Offline)));
+    assert!(matches!(offline.submit(2017, 3, 1, 42), Err(FetchError::Offline)));
+    assert_eq!(server.count(), 0);
+
+    // Once the data is cached, offline mode can read it.
+    assert_eq!(online.get_data(2017, 3).unwrap(), "12345\n");
+    assert_eq!(offline.get_data(2017, 3).unwrap(), "12345\n");
+    assert_eq!(server.count(), 1);
+    let _ = std::fs::remove_dir_all(&cache);
+}
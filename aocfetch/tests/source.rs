@@ -0,0 +1,55 @@
+//! Test pluggable data sources, using fixtures and a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::{Cache, Chain, DataSource, FetchError, Fixture, Web};
+use common::{fetcher, temp_cache, StandIn};
+
+#[test]
+fn fixtures() {
+    let dir = temp_cache("fixtures");
+    std::fs::create_dir_all(dir.join("2016")).unwrap();
+    std::fs::write(dir.join("2016").join("input_03.txt"), "from file\n").unwrap();
+
+    // In-memory data takes priority over files.
+    let fixture = Fixture::dir(&dir).with(2016, 3, "in memory\n").with(2016, 4, "four\n");
+    assert_eq!(fixture.get_data(2016, 3).unwrap(), "in memory\n");
+    assert_eq!(fixture.get_data(2016, 4).unwrap(), "four\n");
+    assert_eq!(Fixture::dir(&dir).get_data(2016, 3).unwrap(), "from file\n");
+    assert!(matches!(fixture.get_data(2016, 5), Err(FetchError::NotFound(_))));
+
+    // The shortcut can be redirected for the whole process.
+    aocfetch::set_source(fixture);
+    assert_eq!(aocfetch::get_data(2016, 4).unwrap(), "four\n");
+    aocfetch::reset_source();
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn web_and_cache() {
+    let cache = temp_cache("sources");
+    let server = StandIn::new(vec![(200, "web 1\n"), (200, "web 2\n")]);
+    let fetcher = fetcher(&cache, &server);
+
+    // The web source never touches the cache.
+    let web = Web::new(fetcher.clone());
+    assert_eq!(web.get_data(2017, 1).unwrap(), "web 1\n");
+    let cached = Cache::new(fetcher.clone());
+    assert!(matches!(cached.get_data(2017, 1), Err(FetchError::NotFound(_))));
+
+    // The Fetcher itself reads and writes the cache.
+    assert_eq!(DataSource::get_data(&fetcher, 2017, 1).unwrap(), "web 2\n");
+    assert_eq!(cached.get_data(2017, 1).unwrap(), "web 2\n");
+    assert_eq!(server.count(), 2);
+
+    // A chain stops at the first success, or reports the last error.
+    let chain = Chain::new()
+        .then(Fixture::new().with(2017, 2, "fixture\n"))
+        .then(cached)
+        .then(Web::new(fetcher.offline()));
+    assert_eq!(chain.get_data(2017, 1).unwrap(), "web 2\n");
+    assert_eq!(chain.get_data(2017, 2).unwrap(), "fixture\n");
+    assert!(matches!(chain.get_data(2017, 3), Err(FetchError::Offline)));
+    assert_eq!(server.count(), 2);
+    let _ = std::fs::remove_dir_all(&cache);
+}
@@ -0,0 +1,108 @@
+//! Test answer submission against a local stand-in server.
+//! Copyright 2023 by Alex Utter
+
+mod common;
+use aocfetch::Verdict;
+use common::{fetcher, temp_cache, StandIn};
+use std::time::Duration;
+
+fn reply(msg: &str) -> String {
+    format!("<html><body><main><article><p>{}</p></article></main></body></html>", msg)
+}
+
+#[test]
+fn submit_and_log() {
+    let (year, day) = (2015, 25);
+    let cache = temp_cache("submit");
+    let too_high = reply("That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data.");
+    let too_low = reply("That's not the right answer; your answer is too low.");
+    let too_soon = reply("You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 23s left to wait.");
+    let correct = reply("That's the right answer!  You are <span class=\"day-success\">\
+        one gold star</span> closer to powering the weather machine.");
+    let solved = reply("You don't seem to be solving the right level.  \
+        Did you already complete it?");
+    let server = StandIn::new(vec![
+        (200, &too_high), (200, &too_low), (200, &too_soon),
+        (200, &correct), (200, &solved)]);
+    let fetcher = fetcher(&cache, &server);
+
+    // First attempt goes to the server.
+    assert_eq!(fetcher.submit(year, day, 1, 500).unwrap(), Verdict::TooHigh);
+    assert_eq!(server.count(), 1);
+    let req = server.last();
+    assert_eq!(req.method, "POST");
+    assert_eq!(req.path, "/2015/day/25/answer");
+    assert_eq!(req.header("cookie"), Some("session=0123abcd"));
+    assert_eq!(req.body, "level=1&answer=500");
+
+    // Answers above a known upper bound are rejected locally.
+    assert_eq!(fetcher.submit(year, day, 1, 600).unwrap(), Verdict::TooHigh);
+    assert_eq!(fetcher.submit(year, day, 1, 500).unwrap(), Verdict::TooHigh);
+    assert_eq!(server.count(), 1);
+
+    // Same for the lower bound.
+    assert_eq!(fetcher.submit(year, day, 1, 100).unwrap(), Verdict::TooLow);
+    assert_eq!(fetcher.submit(year, day, 1, 50).unwrap(), Verdict::TooLow);
+    assert_eq!(server.count(), 2);
+
+    // Rate-limited attempts are not logged, so they can be retried.
+    assert_eq!(fetcher.submit(year, day, 1, 300).unwrap(),
+        Verdict::RateLimited(Duration::from_secs(83)));
+    assert_eq!(fetcher.submit(year, day, 1, " 300\n").unwrap(), Verdict::Correct);
+    assert_eq!(server.count(), 4);
+
+    // Once the answer is known, everything else is answered locally.
+    assert_eq!(fetcher.submit(year, day, 1, 300).unwrap(), Verdict::Correct);
+    assert_eq!(fetcher.submit(year, day, 1, 299).unwrap(), Verdict::TooLow);
+    assert_eq!(fetcher.submit(year, day, 1, "abc").unwrap(), Verdict::Wrong);
+    assert_eq!(server.count(), 4);
+
+    // Part 2 is tracked separately.
+    assert_eq!(fetcher.submit(year, day, 2, 7).unwrap(), Verdict::AlreadySolved);
+    assert_eq!(server.count(), 5);
+    assert!(fetcher.submit(year, day, 3, 7).is_err());
+    assert!(fetcher.submit(year, day, 2, "").is_err());
+
+    // The log is stored in the cache folder.
+    let log = std::fs::read_to_string(fetcher.account_dir().join("2015/answers_25.txt")).unwrap();
+    assert_eq!(log, "1\thigh\t500\n1\tlow\t100\n1\tcorrect\t300\n");
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn submit_no_retry() {
+    let cache = temp_cache("submit-retry");
+    let correct = reply("That's the right answer!");
+    let server = StandIn::new(vec![(503, "Service Unavailable"), (200, &correct)]);
+    let fetcher = fetcher(&cache, &server);
+
+    // The server may have counted the attempt, so it is not repeated.
+    let result = fetcher.submit(2015, 25, 1, 300);
+    assert!(matches!(result, Err(aocfetch::FetchError::Server(503, _))));
+    assert_eq!(server.count(), 1);
+
+    // Nor is it logged, so the caller can try again.
+    assert_eq!(fetcher.submit(2015, 25, 1, 300).unwrap(), Verdict::Correct);
+    assert_eq!(server.count(), 2);
+    let _ = std::fs::remove_dir_all(&cache);
+}
+
+#[test]
+fn submit_wait_time() {
+    let cache = temp_cache("submit-wait");
+    let wait = |left: &str| reply(&format!("You gave an answer too recently; \
+        you have to wait after submitting an answer before trying again.  \
+        You have {} left to wait.", left));
+    let (odd, usual) = (wait("2\u{20ac}"), wait("1h 5s"));
+    let server = StandIn::new(vec![(200, &odd), (200, &usual)]);
+    let fetcher = fetcher(&cache, &server);
+
+    // Anything unexpected falls back to one minute.
+    assert_eq!(fetcher.submit(2015, 25, 1, 300).unwrap(),
+        Verdict::RateLimited(Duration::from_secs(60)));
+    assert_eq!(fetcher.submit(2015, 25, 1, 300).unwrap(),
+        Verdict::RateLimited(Duration::from_secs(3605)));
+    let _ = std::fs::remove_dir_all(&cache);
+}
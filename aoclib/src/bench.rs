@@ -0,0 +1,195 @@
+//! Benchmark each step of a solution, with a running history of results.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each step (parse, part 1, part 2) is timed separately, over several runs
+//! after a few warm-up runs.  Results can be appended to a CSV file, so that
+//! a change in performance shows up against previous runs of the same day.
+
+use crate::solution::{Answers, Entry};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Names for each step, in the order they are run.
+pub const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Number of warm-up and timed runs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig { warmup: 1, runs: 10 }
+    }
+}
+
+/// Summary statistics for one step.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Timing {
+    /// Summarize a list of measurements.
+    pub fn new(times: &[Duration]) -> Timing {
+        if times.is_empty() {return Timing::default();}
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {sorted[n/2]} else {(sorted[n/2-1] + sorted[n/2]) / 2};
+        Timing { min: sorted[0], median }
+    }
+}
+
+/// Benchmark results for one day.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub year: usize,
+    pub day: usize,
+    pub runs: usize,
+    pub answers: Answers,
+    pub steps: [Timing; 3],
+}
+
+impl BenchReport {
+    /// Sum of the median time for each step.
+    pub fn total(&self) -> Duration {
+        self.steps.iter().map(|t| t.median).sum()
+    }
+}
+
+impl Entry {
+    /// Solve the given input repeatedly, timing each step.
+    pub fn bench(&self, input: &str, cfg: BenchConfig) -> BenchReport {
+        for _ in 0..cfg.warmup {self.solve(input);}
+        let runs = cfg.runs.max(1);
+        let mut results: Vec<Answers> = (0..runs).map(|_| self.solve(input)).collect();
+        let times = |f: fn(&Answers) -> Duration| -> Timing {
+            Timing::new(&results.iter().map(f).collect::<Vec<_>>())
+        };
+        let steps = [
+            times(|a| a.parse_time),
+            times(|a| a.part1_time),
+            times(|a| a.part2_time),
+        ];
+        BenchReport {
+            year: self.year,
+            day: self.day,
+            runs,
+            answers: results.pop().unwrap(),
+            steps,
+        }
+    }
+}
+
+/// One line of benchmark history.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryRow {
+    pub timestamp: u64,
+    pub year: usize,
+    pub day: usize,
+    pub step: String,
+    pub runs: usize,
+    pub timing: Timing,
+}
+
+/// Benchmark history, stored as a CSV file.
+#[derive(Clone, Debug)]
+pub struct History {
+    path: PathBuf,
+    rows: Vec<HistoryRow>,
+}
+
+const HEADER: &str = "timestamp,year,day,step,runs,min_ns,median_ns";
+
+impl History {
+    /// Load history from the given file.  A missing file is treated as empty.
+    pub fn load<P: Into<PathBuf>>(path: P) -> Result<History, String> {
+        let path = path.into();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        let rows = text.lines()
+            .filter(|line| !line.is_empty() && *line != HEADER)
+            .map(parse_row)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| format!("{}: Invalid history", path.display()))?;
+        Ok(History { path, rows })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn rows(&self) -> &[HistoryRow] {
+        &self.rows
+    }
+
+    /// Most recent result for a given day and step, if any.
+    pub fn last(&self, year: usize, day: usize, step: &str) -> Option<&HistoryRow> {
+        self.rows.iter().rev()
+            .find(|r| r.year == year && r.day == day && r.step == step)
+    }
+
+    /// Append a new report, both in memory and to the file.
+    pub fn append(&mut self, report: &BenchReport) -> Result<(), String> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)
+            .map(|t| t.as_secs()).unwrap_or(0);
+        let new_file = !self.path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true).append(true).open(&self.path)
+            .map_err(|e| format!("{}: {}", self.path.display(), e))?;
+        let mut text = String::new();
+        if new_file {text.push_str(HEADER); text.push('\n');}
+        for (step, timing) in STEPS.iter().zip(report.steps.iter()) {
+            let row = HistoryRow {
+                timestamp,
+                year: report.year,
+                day: report.day,
+                step: step.to_string(),
+                runs: report.runs,
+                timing: *timing,
+            };
+            text.push_str(&format_row(&row));
+            text.push('\n');
+            self.rows.push(row);
+        }
+        file.write_all(text.as_bytes())
+            .map_err(|e| format!("{}: {}", self.path.display(), e))
+    }
+}
+
+fn format_row(row: &HistoryRow) -> String {
+    format!("{},{},{},{},{},{},{}",
+        row.timestamp, row.year, row.day, row.step, row.runs,
+        row.timing.min.as_nanos(), row.timing.median.as_nanos())
+}
+
+fn parse_row(line: &str) -> Option<HistoryRow> {
+    let tok: Vec<&str> = line.split(',').collect();
+    if tok.len() != 7 {return None;}
+    Some(HistoryRow {
+        timestamp: tok[0].parse().ok()?,
+        year: tok[1].parse().ok()?,
+        day: tok[2].parse().ok()?,
+        step: tok[3].to_string(),
+        runs: tok[4].parse().ok()?,
+        timing: Timing {
+            min: Duration::from_nanos(tok[5].parse().ok()?),
+            median: Duration::from_nanos(tok[6].parse().ok()?),
+        },
+    })
+}
+
+/// Relative change from a previous median, e.g., "+12.5%".
+pub fn change(prev: Duration, next: Duration) -> String {
+    if prev.is_zero() {return String::from("n/a");}
+    let pct = 100.0 * (next.as_secs_f64() / prev.as_secs_f64() - 1.0);
+    format!("{:+.1}%", pct)
+}
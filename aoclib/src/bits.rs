@@ -0,0 +1,329 @@
+//! Compact sets of small integers, and small square bitmaps.
+//! Copyright 2025 by Alex Utter
+//!
+//! Both types pack their contents into a single `u64`, so they are cheap
+//! to copy, hash, and compare.  That makes them a good fit for search
+//! states, e.g., which items are on each floor or which buttons are pressed.
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign};
+
+/// A set of integers in the range 0..N, for N up to 64.  Larger sets
+/// are rejected at compile time:
+/// ```compile_fail
+/// let set = aoclib::bits::BitSet::<100>::single(70);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitSet<const N: usize> {
+    bits: u64,
+}
+
+impl<const N: usize> BitSet<N> {
+    // Every constructor checks this, so an unsupported N fails to compile.
+    const VALID: () = assert!(N <= 64, "BitSet is limited to 64 bits");
+
+    // Mask with the lowest N bits set.
+    const MASK: u64 = if N >= 64 {u64::MAX} else {(1u64 << N) - 1};
+
+    pub const fn new() -> Self {
+        let () = Self::VALID;
+        BitSet { bits: 0 }
+    }
+
+    /// Every integer in 0..N.
+    pub const fn full() -> Self {
+        let () = Self::VALID;
+        BitSet { bits: Self::MASK }
+    }
+
+    /// A set containing only the given integer.
+    pub fn single(n: usize) -> Self {
+        let () = Self::VALID;
+        assert!(n < N, "BitSet index out of range");
+        BitSet { bits: 1u64 << n }
+    }
+
+    /// Create a set from its bit representation, where bit n means
+    /// n is in the set.
+    pub fn from_bits(bits: u64) -> Self {
+        let () = Self::VALID;
+        assert_eq!(bits & !Self::MASK, 0, "BitSet index out of range");
+        BitSet { bits }
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    pub fn contains(&self, n: usize) -> bool {
+        n < N && self.bits & (1u64 << n) != 0
+    }
+
+    /// Add an integer, returning true if it was not already present.
+    pub fn insert(&mut self, n: usize) -> bool {
+        let prev = self.contains(n);
+        self.bits |= Self::single(n).bits;
+        !prev
+    }
+
+    /// Remove an integer, returning true if it was present.
+    pub fn remove(&mut self, n: usize) -> bool {
+        let prev = self.contains(n);
+        if prev {self.bits &= !(1u64 << n);}
+        prev
+    }
+
+    pub fn toggle(&mut self, n: usize) {
+        self.bits ^= Self::single(n).bits;
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Smallest integer in the set, if any.
+    pub fn first(&self) -> Option<usize> {
+        if self.bits == 0 {None} else {Some(self.bits.trailing_zeros() as usize)}
+    }
+
+    /// Is every element of this set also in the other?
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.bits & other.bits == 0
+    }
+
+    /// Iterate over each integer in the set, in ascending order.
+    pub fn iter(&self) -> Iter {
+        Iter { bits: self.bits }
+    }
+
+    /// Iterate over every subset of this set, including the empty set and
+    /// the set itself, in ascending order of their bit representation.
+    pub fn subsets(&self) -> Subsets<N> {
+        Subsets { mask: self.bits, next: Some(0) }
+    }
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {BitSet::new()}
+}
+
+/// Iterator over the integers in a `BitSet`.
+pub struct Iter {
+    bits: u64,
+}
+
+impl Iterator for Iter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.bits == 0 {return None;}
+        let n = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;     // Clear lowest set bit
+        Some(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.bits.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
+/// Iterator over the subsets of a `BitSet`.
+pub struct Subsets<const N: usize> {
+    mask: u64,
+    next: Option<u64>,
+}
+
+impl<const N: usize> Iterator for Subsets<N> {
+    type Item = BitSet<N>;
+
+    fn next(&mut self) -> Option<BitSet<N>> {
+        let bits = self.next?;
+        // Increment only the bits inside the mask, stopping at wraparound.
+        let succ = bits.wrapping_sub(self.mask) & self.mask;
+        self.next = if succ == 0 {None} else {Some(succ)};
+        Some(BitSet { bits })
+    }
+}
+
+impl<const N: usize> IntoIterator for BitSet<N> {
+    type Item = usize;
+    type IntoIter = Iter;
+    fn into_iter(self) -> Iter {self.iter()}
+}
+
+impl<const N: usize> FromIterator<usize> for BitSet<N> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        for n in iter {set.insert(n);}
+        set
+    }
+}
+
+impl<const N: usize> fmt::Debug for BitSet<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// Set operations for each bitwise operator.
+macro_rules! impl_setop {
+    ($tr:ident, $f:ident, $tra:ident, $fa:ident, $op:tt) => {
+        impl<const N: usize> $tr for BitSet<N> {
+            type Output = Self;
+            fn $f(self, rhs: Self) -> Self {BitSet { bits: self.bits $op rhs.bits }}
+        }
+        impl<const N: usize> $tra for BitSet<N> {
+            fn $fa(&mut self, rhs: Self) {self.bits = self.bits $op rhs.bits;}
+        }
+    };
+}
+
+impl_setop!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_setop!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_setop!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+/// Set difference, i.e., elements in the first set but not the second.
+impl<const N: usize> Sub for BitSet<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {BitSet { bits: self.bits & !rhs.bits }}
+}
+
+impl<const N: usize> SubAssign for BitSet<N> {
+    fn sub_assign(&mut self, rhs: Self) {self.bits &= !rhs.bits;}
+}
+
+/// Complement, relative to the full set 0..N.
+impl<const N: usize> Not for BitSet<N> {
+    type Output = Self;
+    fn not(self) -> Self {BitSet { bits: !self.bits & Self::MASK }}
+}
+
+/// An NxN bitmap, for N up to 8, stored in reading order.  Bit N*r + c
+/// holds row r, column c.  Larger bitmaps are rejected at compile time:
+/// ```compile_fail
+/// let grid = aoclib::bits::BitGrid::<9>::new();
+/// ```
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitGrid<const N: usize> {
+    bits: u64,
+}
+
+impl<const N: usize> BitGrid<N> {
+    // Every constructor checks this, so an unsupported N fails to compile.
+    const VALID: () = assert!(N <= 8, "BitGrid is limited to 8x8");
+
+    const MASK: u64 = if N >= 8 {u64::MAX} else {(1u64 << (N * N)) - 1};
+
+    pub const fn new() -> Self {
+        let () = Self::VALID;
+        BitGrid { bits: 0 }
+    }
+
+    /// Create a bitmap from its representation in reading order.
+    pub fn from_bits(bits: u64) -> Self {
+        let () = Self::VALID;
+        assert_eq!(bits & !Self::MASK, 0, "BitGrid index out of range");
+        BitGrid { bits }
+    }
+
+    /// Create a bitmap by calling f(r, c) for each cell.
+    pub fn from_fn<F: FnMut(usize, usize) -> bool>(mut f: F) -> Self {
+        let mut grid = BitGrid::new();
+        for r in 0..N {
+            for c in 0..N {
+                if f(r, c) {grid.set(r, c, true);}
+            }
+        }
+        grid
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    // Bit mask for the given cell.
+    fn rc(r: usize, c: usize) -> u64 {
+        assert!(r < N && c < N, "BitGrid index out of range");
+        1u64 << (N * r + c)
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.bits & Self::rc(r, c) != 0
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: bool) {
+        if value {self.bits |= Self::rc(r, c);} else {self.bits &= !Self::rc(r, c);}
+    }
+
+    /// Number of cells that are set.
+    pub fn count(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Mirror left-to-right.
+    pub fn hflip(&self) -> Self {
+        Self::from_fn(|r, c| self.get(r, N - 1 - c))
+    }
+
+    /// Mirror top-to-bottom.
+    pub fn vflip(&self) -> Self {
+        Self::from_fn(|r, c| self.get(N - 1 - r, c))
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(|r, c| self.get(c, r))
+    }
+
+    /// Rotate 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Self {
+        Self::from_fn(|r, c| self.get(N - 1 - c, r))
+    }
+
+    /// Rotate 90 degrees counter-clockwise.
+    pub fn rotate_left(&self) -> Self {
+        Self::from_fn(|r, c| self.get(c, N - 1 - r))
+    }
+
+    /// All eight rotations and reflections, starting with this bitmap.
+    /// Some may be identical if the bitmap is symmetric.
+    pub fn symmetries(&self) -> [Self; 8] {
+        let mut result = [*self; 8];
+        for n in 1..4 {result[n] = result[n-1].rotate_right();}
+        for n in 4..8 {result[n] = result[n-4].hflip();}
+        result
+    }
+
+    /// A representative that is the same for every rotation and
+    /// reflection of this bitmap.
+    pub fn canonical(&self) -> Self {
+        self.symmetries().into_iter().min().unwrap_or(*self)
+    }
+}
+
+impl<const N: usize> Default for BitGrid<N> {
+    fn default() -> Self {BitGrid::new()}
+}
+
+impl<const N: usize> fmt::Debug for BitGrid<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = (0..N)
+            .map(|r| (0..N).map(|c| if self.get(r, c) {'#'} else {'.'}).collect())
+            .collect();
+        write!(f, "BitGrid({})", rows.join("/"))
+    }
+}
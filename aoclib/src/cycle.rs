@@ -0,0 +1,133 @@
+//! Cycle detection for simulations that eventually repeat.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each function takes an initial state and a step function, and finds the
+//! cycle that the sequence init, step(init), step(step(init)), ... falls
+//! into.  Cycles are reported as (prefix, period), i.e., the state after
+//! step N is the same as after step N + period for every N >= prefix.
+//!
+//! Brent's and Floyd's algorithms need only equality and constant memory,
+//! but they never return if the sequence doesn't repeat.  The hashed
+//! variants keep every state, and can stop early once step N is reached.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Find a cycle using Brent's algorithm.
+pub fn find_cycle<S, F>(init: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // Search successive powers of two for the period.
+    let mut power = 1usize;
+    let mut period = 1usize;
+    let mut tortoise = init.clone();
+    let mut hare = step(&init);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+    // Find the start of the cycle, using two states one period apart.
+    let mut tortoise = init.clone();
+    let mut hare = init;
+    for _ in 0..period {hare = step(&hare);}
+    let mut prefix = 0usize;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    (prefix, period)
+}
+
+/// Find a cycle using Floyd's "tortoise and hare" algorithm.
+pub fn find_cycle_floyd<S, F>(init: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // Find any meeting point inside the cycle.
+    let mut tortoise = step(&init);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    // Find the start of the cycle.
+    let mut prefix = 0usize;
+    tortoise = init;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    // Find the length of the cycle.
+    let mut period = 1usize;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    (prefix, period)
+}
+
+/// Find a cycle by remembering every state.
+pub fn find_cycle_hashed<S, F>(init: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = init;
+    for n in 0usize.. {
+        if let Some(prev) = seen.insert(state.clone(), n) {
+            return (prev, n - prev);
+        }
+        state = step(&state);
+    }
+    unreachable!()
+}
+
+/// Equivalent step number, no larger than prefix + period, for any step N.
+pub fn reduce(n: usize, prefix: usize, period: usize) -> usize {
+    if n < prefix {n} else {prefix + (n - prefix) % period}
+}
+
+/// State after N steps, skipping ahead once the sequence repeats.
+pub fn nth_state<S, F>(init: S, step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    nth_state_by_key(init, step, |s| s.clone(), n)
+}
+
+/// As `nth_state`, for states that can't be hashed directly.  Two states
+/// are treated as the same if their keys are equal.
+pub fn nth_state_by_key<S, K, F, G>(init: S, mut step: F, mut key: G, n: usize) -> S
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    G: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = init;
+    while history.len() < n {
+        if let Some(prev) = seen.insert(key(&state), history.len()) {
+            let idx = reduce(n, prev, history.len() - prev);
+            return history.swap_remove(idx);
+        }
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
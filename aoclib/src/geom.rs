@@ -0,0 +1,349 @@
+//! Points and vectors in 2D, 3D, and on a hexagonal grid, plus directions.
+//! Copyright 2025 by Alex Utter
+//!
+//! `Vec2` and `Vec3` work with any numeric type, and implement the usual
+//! arithmetic operators.  `Hex` uses axial coordinates, with +q to the east
+//! and +r to the southeast, so that each hex has neighbors in six directions.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Numeric types usable as coordinates.
+pub trait Coord: Copy + Default + PartialOrd
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {}
+
+impl<T> Coord for T where T: Copy + Default + PartialOrd
+    + Add<Output = T> + Sub<Output = T> + Mul<Output = T> {}
+
+// Absolute difference, which also works for unsigned types.
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a < b {b - a} else {a - b}
+}
+
+fn max<T: Coord>(a: T, b: T) -> T {
+    if a < b {b} else {a}
+}
+
+/// A two-dimensional point or vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Z-component of the cross product, i.e., twice the signed area of
+    /// the triangle formed with the origin.
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Sum of the distance along each axis.
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Largest distance along any axis.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        max(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+
+    /// Square of the Euclidean distance.
+    pub fn dist_sq(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Vec2<T> {
+    /// Rotate 90 degrees counter-clockwise, with +x right and +y up.
+    pub fn rotate_left(&self) -> Self {
+        Vec2 { x: -self.y, y: self.x }
+    }
+
+    /// Rotate 90 degrees clockwise, with +x right and +y up.
+    pub fn rotate_right(&self) -> Self {
+        Vec2 { x: self.y, y: -self.x }
+    }
+}
+
+/// A three-dimensional point or vector.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Sum of the distance along each axis.
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// Largest distance along any axis.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        max(max(abs_diff(self.x, other.x), abs_diff(self.y, other.y)), abs_diff(self.z, other.z))
+    }
+
+    /// Square of the Euclidean distance.
+    pub fn dist_sq(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+}
+
+/// Number of distinct orientations of a cube, see `Vec3::rotate`.
+pub const ROTATIONS: usize = 24;
+
+impl<T: Coord + Neg<Output = T>> Vec3<T> {
+    /// Apply one of the 24 rotations that map the axes onto themselves:
+    ///  * Pick forward axis (new +x)
+    ///  * Pick upward axis (new +y)
+    ///  * Remaining axis by right-hand-rule
+    pub fn rotate(&self, r: usize) -> Self {
+        let (x, y, z) = (self.x, self.y, self.z);
+        let (x, y, z) = match r {
+            0  => ( x,  y,  z),
+            1  => ( x, -y, -z),
+            2  => ( x,  z, -y),
+            3  => ( x, -z,  y),
+            4  => (-x,  y, -z),
+            5  => (-x, -y,  z),
+            6  => (-x,  z,  y),
+            7  => (-x, -z, -y),
+            8  => ( y,  x, -z),
+            9  => ( y, -x,  z),
+            10 => ( y,  z,  x),
+            11 => ( y, -z, -x),
+            12 => (-y,  x,  z),
+            13 => (-y, -x, -z),
+            14 => (-y,  z, -x),
+            15 => (-y, -z,  x),
+            16 => ( z,  x,  y),
+            17 => ( z, -x, -y),
+            18 => ( z,  y, -x),
+            19 => ( z, -y,  x),
+            20 => (-z,  x, -y),
+            21 => (-z, -x,  y),
+            22 => (-z,  y,  x),
+            23 => (-z, -y, -x),
+            _  => panic!("Invalid rotation {}", r),
+        };
+        Vec3 { x, y, z }
+    }
+
+    /// Every rotation of this vector, in the same order as `rotate`.
+    pub fn rotations(&self) -> [Self; ROTATIONS] {
+        std::array::from_fn(|r| self.rotate(r))
+    }
+}
+
+// Element-wise arithmetic for each vector type.
+macro_rules! impl_ops {
+    ($name:ident, $($f:ident),+) => {
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {$name { $($f: self.$f + rhs.$f),+ }}
+        }
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {$name { $($f: self.$f - rhs.$f),+ }}
+        }
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+            fn neg(self) -> Self {$name { $($f: -self.$f),+ }}
+        }
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {$name { $($f: self.$f * rhs),+ }}
+        }
+        impl<T: Copy + Add<Output = T>> AddAssign for $name<T> {
+            fn add_assign(&mut self, rhs: Self) {$(self.$f = self.$f + rhs.$f;)+}
+        }
+        impl<T: Copy + Sub<Output = T>> SubAssign for $name<T> {
+            fn sub_assign(&mut self, rhs: Self) {$(self.$f = self.$f - rhs.$f;)+}
+        }
+    };
+}
+
+impl_ops!(Vec2, x, y);
+impl_ops!(Vec3, x, y, z);
+
+/// Orthogonal directions on a grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting from north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Parse a direction, e.g., 'N', 'U', or '^' for north.
+    pub fn from_char(ch: char) -> Option<Dir4> {
+        match ch {
+            'N' | 'U' | '^' => Some(Dir4::North),
+            'E' | 'R' | '>' => Some(Dir4::East),
+            'S' | 'D' | 'v' => Some(Dir4::South),
+            'W' | 'L' | '<' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Row and column offset, matching `grid::DIR4`.
+    pub fn delta(self) -> (isize, isize) {
+        crate::grid::DIR4[self as usize]
+    }
+
+    /// Unit vector, with +x east and +y north.
+    pub fn unit(self) -> Vec2<i64> {
+        let (dr, dc) = self.delta();
+        Vec2::new(dc as i64, -dr as i64)
+    }
+}
+
+/// Directions on a hexagonal grid, where each row is offset by half a tile.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HexDir {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDir {
+    /// All directions, clockwise starting from east.
+    pub const ALL: [HexDir; 6] = [HexDir::East, HexDir::SouthEast, HexDir::SouthWest,
+                                  HexDir::West, HexDir::NorthWest, HexDir::NorthEast];
+
+    /// Parse a direction, e.g., "e" or "se".
+    pub fn parse(s: &str) -> Option<HexDir> {
+        ["e", "se", "sw", "w", "nw", "ne"].iter()
+            .position(|x| *x == s).map(|n| HexDir::ALL[n])
+    }
+
+    pub fn turn_left(self) -> HexDir {
+        HexDir::ALL[(self as usize + 5) % 6]
+    }
+
+    pub fn turn_right(self) -> HexDir {
+        HexDir::ALL[(self as usize + 1) % 6]
+    }
+
+    pub fn reverse(self) -> HexDir {
+        HexDir::ALL[(self as usize + 3) % 6]
+    }
+}
+
+/// Position on a hexagonal grid, in axial coordinates.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub const fn new(q: i64, r: i64) -> Self {
+        Hex { q, r }
+    }
+
+    /// Third cube coordinate, so that q + r + s = 0.
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Adjacent position in the given direction.
+    pub fn step(&self, dir: HexDir) -> Hex {
+        let (dq, dr) = match dir {
+            HexDir::East      => ( 1,  0),
+            HexDir::SouthEast => ( 0,  1),
+            HexDir::SouthWest => (-1,  1),
+            HexDir::West      => (-1,  0),
+            HexDir::NorthWest => ( 0, -1),
+            HexDir::NorthEast => ( 1, -1),
+        };
+        Hex { q: self.q + dq, r: self.r + dr }
+    }
+
+    /// All six adjacent positions.
+    pub fn neighbors(&self) -> [Hex; 6] {
+        HexDir::ALL.map(|d| self.step(d))
+    }
+
+    /// Number of steps between two positions.
+    pub fn distance(&self, other: &Hex) -> i64 {
+        let d = *self - *other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    /// Rotate 60 degrees clockwise about the origin.
+    pub fn rotate_right(&self) -> Hex {
+        Hex { q: -self.r, r: -self.s() }
+    }
+
+    /// Rotate 60 degrees counter-clockwise about the origin.
+    pub fn rotate_left(&self) -> Hex {
+        Hex { q: -self.s(), r: -self.q }
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+    fn add(self, rhs: Hex) -> Hex {Hex { q: self.q + rhs.q, r: self.r + rhs.r }}
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+    fn sub(self, rhs: Hex) -> Hex {Hex { q: self.q - rhs.q, r: self.r - rhs.r }}
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+    fn neg(self) -> Hex {Hex { q: -self.q, r: -self.r }}
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+    fn mul(self, rhs: i64) -> Hex {Hex { q: self.q * rhs, r: self.r * rhs }}
+}
@@ -0,0 +1,228 @@
+//! Two-dimensional grid of values, as used by many puzzles.
+//! Copyright 2025 by Alex Utter
+//!
+//! Values are stored in a single flat vector, in row-major order.  Positions
+//! are (row, column) pairs, with row zero at the top of the input.  Offsets
+//! and directions are signed (row, column) pairs.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Row and column position within a grid.
+pub type Rc = (usize, usize);
+
+/// Signed row and column offset, e.g., a direction of travel.
+pub type Delta = (isize, isize);
+
+/// Orthogonal directions, clockwise starting from north.
+pub const DIR4: [Delta; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Orthogonal and diagonal directions, clockwise starting from north.
+pub const DIR8: [Delta; 8] = [
+    (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+/// A rectangular grid of values.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid where every cell has the same value.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self {
+        Grid { rows, cols, data: vec![fill; rows * cols] }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from row-major data.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "Grid size mismatch");
+        Grid { rows, cols, data }
+    }
+
+    /// Parse a grid with one character per cell, mapping each to a value.
+    /// Blank lines before and after the grid are ignored, but spaces are
+    /// cells like any other.  Every line must be the same length.
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut f: F) -> Self {
+        let mut rows = 0usize;
+        let mut cols = 0usize;
+        let mut data = Vec::new();
+        for line in grid_lines(input) {
+            let before = data.len();
+            data.extend(line.chars().map(&mut f));
+            if rows == 0 {cols = data.len();}
+            assert_eq!(data.len() - before, cols, "Ragged grid at row {}", rows);
+            rows += 1;
+        }
+        Grid { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Is the given position inside the grid?
+    pub fn contains(&self, rc: Rc) -> bool {
+        rc.0 < self.rows && rc.1 < self.cols
+    }
+
+    /// Bounds-checked access.
+    pub fn get(&self, rc: Rc) -> Option<&T> {
+        if self.contains(rc) {Some(&self.data[rc.0 * self.cols + rc.1])} else {None}
+    }
+
+    /// Bounds-checked mutable access.
+    pub fn get_mut(&mut self, rc: Rc) -> Option<&mut T> {
+        if self.contains(rc) {Some(&mut self.data[rc.0 * self.cols + rc.1])} else {None}
+    }
+
+    /// Set the value at a given position.  Returns false if out of bounds.
+    pub fn set(&mut self, rc: Rc, val: T) -> bool {
+        match self.get_mut(rc) {
+            Some(x) => {*x = val; true},
+            None => false,
+        }
+    }
+
+    /// Access with signed coordinates that wrap around each edge, as in
+    /// an infinitely repeating tiling of the grid.
+    pub fn get_wrap(&self, r: isize, c: isize) -> &T {
+        assert!(!self.data.is_empty(), "Grid is empty, so nothing to wrap");
+        let r = r.rem_euclid(self.rows as isize) as usize;
+        let c = c.rem_euclid(self.cols as isize) as usize;
+        &self[(r, c)]
+    }
+
+    /// Move from a position by the given offset, if the result is in bounds.
+    pub fn offset(&self, rc: Rc, d: Delta) -> Option<Rc> {
+        let r = rc.0.checked_add_signed(d.0)?;
+        let c = rc.1.checked_add_signed(d.1)?;
+        if self.contains((r, c)) {Some((r, c))} else {None}
+    }
+
+    /// Value at an offset from the given position, if in bounds.
+    pub fn peek(&self, rc: Rc, d: Delta) -> Option<&T> {
+        self.offset(rc, d).map(|x| &self[x])
+    }
+
+    /// In-bounds orthogonal neighbors of a given position.
+    pub fn neighbors4(&self, rc: Rc) -> impl Iterator<Item = Rc> + '_ {
+        DIR4.iter().filter_map(move |d| self.offset(rc, *d))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors of a given position.
+    pub fn neighbors8(&self, rc: Rc) -> impl Iterator<Item = Rc> + '_ {
+        DIR8.iter().filter_map(move |d| self.offset(rc, *d))
+    }
+
+    /// All values in a given row.
+    pub fn row(&self, r: usize) -> &[T] {
+        assert!(r < self.rows, "Grid row out of bounds: {}", r);
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// All values in a given column, from top to bottom.
+    pub fn col(&self, c: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(c < self.cols, "Grid column out of bounds: {}", c);
+        self.data[c..].iter().step_by(self.cols.max(1)).take(self.rows)
+    }
+
+    /// Positions and values from a starting point in a straight line,
+    /// including the start, until leaving the grid.
+    pub fn ray(&self, rc: Rc, d: Delta) -> impl Iterator<Item = (Rc, &T)> + '_ {
+        let first = if self.contains(rc) {Some(rc)} else {None};
+        std::iter::successors(first, move |x| self.offset(*x, d))
+            .map(move |x| (x, &self[x]))
+    }
+
+    /// Diagonal running down and to the right from a given position.
+    pub fn diag(&self, rc: Rc) -> impl Iterator<Item = (Rc, &T)> + '_ {
+        self.ray(rc, (1, 1))
+    }
+
+    /// Diagonal running down and to the left from a given position.
+    pub fn anti_diag(&self, rc: Rc) -> impl Iterator<Item = (Rc, &T)> + '_ {
+        self.ray(rc, (1, -1))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Rc> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |n| (n / cols, n % cols))
+    }
+
+    /// Every position and value in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Rc, &T)> + '_ {
+        self.positions().zip(self.data.iter())
+    }
+
+    /// Every value in row-major order.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// First position, in row-major order, where the predicate is true.
+    pub fn find<F: Fn(&T) -> bool>(&self, pred: F) -> Option<Rc> {
+        self.iter().find(|(_, x)| pred(x)).map(|(rc, _)| rc)
+    }
+
+    /// All positions where the predicate is true.
+    pub fn find_all<'a, F: Fn(&T) -> bool + 'a>(&'a self, pred: F)
+        -> impl Iterator<Item = Rc> + 'a
+    {
+        self.iter().filter(move |(_, x)| pred(x)).map(|(rc, _)| rc)
+    }
+
+    /// Apply a function to every cell, creating a new grid.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { rows: self.rows, cols: self.cols, data: self.data.iter().map(f).collect() }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// First position, in row-major order, with the given value.
+    pub fn position(&self, val: &T) -> Option<Rc> {
+        self.find(|x| x == val)
+    }
+}
+
+impl<T> Index<Rc> for Grid<T> {
+    type Output = T;
+    fn index(&self, rc: Rc) -> &T {
+        assert!(self.contains(rc), "Grid index out of bounds: {:?}", rc);
+        &self.data[rc.0 * self.cols + rc.1]
+    }
+}
+
+impl<T> IndexMut<Rc> for Grid<T> {
+    fn index_mut(&mut self, rc: Rc) -> &mut T {
+        assert!(self.contains(rc), "Grid index out of bounds: {:?}", rc);
+        &mut self.data[rc.0 * self.cols + rc.1]
+    }
+}
+
+/// Render each cell in turn, with one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in 0..self.rows {
+            for x in self.row(r) {write!(f, "{}", x)?;}
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Lines of a grid, without line endings or blank lines before and after.
+pub(crate) fn grid_lines(input: &str) -> Vec<&str> {
+    let lines: Vec<&str> = input.lines().map(|line| line.trim_end_matches('\r')).collect();
+    let first = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let last = lines.iter().rposition(|line| !line.is_empty()).map_or(first, |n| n + 1);
+    lines[first..last].to_vec()
+}
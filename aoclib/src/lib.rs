@@ -0,0 +1,25 @@
+//! Shared library for Advent of Code solutions, across all years.
+//! Copyright 2025 by Alex Utter
+//!
+//! Every day implements the `Solution` trait, so that solutions from any year
+//! can be run, checked, and timed the same way (e.g., by the "aoc" runner).
+//!
+//! Other modules hold data structures and algorithms shared between days.
+
+mod bench;
+pub mod bits;
+pub mod cycle;
+pub mod geom;
+pub mod grid;
+pub mod memo;
+pub mod numtheory;
+pub mod parse;
+pub mod polygon;
+pub mod range;
+pub mod search;
+mod solution;
+mod verify;
+pub use bench::{change, BenchConfig, BenchReport, History, HistoryRow, Timing, STEPS};
+pub use grid::Grid;
+pub use solution::{main, print, print_answer, Answers, Entry, Parsed, Solution, Source};
+pub use verify::{check, AnswerKey, Check, DayKey};
@@ -0,0 +1,149 @@
+//! Memoization for recursive solvers.
+//! Copyright 2025 by Alex Utter
+//!
+//! `Memo::get_or_compute` passes the memo itself back to the closure, so a
+//! recursive function can look up its own subproblems:
+//!
+//!   `memo.get_or_compute(n, |memo, &n| fib(memo, n-1) + fib(memo, n-2))`
+//!
+//! A bounded memo evicts the least-recently-used entry when it is full,
+//! so its memory use never exceeds its capacity, however it is used.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Placeholder for a missing link in the usage list.
+const NONE: usize = usize::MAX;
+
+/// A cache of previously computed values.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    map: HashMap<K, usize>,     // Index into "slots"
+    slots: Vec<Slot<K, V>>,
+    head: usize,                // Most recently used slot
+    tail: usize,                // Least recently used slot
+    capacity: Option<usize>,
+    hits: usize,
+    misses: usize,
+}
+
+// One stored value, linked in order of use if the memo is bounded.
+#[derive(Clone, Debug)]
+struct Slot<K, V> {
+    key: K,
+    value: V,
+    prev: usize,
+    next: usize,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    /// An unbounded memo, which keeps every value.
+    pub fn new() -> Self {
+        Memo {
+            map: HashMap::new(),
+            slots: Vec::new(),
+            head: NONE,
+            tail: NONE,
+            capacity: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// A memo that keeps at most "capacity" of the most recently used values.
+    pub fn bounded(capacity: usize) -> Self {
+        assert!(capacity > 0, "Memo capacity must be positive");
+        Memo { capacity: Some(capacity), ..Self::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Number of lookups that found a cached value.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups that had to compute a new value.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Forget every value, but keep the statistics.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.slots.clear();
+        (self.head, self.tail) = (NONE, NONE);
+    }
+
+    /// Look up a value without counting a hit or miss.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&n| &self.slots[n].value)
+    }
+
+    /// Store a value, e.g., to seed the base case of a recursion.
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(&n) = self.map.get(&key) {
+            self.slots[n].value = value;
+            self.touch(n);
+        } else if self.capacity.is_some_and(|c| self.slots.len() >= c) {
+            // Full, so reuse the least recently used slot.
+            let n = self.tail;
+            let old = std::mem::replace(&mut self.slots[n].key, key.clone());
+            self.map.remove(&old);
+            self.slots[n].value = value;
+            self.map.insert(key, n);
+            self.touch(n);
+        } else {
+            let n = self.slots.len();
+            self.slots.push(Slot { key: key.clone(), value, prev: NONE, next: NONE });
+            self.map.insert(key, n);
+            self.link(n);
+        }
+    }
+
+    /// Return the cached value for a key, or compute and store it.  The
+    /// closure may call back into the memo to solve subproblems.
+    pub fn get_or_compute<F>(&mut self, key: K, f: F) -> V
+        where F: FnOnce(&mut Self, &K) -> V
+    {
+        if let Some(&n) = self.map.get(&key) {
+            self.hits += 1;
+            self.touch(n);
+            return self.slots[n].value.clone();
+        }
+        self.misses += 1;
+        let value = f(self, &key);
+        self.insert(key, value.clone());
+        value
+    }
+
+    // Mark a slot as the most recently used.
+    fn touch(&mut self, n: usize) {
+        if self.capacity.is_none() || self.head == n {return;}
+        let Slot { prev, next, .. } = self.slots[n];
+        if prev != NONE {self.slots[prev].next = next;}
+        if next != NONE {self.slots[next].prev = prev;} else {self.tail = prev;}
+        self.link(n);
+    }
+
+    // Add an unlinked slot at the head of the usage list.
+    fn link(&mut self, n: usize) {
+        if self.capacity.is_none() {return;}
+        self.slots[n].prev = NONE;
+        self.slots[n].next = self.head;
+        if self.head != NONE {self.slots[self.head].prev = n;} else {self.tail = n;}
+        self.head = n;
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,147 @@
+//! Number theory: GCD, modular arithmetic, CRT, discrete logs, and primes.
+//! Copyright 2025 by Alex Utter
+//!
+//! Modular arithmetic uses i64 values, with i128 intermediates so that
+//! products never overflow.  Results are always in the range [0, m).
+
+/// Greatest common divisor, always non-negative.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {(a, b) = (b, a % b);}
+    a
+}
+
+/// Least common multiple, always non-negative.
+/// Panics if the result is too large for an i64.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {return 0;}
+    (a / gcd(a, b)).checked_mul(b).and_then(i64::checked_abs)
+        .expect("lcm overflow")
+}
+
+/// Least common multiple of every item, e.g., to find when several cycles
+/// line up.  Returns one for an empty list.  Panics on overflow, as "lcm".
+pub fn lcm_all<I: IntoIterator<Item = i64>>(items: I) -> i64 {
+    items.into_iter().fold(1, lcm)
+}
+
+/// Extended Euclidean algorithm: (g, x, y) such that a*x + b*y = g = gcd(a, b).
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = egcd128(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+// As "egcd", but with 128-bit inputs and outputs.
+fn egcd128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1i128, 0i128);
+    let (mut y0, mut y1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {(r0, x0, y0) = (-r0, -x0, -y0);}
+    (r0, x0, y0)
+}
+
+/// Reduce a value to the range [0, m).
+pub fn modulo(a: i64, m: i64) -> i64 {
+    a.rem_euclid(m)
+}
+
+/// Modular multiplication, safe for any 64-bit modulus.
+pub fn mul_mod(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Modular exponentiation by repeated squaring.
+pub fn pow_mod(base: i64, mut exp: u64, m: i64) -> i64 {
+    let mut base = modulo(base, m);
+    let mut result = modulo(1, m);
+    while exp > 0 {
+        if exp & 1 == 1 {result = mul_mod(result, base, m);}
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Modular inverse, if it exists (i.e., if a and m are coprime).
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(modulo(a, m), m);
+    if g == 1 {Some(modulo(x, m))} else {None}
+}
+
+/// Chinese remainder theorem, for a list of (residue, modulus) pairs.
+/// Moduli must be positive, but need not be coprime.  Returns (x, lcm) such that x satisfies
+/// every congruence, 0 <= x < lcm, or None if there's no solution, any
+/// modulus is zero or negative, or the lcm is too large for an i64.
+pub fn crt(pairs: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x = 0i128;
+    let mut m = 1i128;
+    for &(r, n) in pairs {
+        // Solve x + m*k = r (mod n), for the smallest k >= 0.
+        if n <= 0 {return None;}
+        let n = n as i128;
+        let (g, p, _) = egcd128(m, n);
+        let diff = r as i128 - x;
+        if diff % g != 0 {return None;}
+        let step = n / g;
+        let k = ((diff / g) % step * p).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        if m > i64::MAX as i128 {return None;}
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+/// Discrete logarithm by baby-step giant-step: the smallest x >= 0 such that
+/// base^x = target (mod m).  Requires base and m to be coprime.
+pub fn discrete_log(base: i64, target: i64, m: i64) -> Option<u64> {
+    let target = modulo(target, m);
+    let n = (m as f64).sqrt().ceil() as i64 + 1;
+    // Baby steps: base^j for each j < n, keeping the smallest j.
+    let mut table = std::collections::HashMap::new();
+    let mut val = modulo(1, m);
+    for j in 0..n {
+        table.entry(val).or_insert(j);
+        val = mul_mod(val, base, m);
+    }
+    // Giant steps: target * base^(-n*i) for each i <= n.
+    let factor = mod_inv(pow_mod(base, n as u64, m), m)?;
+    let mut val = target;
+    for i in 0..=n {
+        if let Some(j) = table.get(&val) {return Some((i * n + j) as u64);}
+        val = mul_mod(val, factor, m);
+    }
+    None
+}
+
+/// Every prime number up to and including n, by the sieve of Eratosthenes.
+pub fn primes(n: usize) -> Vec<usize> {
+    let mut sieve = vec![true; n + 1];
+    let mut result = Vec::new();
+    for p in 2..=n {
+        if !sieve[p] {continue;}
+        result.push(p);
+        for k in (p * p..=n).step_by(p) {sieve[k] = false;}
+    }
+    result
+}
+
+/// Prime factorization, as a list of (prime, exponent) in ascending order.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut result = Vec::new();
+    let mut p = 2u64;
+    while p <= n / p {
+        let mut count = 0u32;
+        while n.is_multiple_of(p) {n /= p; count += 1;}
+        if count > 0 {result.push((p, count));}
+        p += if p == 2 {1} else {2};
+    }
+    if n > 1 {result.push((n, 1));}
+    result
+}
@@ -0,0 +1,199 @@
+//! Helpers for reading puzzle input, with errors that say where it broke.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each `ParseError` notes a line and column, counting from one, relative
+//! to the string that was being parsed.  Use `lines` to parse an input one
+//! line at a time while keeping track of line numbers.  Line zero means
+//! the location is unknown.
+
+use crate::grid::{grid_lines, Grid};
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsing failure and its location.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub msg: String,
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+impl ParseError {
+    /// An error at the start of "at", which should be a substring of "input".
+    /// Otherwise, the location is unknown.
+    pub fn new(input: &str, at: &str, msg: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        let inside = offset <= input.len() && at.len() <= input.len() - offset;
+        let Some(before) = input.get(..offset).filter(|_| inside) else {
+            return ParseError { line: 0, col: 0, msg: msg.into() };
+        };
+        let line = before.matches('\n').count() + 1;
+        let col = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        ParseError { line, col, msg: msg.into() }
+    }
+
+    /// Given an error relative to "part", a substring of "input", find its
+    /// location in "input" instead.
+    pub fn within(mut self, input: &str, part: &str) -> Self {
+        let base = ParseError::new(input, part, "");
+        if self.line == 0 || base.line == 0 {
+            return ParseError { line: 0, col: 0, msg: self.msg };
+        }
+        if self.line == 1 {self.col += base.col - 1;}
+        self.line += base.line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {return write!(f, "{}", self.msg);}
+        write!(f, "Line {}, column {}: {}", self.line, self.col, self.msg)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse "s", which is a substring of "input", reporting its location in
+/// "input" if it fails.
+pub fn field<T: FromStr>(input: &str, s: &str) -> Result<T> {
+    s.parse().map_err(|_| ParseError::new(input, s,
+        format!("Expected {}, found \"{}\"", std::any::type_name::<T>(), s)))
+}
+
+/// Every integer in a string, e.g., "x=-3, y=5..8" gives [-3, 5, 8].
+/// A minus sign counts only if it does not follow a digit, so "5-8" is a
+/// range rather than a subtraction.  Negative numbers are an error if the
+/// requested type is unsigned.
+pub fn ints<T: FromStr>(s: &str) -> Result<Vec<T>> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0usize;
+    while i < bytes.len() {
+        let start = i;
+        let neg = bytes[i] == b'-'
+            && (i == 0 || !bytes[i-1].is_ascii_digit())
+            && bytes.get(i+1).is_some_and(u8::is_ascii_digit);
+        if neg || bytes[i].is_ascii_digit() {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {i += 1;}
+            result.push(field(s, &s[start..i])?);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Match a string against a pattern, where each "{}" is a field.  Each
+/// field runs until the text that follows it in the pattern.  Returns the
+/// text of each field.  See also the `scan!` macro.
+pub fn scan_fields<'a>(input: &'a str, pattern: &str) -> Result<Vec<&'a str>> {
+    let expected = |at: &str, lit: &str| ParseError::new(input, at, format!("Expected \"{}\"", lit));
+    let mut parts = pattern.split("{}");
+    let first = parts.next().unwrap_or("");
+    let mut rest = input.strip_prefix(first).ok_or_else(|| {
+        // Point to the first character that doesn't match.
+        let n: usize = input.chars().zip(first.chars()).take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8()).sum();
+        expected(&input[n..], first)
+    })?;
+    let parts: Vec<&str> = parts.collect();
+    let mut fields = Vec::new();
+    for (n, lit) in parts.iter().enumerate() {
+        let end = if lit.is_empty() {
+            assert!(n + 1 == parts.len(), "Adjacent fields in pattern");
+            rest.len()
+        } else {
+            rest.find(lit).ok_or_else(|| expected(rest, lit))?
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + lit.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::new(input, rest, format!("Unexpected \"{}\"", rest)));
+    }
+    Ok(fields)
+}
+
+/// Match a string against a pattern, and parse each "{}" as the matching
+/// type.  Returns a tuple with one value per field, e.g.,
+/// `scan!(line, "Button A: X+{}, Y+{}" => i64, i64)` gives `Ok((94, 34))`
+/// for the line "Button A: X+94, Y+34".
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:expr => $($t:ty),+ $(,)?) => {{
+        let input: &str = $input;
+        $crate::parse::scan_fields(input, $pattern).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            let result = ($($crate::parse::field::<$t>(
+                input, fields.next().expect("Too few fields in pattern"))?,)+);
+            assert!(fields.next().is_none(), "Too many fields in pattern");
+            Ok(result)
+        })
+    }};
+}
+
+/// Parse each non-blank line, after trimming leading and trailing
+/// whitespace.  Errors from "f" are relative to the line it was given, and
+/// are adjusted to their location in the full input.
+pub fn lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>>
+    where F: FnMut(&str) -> Result<T>
+{
+    let mut result = Vec::new();
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() {continue;}
+        result.push(f(line).map_err(|e| e.within(input, line))?);
+    }
+    Ok(result)
+}
+
+/// Split the input into blocks separated by blank lines.  Each block is a
+/// substring of the input, so `ParseError::new` can still locate errors
+/// relative to the full input.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0usize;
+    for line in input.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        if line.trim().is_empty() {
+            if let Some((s, e)) = block.take() {result.push(&input[s..e]);}
+        } else {
+            let end = start + line.trim_end().len();
+            block = Some((block.map_or(start, |b| b.0), end));
+        }
+    }
+    if let Some((s, e)) = block {result.push(&input[s..e]);}
+    result
+}
+
+/// Read a rectangular grid with one character per cell, converting each
+/// with "f".  Blank lines before and after the grid are ignored, but
+/// spaces are cells like any other.
+/// Reports any character that "f" rejects, or a line with the wrong length.
+pub fn grid<T, F>(input: &str, mut f: F) -> Result<Grid<T>>
+    where F: FnMut(char) -> Option<T>
+{
+    let mut rows = 0usize;
+    let mut cols = None;
+    let mut data = Vec::new();
+    for line in grid_lines(input) {
+        for (n, ch) in line.char_indices() {
+            let value = f(ch).ok_or_else(||
+                ParseError::new(input, &line[n..], format!("Unexpected '{}'", ch)))?;
+            data.push(value);
+        }
+        let len = line.chars().count();
+        let expect = *cols.get_or_insert(len);
+        if len != expect {
+            return Err(ParseError::new(input, line,
+                format!("Expected {} columns, found {}", expect, len)));
+        }
+        rows += 1;
+    }
+    Ok(Grid::from_vec(rows, cols.unwrap_or(0), data))
+}
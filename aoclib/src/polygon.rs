@@ -0,0 +1,130 @@
+//! Simple polygons with integer vertices.
+//! Copyright 2025 by Alex Utter
+//!
+//! Vertices are listed in order around the boundary, in either direction,
+//! with an implied edge from the last vertex back to the first.  Every query
+//! treats the polygon as a closed region, so points on the boundary are
+//! inside.  The boundary must not cross itself.
+
+use crate::geom::Vec2;
+use crate::numtheory::gcd;
+use std::cmp::{max, min};
+
+pub type Point = Vec2<i64>;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Polygon {
+    pts: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(pts: Vec<Point>) -> Self {
+        Polygon { pts }
+    }
+
+    /// Trace a closed path starting from the origin, given each step
+    /// as a vector.  The path must end where it began.
+    pub fn from_steps<I: IntoIterator<Item = Point>>(steps: I) -> Self {
+        let mut posn = Point::default();
+        let mut pts = Vec::new();
+        for step in steps {
+            pts.push(posn);
+            posn += step;
+        }
+        assert_eq!(posn, Point::default(), "Path does not close");
+        Polygon { pts }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.pts
+    }
+
+    /// Each edge as a pair of endpoints.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let next = self.pts.iter().cycle().skip(1);
+        self.pts.iter().zip(next).map(|(a, b)| (*a, *b))
+    }
+
+    /// Are all edges horizontal or vertical?
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|(a, b)| a.x == b.x || a.y == b.y)
+    }
+
+    /// Twice the signed area, using the shoelace formula.  Positive if the
+    /// vertices are counter-clockwise, with +x right and +y up.
+    pub fn signed_area2(&self) -> i64 {
+        self.edges().map(|(a, b)| a.cross(&b)).sum()
+    }
+
+    /// Twice the enclosed area, which is always an integer.
+    pub fn area2(&self) -> i64 {
+        self.signed_area2().abs()
+    }
+
+    /// Number of integer points on the boundary.
+    pub fn boundary(&self) -> i64 {
+        self.edges().map(|(a, b)| gcd(b.x - a.x, b.y - a.y)).sum()
+    }
+
+    /// Number of integer points strictly inside, using Pick's theorem.
+    pub fn interior(&self) -> i64 {
+        (self.area2() - self.boundary() + 2) / 2
+    }
+
+    /// Number of integer points inside or on the boundary.
+    pub fn lattice_points(&self) -> i64 {
+        self.interior() + self.boundary()
+    }
+
+    /// Is the given point inside the polygon or on its boundary?
+    pub fn contains(&self, p: Point) -> bool {
+        self.locate(p, 1)
+    }
+
+    // Point-in-polygon test, with the polygon scaled up by the given factor.
+    // Scaling allows tests at half-integer coordinates.
+    fn locate(&self, p: Point, scale: i64) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (a, b) = (a * scale, b * scale);
+            // Any point on the boundary counts as inside.
+            let side = (b - a).cross(&(p - a));
+            if side == 0 && min(a.x, b.x) <= p.x && p.x <= max(a.x, b.x)
+                && min(a.y, b.y) <= p.y && p.y <= max(a.y, b.y) {return true;}
+            // Otherwise, count edges crossing the ray from p toward +x.
+            if (a.y > p.y) != (b.y > p.y) {
+                let right = if a.x == b.x {a.x > p.x} else {(side > 0) == (b.y > a.y)};
+                if right {inside = !inside;}
+            }
+        }
+        inside
+    }
+
+    /// Is the rectangle with opposite corners "a" and "b" entirely inside
+    /// the polygon?  The polygon must be rectilinear.
+    pub fn contains_rect(&self, a: Point, b: Point) -> bool {
+        assert!(self.is_rectilinear(), "Polygon is not rectilinear");
+        let (x0, x1) = (min(a.x, b.x), max(a.x, b.x));
+        let (y0, y1) = (min(a.y, b.y), max(a.y, b.y));
+        if x0 == x1 || y0 == y1 {
+            // Degenerate rectangle: membership along the line segment only
+            // changes at vertex coordinates, so test each of those points
+            // and the midpoints between them.
+            let mut cuts: Vec<Point> = self.pts.iter()
+                .map(|p| Point::new(p.x.clamp(x0, x1), p.y.clamp(y0, y1)))
+                .chain([Point::new(x0, y0), Point::new(x1, y1)])
+                .collect();
+            cuts.sort();
+            cuts.dedup();
+            return cuts.iter().all(|p| self.contains(*p))
+                && cuts.windows(2).all(|w| self.locate(w[0] + w[1], 2));
+        }
+        // Otherwise, no edge may pass through the open interior, and then
+        // testing the center is enough.
+        let crossed = self.edges().any(|(p, q)| {
+            x0 < max(p.x, q.x) && min(p.x, q.x) < x1
+                && y0 < max(p.y, q.y) && min(p.y, q.y) < y1
+        });
+        !crossed && self.locate(Point::new(x0 + x1, y0 + y1), 2)
+    }
+}
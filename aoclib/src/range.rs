@@ -0,0 +1,285 @@
+//! Sets of intervals, piecewise mappings, and N-dimensional cuboids.
+//! Copyright 2025 by Alex Utter
+//!
+//! Every interval is half-open, like `std::ops::Range`: it includes the
+//! start but not the end.  Inputs with inclusive bounds (e.g., "5-8")
+//! should add one to the upper bound.
+
+use std::cmp::{max, min};
+use std::ops::{Add, Range, Sub};
+
+/// A set of values, stored as a sorted list of disjoint intervals.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    // Sort and merge a list of intervals, discarding any that are empty.
+    fn normalize(mut list: Vec<Range<T>>) -> Self {
+        list.retain(|r| r.start < r.end);
+        list.sort_by_key(|r| r.start);
+        let mut ranges: Vec<Range<T>> = Vec::new();
+        for r in list {
+            match ranges.last_mut() {
+                Some(last) if r.start <= last.end => last.end = max(last.end, r.end),
+                _ => ranges.push(r),
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Sorted list of disjoint, non-adjacent intervals.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Is the given value in this set?
+    pub fn contains(&self, x: T) -> bool {
+        // Find the last interval starting at or before x.
+        let idx = self.ranges.partition_point(|r| r.start <= x);
+        idx > 0 && x < self.ranges[idx - 1].end
+    }
+
+    /// Smallest value in this set, if any.
+    pub fn first(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Add an interval to this set.
+    pub fn insert(&mut self, r: Range<T>) {
+        let mut list = std::mem::take(&mut self.ranges);
+        list.push(r);
+        *self = Self::normalize(list);
+    }
+
+    /// Remove an interval from this set.
+    pub fn remove(&mut self, r: Range<T>) {
+        *self = self.difference(&RangeSet::from(r));
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.ranges.iter().chain(other.ranges.iter()).cloned().collect())
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while i < a.len() && j < b.len() {
+            let lo = max(a[i].start, b[j].start);
+            let hi = min(a[i].end, b[j].end);
+            if lo < hi {ranges.push(lo..hi);}
+            if a[i].end < b[j].end {i += 1;} else {j += 1;}
+        }
+        RangeSet { ranges }
+    }
+
+    /// Values in this set but not the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0usize;
+        for r in self.ranges.iter() {
+            // Skip intervals in the other set that end before this one.
+            while j < other.ranges.len() && other.ranges[j].end <= r.start {j += 1;}
+            let mut lo = r.start;
+            for cut in other.ranges[j..].iter() {
+                if cut.start >= r.end {break;}
+                if cut.start > lo {ranges.push(lo..cut.start);}
+                lo = max(lo, cut.end);
+            }
+            if lo < r.end {ranges.push(lo..r.end);}
+        }
+        RangeSet { ranges }
+    }
+
+    /// Values within the given bounds that are not in this set.
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        RangeSet::from(bounds).difference(self)
+    }
+}
+
+impl<T> RangeSet<T>
+    where T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>
+{
+    /// Total number of values in this set.
+    pub fn size(&self) -> T {
+        self.ranges.iter().fold(T::default(), |acc, r| acc + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(r: Range<T>) -> Self {
+        Self::normalize(vec![r])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+/// A piecewise-linear mapping, where each source interval is shifted to a
+/// new starting point.  Values outside every source interval are unchanged.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RangeMap<T> {
+    // Source interval and destination start, sorted by source.
+    segments: Vec<(Range<T>, T)>,
+}
+
+impl<T> RangeMap<T>
+    where T: Copy + Ord + Add<Output = T> + Sub<Output = T>
+{
+    pub fn new() -> Self {
+        RangeMap { segments: Vec::new() }
+    }
+
+    /// Map the source interval so it starts at "dst".  Source intervals
+    /// must not overlap.
+    pub fn insert(&mut self, src: Range<T>, dst: T) {
+        let idx = self.segments.partition_point(|s| s.0.start < src.start);
+        assert!(idx == 0 || self.segments[idx - 1].0.end <= src.start, "Overlapping segment");
+        assert!(idx == self.segments.len() || src.end <= self.segments[idx].0.start, "Overlapping segment");
+        self.segments.insert(idx, (src, dst));
+    }
+
+    /// Map a single value.
+    pub fn get(&self, x: T) -> T {
+        let idx = self.segments.partition_point(|s| s.0.start <= x);
+        match self.segments.get(idx.wrapping_sub(1)) {
+            Some((src, dst)) if x < src.end => *dst + (x - src.start),
+            _ => x,
+        }
+    }
+
+    /// Map every value in a set, splitting intervals as needed.
+    pub fn apply(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut list = Vec::new();
+        let mut rest = set.clone();
+        for (src, dst) in self.segments.iter() {
+            let part = set.intersection(&RangeSet::from(src.clone()));
+            for r in part.ranges() {
+                list.push(*dst + (r.start - src.start) .. *dst + (r.end - src.start));
+            }
+            rest = rest.difference(&part);
+        }
+        list.extend(rest.ranges);
+        RangeSet::normalize(list)
+    }
+}
+
+/// An axis-aligned box in N dimensions, including the lower corner but not
+/// the upper corner.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cuboid<const N: usize> {
+    pub lo: [i64; N],
+    pub hi: [i64; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(lo: [i64; N], hi: [i64; N]) -> Self {
+        Cuboid { lo, hi }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|n| self.lo[n] >= self.hi[n])
+    }
+
+    /// Number of integer points inside this box.
+    pub fn volume(&self) -> u64 {
+        if self.is_empty() {return 0;}
+        (0..N).map(|n| (self.hi[n] - self.lo[n]) as u64).product()
+    }
+
+    pub fn contains(&self, p: &[i64; N]) -> bool {
+        (0..N).all(|n| self.lo[n] <= p[n] && p[n] < self.hi[n])
+    }
+
+    /// Overlap between two boxes, if any.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let lo = std::array::from_fn(|n| max(self.lo[n], other.lo[n]));
+        let hi = std::array::from_fn(|n| min(self.hi[n], other.hi[n]));
+        let tmp = Cuboid { lo, hi };
+        if tmp.is_empty() {None} else {Some(tmp)}
+    }
+
+    /// Split this box into disjoint pieces that exclude the other box.
+    /// Returns at most 2*N pieces.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let cut = match self.intersect(other) {
+            Some(cut) => cut,
+            None => return vec![*self],
+        };
+        // Slice off the parts below and above the cut on each axis in turn.
+        let mut result = Vec::new();
+        let mut rest = *self;
+        for n in 0..N {
+            if rest.lo[n] < cut.lo[n] {
+                let mut below = rest;
+                below.hi[n] = cut.lo[n];
+                result.push(below);
+            }
+            if cut.hi[n] < rest.hi[n] {
+                let mut above = rest;
+                above.lo[n] = cut.hi[n];
+                result.push(above);
+            }
+            rest.lo[n] = cut.lo[n];
+            rest.hi[n] = cut.hi[n];
+        }
+        result
+    }
+}
+
+/// A set of points, stored as a list of disjoint boxes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CuboidSet<const N: usize> {
+    boxes: Vec<Cuboid<N>>,
+}
+
+impl<const N: usize> CuboidSet<N> {
+    pub fn new() -> Self {
+        CuboidSet { boxes: Vec::new() }
+    }
+
+    /// List of disjoint boxes making up this set, in no particular order.
+    pub fn boxes(&self) -> &[Cuboid<N>] {
+        &self.boxes
+    }
+
+    pub fn contains(&self, p: &[i64; N]) -> bool {
+        self.boxes.iter().any(|b| b.contains(p))
+    }
+
+    /// Add every point in the given box.
+    pub fn insert(&mut self, c: Cuboid<N>) {
+        self.remove(&c);
+        if !c.is_empty() {self.boxes.push(c);}
+    }
+
+    /// Remove every point in the given box.
+    pub fn remove(&mut self, c: &Cuboid<N>) {
+        self.boxes = self.boxes.iter().flat_map(|b| b.subtract(c)).collect();
+    }
+
+    /// Points in this set that are also inside the given box.
+    pub fn intersection(&self, c: &Cuboid<N>) -> Self {
+        CuboidSet { boxes: self.boxes.iter().filter_map(|b| b.intersect(c)).collect() }
+    }
+
+    /// Total number of integer points in this set.
+    pub fn volume(&self) -> u64 {
+        self.boxes.iter().map(Cuboid::volume).sum()
+    }
+}
@@ -0,0 +1,221 @@
+//! Shortest-path searches over any state type: BFS, Dijkstra, and A*.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each search takes a starting state, a function listing the neighbors of
+//! a given state, and a function that recognizes the goal.  The result
+//! keeps the best cost to every visited state, and every predecessor that
+//! ties for that cost, so it can reconstruct one shortest path or find all
+//! states that lie on any shortest path.
+//!
+//! Searches stop once every goal state at the best cost has been reached.
+//! If the goal is never reached, the search explores every reachable state.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Best cost and predecessors for every state visited by a search.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S, C> {
+    costs: HashMap<S, C>,
+    prevs: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+    expanded: usize,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        SearchResult {
+            costs: HashMap::from([(start, zero)]),
+            prevs: HashMap::new(),
+            goals: Vec::new(),
+            expanded: 0,
+        }
+    }
+
+    // Record a new route to the next state, returning true if it's better
+    // than any previous route.  Ties add another predecessor.
+    fn relax(&mut self, prev: &S, next: S, cost: C) -> bool where C: Ord {
+        match self.costs.get(&next).map(|old| cost.cmp(old)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => {
+                let list = self.prevs.entry(next).or_default();
+                if !list.contains(prev) {list.push(prev.clone());}
+                false
+            },
+            _ => {
+                self.costs.insert(next.clone(), cost);
+                self.prevs.insert(next, vec![prev.clone()]);
+                true
+            },
+        }
+    }
+
+    /// Best cost to reach a given state, if it was visited.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// The first goal state reached, if any.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Cost to reach the goal, if it was reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal().and_then(|s| self.cost(s))
+    }
+
+    /// Every goal state reached at the best cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Every predecessor of a given state on a shortest path to that state.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.prevs.get(state).map_or(&[], |v| v.as_slice())
+    }
+
+    /// One shortest path from the start to the given state, inclusive.
+    pub fn path(&self, end: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(end) {return None;}
+        let mut path = vec![end.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            // Zero-cost cycles could otherwise loop forever.
+            if path.len() > self.costs.len() {return None;}
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any shortest path from the start to any goal.
+    pub fn on_best_paths(&self) -> HashSet<S> {
+        self.on_paths_to(&self.goals)
+    }
+
+    /// Every state on any shortest path from the start to any given state.
+    pub fn on_paths_to(&self, ends: &[S]) -> HashSet<S> {
+        let mut queue: Vec<S> = ends.iter()
+            .filter(|s| self.costs.contains_key(s)).cloned().collect();
+        let mut found: HashSet<S> = queue.iter().cloned().collect();
+        while let Some(state) = queue.pop() {
+            for prev in self.predecessors(&state) {
+                if found.insert(prev.clone()) {queue.push(prev.clone());}
+            }
+        }
+        found
+    }
+
+    /// Number of distinct states reached by the search.
+    pub fn visited(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// Number of states whose neighbors were explored.
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+}
+
+/// Breadth-first search, where every step has a cost of one.
+pub fn bfs<S, I, N, G>(start: S, mut neighbors: N, mut goal: G) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(0usize, start)]);
+    let mut best = usize::MAX;
+    while let Some((cost, state)) = queue.pop_front() {
+        if cost > best {break;}
+        if goal(&state) {best = cost; result.goals.push(state.clone());}
+        if cost == best {continue;}
+        result.expanded += 1;
+        for next in neighbors(&state) {
+            if result.relax(&state, next.clone(), cost + 1) {
+                queue.push_back((cost + 1, next));
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's algorithm, where each neighbor has a non-negative step cost.
+pub fn dijkstra<S, C, I, N, G>(start: S, neighbors: N, goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), goal)
+}
+
+/// A* search, guided by a heuristic estimate of the remaining cost.
+/// The heuristic must never overestimate, and must be consistent (i.e.,
+/// never decrease by more than the cost of any step), or results may not
+/// be optimal.  The cost type's default value is taken as zero.
+pub fn astar<S, C, I, N, H, G>(start: S, mut neighbors: N, mut heuristic: H, mut goal: G)
+    -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let init = Item {pri: heuristic(&start), cost: C::default(), state: start};
+    let mut queue = BinaryHeap::from([init]);
+    let mut best: Option<C> = None;
+    while let Some(Item {pri, cost, state}) = queue.pop() {
+        if best.is_some_and(|b| pri > b) {break;}
+        if result.cost(&state).is_some_and(|c| cost > c) {continue;}
+        if goal(&state) {
+            if *best.get_or_insert(cost) == cost {result.goals.push(state.clone());}
+            continue;
+        }
+        result.expanded += 1;
+        for (next, step) in neighbors(&state) {
+            let new_cost = cost + step;
+            if result.relax(&state, next.clone(), new_cost) {
+                let pri = new_cost + heuristic(&next);
+                queue.push(Item {pri, cost: new_cost, state: next});
+            }
+        }
+    }
+    result
+}
+
+// Priority queue entry, ordered so the BinaryHeap pops the lowest priority.
+struct Item<S, C> {
+    pri: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Item<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.pri.cmp(&self.pri)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Item<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Item<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pri == other.pri
+    }
+}
+
+impl<S, C: Ord> Eq for Item<S, C> {}
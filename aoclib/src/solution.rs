@@ -0,0 +1,177 @@
+//! Uniform interface for each day's solution, and a type-erased registry entry.
+//! Copyright 2025 by Alex Utter
+
+use aocfetch::FetchError;
+use std::any::Any;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// A solution for one day's puzzle.
+///
+/// The input is parsed once, then shared by both parts.  Answers can be any
+/// printable type.  Puzzles with only one part (e.g., Day 25) can leave out
+/// part 2, which returns an empty answer by default.
+pub trait Solution {
+    type Input;
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(_input: &Self::Input) -> impl Display { "" }
+}
+
+/// Parsed input, as returned by `Entry::parse`.
+pub struct Parsed(Box<dyn Any>);
+
+/// Where to find the input for a given puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    /// Fetch from the server (or the local cache) using aocfetch.
+    Fetch,
+    /// Read from a local file.
+    File(PathBuf),
+    /// The input is part of the solution itself.
+    Embedded,
+}
+
+/// Answers and elapsed time for each step.
+#[derive(Clone, Debug)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+impl Answers {
+    /// Total time for all steps.
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+}
+
+/// One day's solution, with its type erased so every day fits in one list.
+#[derive(Clone)]
+pub struct Entry {
+    pub year: usize,
+    pub day: usize,
+    pub source: Source,
+    parse_fn: fn(&str) -> Parsed,
+    part1_fn: fn(&Parsed) -> String,
+    part2_fn: fn(&Parsed) -> String,
+}
+
+fn parse_as<S: Solution>(input: &str) -> Parsed
+    where S::Input: 'static
+{
+    Parsed(Box::new(S::parse(input)))
+}
+
+fn part1_as<S: Solution>(input: &Parsed) -> String
+    where S::Input: 'static
+{
+    S::part1(input.0.downcast_ref().expect("Input type mismatch")).to_string()
+}
+
+fn part2_as<S: Solution>(input: &Parsed) -> String
+    where S::Input: 'static
+{
+    S::part2(input.0.downcast_ref().expect("Input type mismatch")).to_string()
+}
+
+impl Entry {
+    /// Registry entry for the given solution, fetching input from the server.
+    pub fn new<S: Solution>(year: usize, day: usize) -> Entry
+        where S::Input: 'static
+    {
+        Entry {
+            year,
+            day,
+            source: Source::Fetch,
+            parse_fn: parse_as::<S>,
+            part1_fn: part1_as::<S>,
+            part2_fn: part2_as::<S>,
+        }
+    }
+
+    /// Read input from a local file instead of the server.
+    pub fn file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.source = Source::File(path.into());
+        self
+    }
+
+    /// Mark a solution that doesn't need any input.
+    pub fn embedded(mut self) -> Self {
+        self.source = Source::Embedded;
+        self
+    }
+
+    /// Load the puzzle input from the designated source.
+    pub fn input(&self) -> Result<String, FetchError> {
+        match &self.source {
+            Source::Fetch => aocfetch::get_data(self.year, self.day),
+            Source::File(path) => Ok(std::fs::read_to_string(path)?),
+            Source::Embedded => Ok(String::new()),
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Parsed {
+        (self.parse_fn)(input)
+    }
+
+    pub fn part1(&self, input: &Parsed) -> String {
+        (self.part1_fn)(input)
+    }
+
+    pub fn part2(&self, input: &Parsed) -> String {
+        (self.part2_fn)(input)
+    }
+
+    /// Solve both parts for the given input, timing each step.
+    pub fn solve(&self, input: &str) -> Answers {
+        let time = Instant::now();
+        let parsed = self.parse(input);
+        let parse_time = time.elapsed();
+        let time = Instant::now();
+        let part1 = self.part1(&parsed);
+        let part1_time = time.elapsed();
+        let time = Instant::now();
+        let part2 = self.part2(&parsed);
+        let part2_time = time.elapsed();
+        Answers { part1, part2, parse_time, part1_time, part2_time }
+    }
+
+    /// Load input from the designated source, then solve both parts.
+    pub fn run(&self) -> Result<Answers, FetchError> {
+        Ok(self.solve(&self.input()?))
+    }
+}
+
+/// Print one answer, skipping parts that don't exist.
+/// Multi-line answers (e.g., ASCII art) start on a new line.
+pub fn print_answer(part: usize, answer: &str)
+{
+    if answer.is_empty() {return;}
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer.trim_end());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+/// Solve the given input and print the results.
+pub fn print<S: Solution>(input: &str)
+    where S::Input: 'static
+{
+    let result = Entry::new::<S>(0, 0).solve(input);
+    print_answer(1, &result.part1);
+    print_answer(2, &result.part2);
+    println!("Elapsed time: {:.1?}", result.elapsed());
+}
+
+/// Entry point for single-day binaries: fetch input, then solve and print.
+pub fn main<S: Solution>(year: usize, day: usize)
+    where S::Input: 'static
+{
+    print::<S>(&aocfetch::get_data(year, day).unwrap());
+}
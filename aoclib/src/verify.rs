@@ -0,0 +1,132 @@
+//! Recorded answers for each year, for checking solutions against real inputs.
+//! Copyright 2025 by Alex Utter
+//!
+//! Each year keeps an "answers.toml" file, with one table per day:
+//!     [day01]
+//!     part1 = "123"
+//!     part2 = "456"
+
+use crate::solution::Answers;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Recorded answers for one day.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DayKey {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Outcome of comparing one computed answer to the recorded answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Check {
+    /// Matches the recorded answer.
+    Pass,
+    /// Doesn't match the recorded answer, shown here.
+    Fail(String),
+    /// No recorded answer yet.
+    New,
+    /// No answer and nothing recorded (e.g., Day 25 Part 2).
+    Skip,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass     => write!(f, "PASS"),
+            Check::Fail(_)  => write!(f, "FAIL"),
+            Check::New      => write!(f, "NEW"),
+            Check::Skip     => write!(f, "-"),
+        }
+    }
+}
+
+/// Compare a computed answer against the recorded one, if any.
+pub fn check(expected: Option<&str>, actual: &str) -> Check
+{
+    match expected {
+        Some(x) if x == actual => Check::Pass,
+        Some(x) => Check::Fail(x.to_string()),
+        None if actual.is_empty() => Check::Skip,
+        None => Check::New,
+    }
+}
+
+/// Recorded answers for every day in a given year.
+#[derive(Clone, Debug)]
+pub struct AnswerKey {
+    year: usize,
+    path: PathBuf,
+    days: BTreeMap<String, DayKey>,
+    changed: bool,
+}
+
+impl AnswerKey {
+    /// An empty set of answers, which will be saved to the given file.
+    pub fn new<P: Into<PathBuf>>(year: usize, path: P) -> AnswerKey {
+        AnswerKey { year, path: path.into(), days: BTreeMap::new(), changed: false }
+    }
+
+    /// Load answers from the given file.  A missing file is an error, so
+    /// that a misplaced file can't quietly disable every check.
+    pub fn load<P: Into<PathBuf>>(year: usize, path: P) -> Result<AnswerKey, String> {
+        let path = path.into();
+        let days = std::fs::read_to_string(&path)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+            .and_then(|text| toml::from_str(&text)
+                .map_err(|e| format!("{}: {}", path.display(), e)))?;
+        Ok(AnswerKey { year, path, days, changed: false })
+    }
+
+    pub fn year(&self) -> usize {
+        self.year
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Recorded answer for the given day and part, if any.
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        let key = self.days.get(&day_label(day))?;
+        let ans = if part == 1 {&key.part1} else {&key.part2};
+        ans.as_deref()
+    }
+
+    /// Compare both parts of a computed result to the recorded answers.
+    pub fn check(&self, day: usize, result: &Answers) -> [Check; 2] {
+        [check(self.get(day, 1), &result.part1),
+         check(self.get(day, 2), &result.part2)]
+    }
+
+    /// Record any answers that are new.  Existing answers are never replaced.
+    /// Returns true if anything changed.
+    pub fn record(&mut self, day: usize, result: &Answers) -> bool {
+        let key = self.days.entry(day_label(day)).or_default();
+        let mut changed = false;
+        for (ans, new) in [(&mut key.part1, &result.part1), (&mut key.part2, &result.part2)] {
+            if ans.is_none() && !new.is_empty() {
+                *ans = Some(new.clone());
+                changed = true;
+            }
+        }
+        self.changed |= changed;
+        changed
+    }
+
+    /// Write all answers back to the original file, if anything changed.
+    pub fn save(&self) -> Result<(), String> {
+        if !self.changed {return Ok(());}
+        let text = toml::to_string(&self.days).map_err(|e| e.to_string())?;
+        std::fs::write(&self.path, text)
+            .map_err(|e| format!("{}: {}", self.path.display(), e))
+    }
+}
+
+fn day_label(day: usize) -> String {
+    format!("day{:02}", day)
+}
@@ -0,0 +1,66 @@
+//! Test benchmark statistics and history.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::{BenchConfig, Entry, History, Solution, Timing};
+use std::time::Duration;
+
+struct Example;
+
+impl Solution for Example {
+    type Input = Vec<u64>;
+    fn parse(input: &str) -> Vec<u64> {
+        input.split(',').map(|x| x.trim().parse().unwrap()).collect()
+    }
+    fn part1(input: &Vec<u64>) -> impl std::fmt::Display {
+        input.iter().sum::<u64>()
+    }
+    fn part2(input: &Vec<u64>) -> impl std::fmt::Display {
+        input.iter().product::<u64>()
+    }
+}
+
+fn temp_file(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("aoclib-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("history.csv")
+}
+
+#[test]
+fn timing() {
+    let ms = Duration::from_millis;
+    assert_eq!(Timing::new(&[]), Timing::default());
+    assert_eq!(Timing::new(&[ms(5), ms(1), ms(3)]), Timing { min: ms(1), median: ms(3) });
+    assert_eq!(Timing::new(&[ms(4), ms(1), ms(2), ms(8)]), Timing { min: ms(1), median: ms(3) });
+    assert_eq!(aoclib::change(ms(4), ms(5)), "+25.0%");
+    assert_eq!(aoclib::change(ms(4), ms(3)), "-25.0%");
+    assert_eq!(aoclib::change(ms(0), ms(3)), "n/a");
+}
+
+#[test]
+fn bench_history() {
+    let path = temp_file("bench");
+    let entry = Entry::new::<Example>(2000, 1);
+    let report = entry.bench("1, 2, 3, 4", BenchConfig { warmup: 0, runs: 3 });
+    assert_eq!(report.runs, 3);
+    assert_eq!(report.answers.part1, "10");
+    assert_eq!(report.answers.part2, "24");
+    assert!(report.steps.iter().all(|t| t.min <= t.median));
+
+    // Append twice, then reload.
+    let mut history = History::load(&path).unwrap();
+    assert!(history.last(2000, 1, "part1").is_none());
+    history.append(&report).unwrap();
+    history.append(&report).unwrap();
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.rows().len(), 6);
+    let last = history.last(2000, 1, "part2").unwrap();
+    assert_eq!(last.runs, 3);
+    assert_eq!(last.timing.median.as_nanos(), report.steps[2].median.as_nanos());
+    assert!(history.last(2000, 2, "part2").is_none());
+
+    // Corrupt files are reported, not silently discarded.
+    std::fs::write(&path, "1,2,3\n").unwrap();
+    assert!(History::load(&path).is_err());
+}
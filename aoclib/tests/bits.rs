@@ -0,0 +1,88 @@
+//! Test the bit-set and bitmap types.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::bits::{BitGrid, BitSet};
+
+type Set = BitSet<8>;
+
+#[test]
+fn bitset() {
+    let mut a: Set = [1, 3, 5].into_iter().collect();
+    assert_eq!(a.bits(), 0b101010);
+    assert_eq!(a.len(), 3);
+    assert!(a.contains(3) && !a.contains(4) && !a.contains(99));
+    assert!(a.insert(4));
+    assert!(!a.insert(4));
+    assert!(a.remove(1));
+    assert!(!a.remove(1));
+    a.toggle(0);
+    assert_eq!(a.iter().collect::<Vec<_>>(), vec![0, 3, 4, 5]);
+    assert_eq!(a.first(), Some(0));
+    assert_eq!(Set::new().first(), None);
+    assert_eq!(format!("{:?}", a), "{0, 3, 4, 5}");
+    assert_eq!(Set::full().len(), 8);
+    assert_eq!(BitSet::<64>::full().len(), 64);
+    assert_eq!(Set::single(7), Set::from_bits(0x80));
+}
+
+#[test]
+fn operators() {
+    let a = Set::from_bits(0b1100);
+    let b = Set::from_bits(0b1010);
+    assert_eq!(a | b, Set::from_bits(0b1110));
+    assert_eq!(a & b, Set::from_bits(0b1000));
+    assert_eq!(a ^ b, Set::from_bits(0b0110));
+    assert_eq!(a - b, Set::from_bits(0b0100));
+    assert_eq!(!a, Set::from_bits(0b11110011));
+    let mut c = a;
+    c |= b;
+    c -= Set::single(3);
+    c ^= Set::single(0);
+    c &= Set::from_bits(0b0111);
+    assert_eq!(c, Set::from_bits(0b0111));
+    assert!((a & b).is_subset(&a) && a.is_superset(&(a & b)));
+    assert!(!a.is_subset(&b));
+    assert!(a.is_disjoint(&!a));
+}
+
+#[test]
+fn subsets() {
+    let a = Set::from_bits(0b10110);
+    let subs: Vec<u64> = a.subsets().map(|s| s.bits()).collect();
+    assert_eq!(subs, vec![0b00000, 0b00010, 0b00100, 0b00110,
+                          0b10000, 0b10010, 0b10100, 0b10110]);
+    assert_eq!(Set::new().subsets().count(), 1);
+    assert_eq!(Set::full().subsets().count(), 256);
+    assert_eq!(a.subsets().filter(|s| s.len() == 2).count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    Set::single(8);
+}
+
+#[test]
+fn bitgrid() {
+    // Glider from 2017 Day 21: ".#./..#/###"
+    let glider = BitGrid::<3>::from_fn(|r, c| [0b010, 0b001, 0b111][r] & (4 >> c) != 0);
+    assert_eq!(format!("{:?}", glider), "BitGrid(.#./..#/###)");
+    assert_eq!(glider.count(), 5);
+    assert!(glider.get(1, 2) && !glider.get(1, 1));
+    assert_eq!(format!("{:?}", glider.hflip()), "BitGrid(.#./#../###)");
+    assert_eq!(format!("{:?}", glider.vflip()), "BitGrid(###/..#/.#.)");
+    assert_eq!(format!("{:?}", glider.transpose()), "BitGrid(..#/#.#/.##)");
+    assert_eq!(format!("{:?}", glider.rotate_right()), "BitGrid(#../#.#/##.)");
+    assert_eq!(glider.rotate_right().rotate_left(), glider);
+    assert_eq!(glider.transpose().hflip(), glider.rotate_right());
+    // The glider is asymmetric, so all eight orientations are distinct.
+    let mut all = glider.symmetries().to_vec();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 8);
+    assert!(all.iter().all(|g| g.canonical() == glider.canonical()));
+    // A full 8x8 grid uses every bit.
+    let full = BitGrid::<8>::from_fn(|_, _| true);
+    assert_eq!(full.bits(), u64::MAX);
+    assert_eq!(full.transpose(), full);
+}
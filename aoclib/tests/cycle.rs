@@ -0,0 +1,62 @@
+//! Test the cycle-detection functions.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::cycle::{find_cycle, find_cycle_floyd, find_cycle_hashed,
+                    nth_state, nth_state_by_key, reduce};
+
+fn step(x: &u64) -> u64 {
+    (x * x + 1) % 1009
+}
+
+// Brute-force reference: simulate N steps.
+fn simulate(mut x: u64, n: usize) -> u64 {
+    for _ in 0..n {x = step(&x);}
+    x
+}
+
+#[test]
+fn detect() {
+    for init in [0u64, 2, 17, 500, 1008] {
+        let (prefix, period) = find_cycle_hashed(init, step);
+        assert!(period > 0);
+        assert_eq!(simulate(init, prefix), simulate(init, prefix + period));
+        if prefix > 0 {
+            assert_ne!(simulate(init, prefix - 1), simulate(init, prefix + period - 1));
+        }
+        assert_eq!(find_cycle(init, step), (prefix, period));
+        assert_eq!(find_cycle_floyd(init, step), (prefix, period));
+    }
+}
+
+#[test]
+fn pure_cycle() {
+    let rotate = |x: &u32| (x + 1) % 7;
+    assert_eq!(find_cycle(3, rotate), (0, 7));
+    assert_eq!(find_cycle_floyd(3, rotate), (0, 7));
+    assert_eq!(find_cycle_hashed(3, rotate), (0, 7));
+    let fixed = |_: &u32| 5;
+    assert_eq!(find_cycle(0, fixed), (1, 1));
+    assert_eq!(find_cycle_floyd(5, fixed), (0, 1));
+}
+
+#[test]
+fn fast_forward() {
+    assert_eq!(reduce(3, 5, 4), 3);
+    assert_eq!(reduce(5, 5, 4), 5);
+    assert_eq!(reduce(10, 5, 4), 6);
+    for n in [0usize, 1, 10, 100, 1000, 5000] {
+        assert_eq!(nth_state(2u64, step, n), simulate(2, n));
+    }
+    let big = 1_000_000_000usize;
+    let (prefix, period) = find_cycle_hashed(2u64, step);
+    assert_eq!(nth_state(2u64, step, big), simulate(2, reduce(big, prefix, period)));
+}
+
+#[test]
+fn by_key() {
+    // The state carries a step counter, but only the value is compared.
+    let count = |s: &(u64, usize)| (step(&s.0), s.1 + 1);
+    let (val, ct) = nth_state_by_key((2u64, 0usize), count, |s| s.0, 12345);
+    assert_eq!(val, simulate(2, 12345));
+    assert!(ct <= 12345);
+}
@@ -0,0 +1,104 @@
+//! Test the vector, direction, and hex-grid types.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::geom::{Dir4, Hex, HexDir, Vec2, Vec3, ROTATIONS};
+use std::collections::HashSet;
+
+#[test]
+fn vec2() {
+    let a = Vec2::new(3i64, -4);
+    let b = Vec2::new(-1i64, 2);
+    assert_eq!(a + b, Vec2::new(2, -2));
+    assert_eq!(a - b, Vec2::new(4, -6));
+    assert_eq!(-a, Vec2::new(-3, 4));
+    assert_eq!(a * 2, Vec2::new(6, -8));
+    let mut c = a;
+    c += b;
+    c -= Vec2::new(1, 1);
+    assert_eq!(c, Vec2::new(1, -3));
+    assert_eq!(a.dot(&b), -11);
+    assert_eq!(a.cross(&b), 2);
+    assert_eq!(a.manhattan(&b), 10);
+    assert_eq!(a.chebyshev(&b), 6);
+    assert_eq!(a.dist_sq(&Vec2::default()), 25);
+    assert_eq!(a.rotate_left(), Vec2::new(4, 3));
+    assert_eq!(a.rotate_right(), Vec2::new(-4, -3));
+    // Distances also work for unsigned coordinates.
+    assert_eq!(Vec2::new(1usize, 9).manhattan(&Vec2::new(4, 2)), 10);
+}
+
+#[test]
+fn vec3() {
+    let a = Vec3::new(1i64, 2, 3);
+    let b = Vec3::new(-2i64, 0, 5);
+    assert_eq!(a + b, Vec3::new(-1, 2, 8));
+    assert_eq!(a - b, Vec3::new(3, 2, -2));
+    assert_eq!(a.dot(&b), 13);
+    assert_eq!(a.manhattan(&b), 7);
+    assert_eq!(a.chebyshev(&b), 3);
+    assert_eq!(a.dist_sq(&b), 17);
+    let x = Vec3::new(1i64, 0, 0);
+    let y = Vec3::new(0i64, 1, 0);
+    assert_eq!(x.cross(&y), Vec3::new(0, 0, 1));
+}
+
+#[test]
+fn rotations() {
+    // Every rotation is distinct and preserves handedness.
+    let x = Vec3::new(1i64, 0, 0);
+    let y = Vec3::new(0i64, 1, 0);
+    let z = Vec3::new(0i64, 0, 1);
+    let mut seen = HashSet::new();
+    for r in 0..ROTATIONS {
+        assert_eq!(x.rotate(r).cross(&y.rotate(r)), z.rotate(r));
+        seen.insert((x.rotate(r), y.rotate(r)));
+    }
+    assert_eq!(seen.len(), ROTATIONS);
+    let p = Vec3::new(1i64, 2, 3);
+    assert_eq!(p.rotations().iter().collect::<HashSet<_>>().len(), ROTATIONS);
+    assert!(p.rotations().iter().all(|q| q.dist_sq(&Vec3::default()) == 14));
+}
+
+#[test]
+fn directions() {
+    assert_eq!(Dir4::North.turn_right(), Dir4::East);
+    assert_eq!(Dir4::North.turn_left(), Dir4::West);
+    assert_eq!(Dir4::West.turn_right(), Dir4::North);
+    assert_eq!(Dir4::East.reverse(), Dir4::West);
+    assert_eq!(Dir4::from_char('v'), Some(Dir4::South));
+    assert_eq!(Dir4::from_char('L'), Some(Dir4::West));
+    assert_eq!(Dir4::from_char('x'), None);
+    assert_eq!(Dir4::South.delta(), (1, 0));
+    for d in Dir4::ALL {
+        assert_eq!(d.turn_left().unit(), d.unit().rotate_left());
+        assert_eq!(d.turn_right().turn_left(), d);
+    }
+}
+
+#[test]
+fn hex() {
+    let origin = Hex::default();
+    assert_eq!(HexDir::parse("se"), Some(HexDir::SouthEast));
+    assert_eq!(HexDir::parse("s"), None);
+    assert_eq!(HexDir::East.turn_right(), HexDir::SouthEast);
+    assert_eq!(HexDir::East.turn_left(), HexDir::NorthEast);
+    assert_eq!(HexDir::NorthWest.reverse(), HexDir::SouthEast);
+    for d in HexDir::ALL {
+        let p = origin.step(d);
+        assert_eq!(p.distance(&origin), 1);
+        assert_eq!(p.step(d.reverse()), origin);
+        assert_eq!(p.rotate_right(), origin.step(d.turn_right()));
+        assert_eq!(p.rotate_left(), origin.step(d.turn_left()));
+    }
+    assert_eq!(origin.neighbors().len(), 6);
+    // Example from 2020 Day 24: "nwwswee" returns to the start.
+    let path = [HexDir::NorthWest, HexDir::West, HexDir::SouthWest,
+                HexDir::East, HexDir::East];
+    assert_eq!(path.iter().fold(origin, |p, d| p.step(*d)), origin);
+    let far = Hex::new(3, -5);
+    assert_eq!(far.distance(&origin), 5);
+    assert_eq!(far.s(), 2);
+    assert_eq!(far - far, origin);
+    assert_eq!(far * 2, far + far);
+    assert_eq!(-far + far, origin);
+}
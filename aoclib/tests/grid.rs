@@ -0,0 +1,112 @@
+//! Test the shared Grid type.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::grid::{Grid, DIR4};
+
+const EXAMPLE: &str = "\
+123
+456
+789
+abc";
+
+fn example() -> Grid<char> {
+    Grid::parse(EXAMPLE, |ch| ch)
+}
+
+#[test]
+fn parse_and_access() {
+    let grid = example();
+    assert_eq!((grid.rows(), grid.cols()), (4, 3));
+    assert_eq!(grid[(0, 0)], '1');
+    assert_eq!(grid[(3, 2)], 'c');
+    assert_eq!(grid.get((1, 2)), Some(&'6'));
+    assert_eq!(grid.get((4, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(*grid.get_wrap(-1, -1), 'c');
+    assert_eq!(*grid.get_wrap(5, 4), '5');
+    assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+    assert_eq!(grid.peek((1, 1), (1, 1)), Some(&'9'));
+
+    let digits = Grid::parse("12\n34", |ch| ch.to_digit(10).unwrap());
+    assert_eq!(digits.values().sum::<u32>(), 10);
+}
+
+#[test]
+fn parse_spaces() {
+    // Leading and trailing spaces are part of the grid.
+    let grid = Grid::parse("\n  |  \n--+--\n  |  \n", |ch| ch);
+    assert_eq!((grid.rows(), grid.cols()), (3, 5));
+    assert_eq!(grid[(0, 0)], ' ');
+    assert_eq!(grid[(1, 2)], '+');
+}
+
+#[test]
+#[should_panic]
+fn parse_ragged() {
+    Grid::parse("123\n45", |ch| ch);
+}
+
+#[test]
+#[should_panic(expected = "column out of bounds")]
+fn col_bounds() {
+    Grid::parse("123\n456", |ch| ch).col(3).count();
+}
+
+#[test]
+#[should_panic(expected = "Grid is empty")]
+fn wrap_empty() {
+    Grid::new(0, 0, 0u8).get_wrap(1, 1);
+}
+
+#[test]
+fn modify() {
+    let mut grid = Grid::new(2, 3, 0u8);
+    assert!(grid.set((1, 2), 5));
+    assert!(!grid.set((2, 0), 5));
+    grid[(0, 1)] = 7;
+    *grid.get_mut((0, 0)).unwrap() += 1;
+    assert_eq!(grid, Grid::from_vec(2, 3, vec![1, 7, 0, 0, 0, 5]));
+    assert_eq!(grid.map(|x| x * 2)[(0, 1)], 14);
+}
+
+#[test]
+fn neighbors() {
+    let grid = example();
+    let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+    assert_eq!(corner, vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbors8((3, 1)).count(), 5);
+    assert_eq!(DIR4.len(), 4);
+}
+
+#[test]
+fn views() {
+    let grid = example();
+    assert_eq!(grid.row(1), &['4', '5', '6']);
+    assert_eq!(grid.col(2).collect::<String>(), "369c");
+    assert_eq!(grid.diag((0, 0)).map(|x| *x.1).collect::<String>(), "159");
+    assert_eq!(grid.anti_diag((1, 2)).map(|x| *x.1).collect::<String>(), "68a");
+    assert_eq!(grid.ray((3, 2), (-1, 0)).map(|x| *x.1).collect::<String>(), "c963");
+    assert_eq!(grid.ray((4, 0), (1, 0)).count(), 0);
+}
+
+#[test]
+fn search() {
+    let grid = example();
+    assert_eq!(grid.position(&'8'), Some((2, 1)));
+    assert_eq!(grid.position(&'x'), None);
+    assert_eq!(grid.find(|ch| ch.is_alphabetic()), Some((3, 0)));
+    assert_eq!(grid.find_all(|ch| ch.is_alphabetic()).count(), 3);
+    assert_eq!(grid.positions().nth(4), Some((1, 1)));
+    assert_eq!(grid.iter().last(), Some(((3, 2), &'c')));
+}
+
+#[test]
+fn display() {
+    let grid = Grid::parse("#.\n.#", |ch| ch == '#');
+    let text = grid.map(|x| if *x {'#'} else {'.'}).to_string();
+    assert_eq!(text, "#.\n.#\n");
+}
@@ -0,0 +1,88 @@
+//! Test the memoization helper.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::memo::Memo;
+
+fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+    if n < 2 {return n;}
+    memo.get_or_compute(n, |memo, &n| fib(memo, n - 1) + fib(memo, n - 2))
+}
+
+#[test]
+fn recursion() {
+    let mut memo = Memo::new();
+    assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    assert_eq!(memo.len(), 89);
+    assert_eq!(memo.misses(), 89);
+    assert_eq!(memo.hits(), 87);
+    assert_eq!(memo.get(&10), Some(&55));
+    assert_eq!(memo.get(&1), None);
+    // A second call is a single hit.
+    assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    assert_eq!(memo.hits(), 88);
+    memo.clear();
+    assert!(memo.is_empty());
+}
+
+#[test]
+fn seeded() {
+    // Count paths to a base case that was inserted ahead of time.
+    let mut memo: Memo<(u32, u32), u64> = Memo::new();
+    memo.insert((0, 0), 1);
+    fn paths(memo: &mut Memo<(u32, u32), u64>, r: u32, c: u32) -> u64 {
+        memo.get_or_compute((r, c), |memo, &(r, c)| {
+            let up = if r > 0 {paths(memo, r - 1, c)} else {0};
+            let left = if c > 0 {paths(memo, r, c - 1)} else {0};
+            up + left
+        })
+    }
+    assert_eq!(paths(&mut memo, 16, 16), 601080390);
+}
+
+#[test]
+fn bounded() {
+    let mut memo = Memo::bounded(3);
+    let mut calls = 0;
+    {
+        let mut square = |memo: &mut Memo<u32, u32>, n: u32| {
+            memo.get_or_compute(n, |_, &n| {calls += 1; n * n})
+        };
+        for n in [1, 2, 3, 1, 4] {square(&mut memo, n);}
+        // Key 2 was least recently used, so it was evicted.
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.get(&2), None);
+        assert_eq!(memo.get(&1), Some(&1));
+        assert_eq!((memo.hits(), memo.misses()), (1, 4));
+        // Repeated use of the same few keys stays within capacity.
+        for n in 0..1000 {square(&mut memo, n % 3);}
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.misses(), 4 + 2);
+        // Hits alone still track the order of use, so the next miss evicts
+        // the oldest key, which was 1.
+        square(&mut memo, 5);
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.get(&1), None);
+        assert!(memo.get(&0).is_some() && memo.get(&2).is_some());
+    }
+    assert_eq!(calls, 7);
+}
+
+#[test]
+fn bounded_matches_model() {
+    // Compare against a simple list of keys, most recently used last.
+    let mut memo: Memo<u64, u64> = Memo::bounded(5);
+    let mut model: Vec<u64> = Vec::new();
+    let mut seed = 12345u64;
+    for _ in 0..10000 {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let key = (seed >> 33) % 9;
+        let hit = model.contains(&key);
+        assert_eq!(memo.get(&key).is_some(), hit);
+        assert_eq!(memo.get_or_compute(key, |_, &k| k * 10), key * 10);
+        model.retain(|&k| k != key);
+        model.push(key);
+        if model.len() > 5 {model.remove(0);}
+        assert_eq!(memo.len(), model.len());
+    }
+    assert_eq!(memo.hits() + memo.misses(), 10000);
+}
@@ -0,0 +1,86 @@
+//! Test the number-theory functions.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::numtheory::*;
+
+#[test]
+fn gcd_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(-12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(lcm(4, 6), 12);
+    assert_eq!(lcm(0, 6), 0);
+    assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+    assert_eq!(lcm_all([]), 1);
+    assert_eq!(lcm(-4, 6), 12);
+    for (a, b) in [(240, 46), (-7, 3), (17, 0), (1 << 40, 3 << 20)] {
+        let (g, x, y) = egcd(a, b);
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn modular() {
+    assert_eq!(modulo(-3, 7), 4);
+    assert_eq!(mul_mod(i64::MAX - 1, i64::MAX - 2, i64::MAX), 2);
+    assert_eq!(pow_mod(2, 10, 1000), 24);
+    assert_eq!(pow_mod(3, 0, 7), 1);
+    assert_eq!(pow_mod(5, 3, 1), 0);
+    assert_eq!(pow_mod(7, 8, 20201227), 5764801);
+    assert_eq!(mod_inv(3, 11), Some(4));
+    assert_eq!(mod_inv(-3, 11), Some(7));
+    assert_eq!(mod_inv(6, 9), None);
+}
+
+#[test]
+#[should_panic(expected = "lcm overflow")]
+fn lcm_overflow() {
+    lcm(1 << 62, 3);
+}
+
+#[test]
+fn chinese_remainder() {
+    assert_eq!(crt(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // Non-coprime moduli, with and without a solution.
+    assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    // Negative residues, as in 2020 Day 13.
+    assert_eq!(crt(&[(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+    // Large moduli need 128-bit intermediates.
+    let big = [(1, 1000000007), (2, 1000000009), (3, 7)];
+    let (x, m) = crt(&big).unwrap();
+    for (r, n) in big {assert_eq!(x % n, r);}
+    assert_eq!(m, 1000000007 * 1000000009 * 7);
+    // Product of moduli above 2^63, but the lcm still fits.
+    let (a, b) = (3i64 << 40, 5i64 << 40);
+    assert_eq!(crt(&[(7, a), (7, b)]), Some((7, 15 << 40)));
+    // Otherwise, there's no way to return the result.
+    assert_eq!(crt(&[(1, 4294967311), (2, 4294967357)]), None);
+    // Moduli must be positive.
+    assert_eq!(crt(&[(1, 3), (0, 0)]), None);
+    assert_eq!(crt(&[(1, -5)]), None);
+}
+
+#[test]
+fn discrete_logarithm() {
+    assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+    assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+    assert_eq!(discrete_log(2, 1, 13), Some(0));
+    assert_eq!(discrete_log(3, 13, 17), Some(4));
+    assert_eq!(discrete_log(2, 3, 7), None);
+}
+
+#[test]
+fn prime_numbers() {
+    assert_eq!(primes(1), Vec::<usize>::new());
+    assert_eq!(primes(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(primes(10000).len(), 1229);
+    assert_eq!(factorize(1), vec![]);
+    assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factorize(20201227), vec![(20201227, 1)]);
+    assert_eq!(factorize(999999937 * 2), vec![(2, 1), (999999937, 1)]);
+    assert_eq!(factorize(u64::MAX), vec![(3, 1), (5, 1), (17, 1), (257, 1),
+        (641, 1), (65537, 1), (6700417, 1)]);
+}
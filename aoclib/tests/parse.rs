@@ -0,0 +1,96 @@
+//! Test the input-parsing helpers and their error locations.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::parse::{blocks, grid, ints, lines, scan_fields, ParseError};
+use aoclib::scan;
+
+#[test]
+fn integers() {
+    assert_eq!(ints::<i64>("x=-3, y=5..8"), Ok(vec![-3, 5, 8]));
+    assert_eq!(ints::<i64>("5-8,-2 - 4"), Ok(vec![5, 8, -2, 4]));
+    assert_eq!(ints::<u8>("Program: 0,1,5,4"), Ok(vec![0, 1, 5, 4]));
+    assert_eq!(ints::<u32>("no numbers"), Ok(vec![]));
+    let err = ints::<u32>("p=1,2 v=-3,4").unwrap_err();
+    assert_eq!((err.line, err.col), (1, 9));
+    let err = ints::<u8>("1\n22\n333").unwrap_err();
+    assert_eq!((err.line, err.col), (3, 1));
+    assert_eq!(err.to_string(), "Line 3, column 1: Expected u8, found \"333\"");
+}
+
+#[test]
+fn patterns() {
+    let line = "Button A: X+94, Y+34";
+    assert_eq!(scan!(line, "Button A: X+{}, Y+{}" => i64, i64), Ok((94, 34)));
+    assert_eq!(scan!("move 3 from 1 to 2", "move {} from {} to {}" => usize, u8, u8),
+               Ok((3, 1, 2)));
+    assert_eq!(scan!("In state A:", "In state {}:" => char), Ok(('A',)));
+    assert_eq!(scan_fields("a=1, b=xyz", "a={}, b={}"), Ok(vec!["1", "xyz"]));
+    // Each kind of mismatch reports where it happened.
+    let err = scan!("Button B: X+94, Y+34", "Button A: X+{}, Y+{}" => i64, i64);
+    assert_eq!(err.unwrap_err().col, 8);
+    let err = scan!("Button A: X+94; Y+34", "Button A: X+{}, Y+{}" => i64, i64);
+    assert_eq!(err.unwrap_err().col, 13);
+    let err = scan!("Button A: X+9z, Y+34", "Button A: X+{}, Y+{}" => i64, i64);
+    assert_eq!(err.unwrap_err().col, 13);
+    let err = scan_fields("3 apples!", "{} apples").unwrap_err();
+    assert_eq!(err, ParseError { line: 1, col: 9, msg: "Unexpected \"!\"".into() });
+}
+
+#[test]
+fn line_numbers() {
+    let input = "\
+        1,2
+        3,4
+
+        5;6";
+    let pairs = |line: &str| scan!(line, "{},{}" => i32, i32);
+    let err = lines(input, pairs).unwrap_err();
+    assert_eq!((err.line, err.col), (4, 9));
+    assert_eq!(lines(&input[..20], pairs), Ok(vec![(1, 2), (3, 4)]));
+}
+
+#[test]
+fn blank_lines() {
+    let input = "a\nb\n\n\nc\n  \nd\ne\n";
+    assert_eq!(blocks(input), vec!["a\nb", "c", "d\ne"]);
+    assert_eq!(blocks("\n\nx\n"), vec!["x"]);
+    assert!(blocks("").is_empty());
+    // Blocks are slices of the input, so errors can find them.
+    let last = blocks(input)[2];
+    let err = ParseError::new(input, &last[2..], "oops");
+    assert_eq!((err.line, err.col), (8, 1));
+    // Anything else has no known location.
+    let copy = last.to_string();
+    let err = ParseError::new(input, &copy, "oops");
+    assert_eq!((err.line, err.col), (0, 0));
+    assert_eq!(err.to_string(), "oops");
+    assert_eq!(ParseError::new(&input[..4], last, "oops").line, 0);
+    assert_eq!(ParseError::new(last, "x", "oops").within(input, last).line, 0);
+}
+
+#[test]
+fn grids() {
+    let digit = |ch: char| ch.to_digit(10);
+    let g = grid("123\n456\n", digit).unwrap();
+    assert_eq!((g.rows(), g.cols()), (2, 3));
+    assert_eq!(g[(1, 2)], 6);
+    let err = grid("123\n4x6", digit).unwrap_err();
+    assert_eq!((err.line, err.col, err.msg.as_str()), (2, 2, "Unexpected 'x'"));
+    let err = grid("123\n45\n789", digit).unwrap_err();
+    assert_eq!((err.line, err.col), (2, 1));
+    // Spaces are cells, but blank lines around the grid are not.
+    let cell = |ch: char| Some(ch == '#');
+    let g = grid("\n\n # \r\n#  \n\n", cell).unwrap();
+    assert_eq!((g.rows(), g.cols()), (2, 3));
+    assert!(g[(0, 1)] && g[(1, 0)] && !g[(0, 0)]);
+}
+
+#[test]
+fn relative() {
+    let input = "abc\n  def: 12x\n";
+    let line = input.lines().nth(1).unwrap().trim();
+    let err = ints::<u8>(line).and_then(|_| scan_fields(line, "def: {}!")).unwrap_err();
+    assert_eq!((err.line, err.col), (1, 6));
+    let err = err.within(input, line);
+    assert_eq!((err.line, err.col), (2, 8));
+}
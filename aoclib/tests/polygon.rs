@@ -0,0 +1,80 @@
+//! Test polygon area, lattice counts, and containment.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::polygon::{Point, Polygon};
+
+fn poly(list: &[(i64, i64)]) -> Polygon {
+    Polygon::new(list.iter().map(|p| Point::new(p.0, p.1)).collect())
+}
+
+#[test]
+fn area() {
+    let square = poly(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    assert_eq!(square.signed_area2(), 32);
+    assert_eq!(square.boundary(), 16);
+    assert_eq!(square.interior(), 9);
+    assert_eq!(square.lattice_points(), 25);
+    // Clockwise order gives a negative signed area.
+    let tri = poly(&[(0, 0), (0, 3), (6, 0)]);
+    assert_eq!(tri.signed_area2(), -18);
+    assert_eq!(tri.area2(), 18);
+    assert_eq!(tri.boundary(), 3 + 3 + 6);
+    assert_eq!(tri.interior(), 4);
+    assert!(square.is_rectilinear() && !tri.is_rectilinear());
+}
+
+#[test]
+fn steps() {
+    // Example from 2023 Day 18.
+    let dig = [(6, 'R'), (5, 'D'), (2, 'L'), (2, 'D'), (2, 'R'), (2, 'D'), (5, 'L'),
+               (2, 'U'), (1, 'L'), (2, 'U'), (2, 'R'), (3, 'U'), (2, 'L'), (2, 'U')];
+    let trench = Polygon::from_steps(dig.iter().map(|(n, d)| match d {
+        'R' => Point::new(*n, 0),
+        'L' => Point::new(-n, 0),
+        'U' => Point::new(0, *n),
+        _   => Point::new(0, -n),
+    }));
+    assert_eq!(trench.vertices().len(), 14);
+    assert_eq!(trench.boundary(), 38);
+    assert_eq!(trench.lattice_points(), 62);
+}
+
+#[test]
+#[should_panic]
+fn steps_open() {
+    Polygon::from_steps([Point::new(1, 0), Point::new(0, 1)]);
+}
+
+#[test]
+fn contains() {
+    // An L-shaped region, and a triangle.
+    let ell = poly(&[(0, 0), (6, 0), (6, 2), (2, 2), (2, 6), (0, 6)]);
+    assert!(ell.contains(Point::new(1, 1)));
+    assert!(ell.contains(Point::new(6, 1)));
+    assert!(ell.contains(Point::new(2, 4)));
+    assert!(ell.contains(Point::new(0, 6)));
+    assert!(!ell.contains(Point::new(3, 3)));
+    assert!(!ell.contains(Point::new(7, 1)));
+    assert!(!ell.contains(Point::new(-1, 2)));
+    let tri = poly(&[(0, 0), (6, 0), (0, 6)]);
+    assert!(tri.contains(Point::new(3, 3)));
+    assert!(tri.contains(Point::new(1, 1)));
+    assert!(!tri.contains(Point::new(4, 3)));
+    assert!(!tri.contains(Point::new(-1, 0)));
+}
+
+#[test]
+fn contains_rect() {
+    // Example from 2025 Day 9.
+    let red = poly(&[(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)]);
+    assert!(red.contains_rect(Point::new(9, 5), Point::new(2, 3)));
+    assert!(red.contains_rect(Point::new(7, 3), Point::new(11, 1)));
+    assert!(!red.contains_rect(Point::new(2, 5), Point::new(11, 1)));
+    assert!(!red.contains_rect(Point::new(2, 3), Point::new(7, 1)));
+    // Degenerate rectangles are line segments.
+    assert!(red.contains_rect(Point::new(2, 4), Point::new(11, 4)));
+    assert!(red.contains_rect(Point::new(9, 7), Point::new(11, 7)));
+    assert!(!red.contains_rect(Point::new(2, 6), Point::new(11, 6)));
+    assert!(!red.contains_rect(Point::new(2, 7), Point::new(11, 7)));
+    assert!(!red.contains_rect(Point::new(10, 8), Point::new(10, 8)));
+}
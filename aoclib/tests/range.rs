@@ -0,0 +1,94 @@
+//! Test interval sets, range mapping, and cuboids.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::range::{Cuboid, CuboidSet, RangeMap, RangeSet};
+
+fn set(list: &[(i32, i32)]) -> RangeSet<i32> {
+    list.iter().map(|r| r.0..r.1).collect()
+}
+
+#[test]
+fn normalize() {
+    let a = set(&[(5, 9), (0, 3), (4, 4), (2, 4), (9, 12)]);
+    assert_eq!(a.ranges(), &[0..4, 5..12]);
+    assert_eq!(a.size(), 11);
+    assert_eq!(a.first(), Some(0));
+    assert!(a.contains(0) && a.contains(3) && a.contains(11));
+    assert!(!a.contains(4) && !a.contains(12) && !a.contains(-1));
+    assert!(set(&[(3, 3)]).is_empty());
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(0, 10), (20, 30)]);
+    let b = set(&[(5, 25), (28, 40)]);
+    assert_eq!(a.union(&b), set(&[(0, 40)]));
+    assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+    assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+    assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+    assert_eq!(a.complement(-5..50), set(&[(-5, 0), (10, 20), (30, 50)]));
+    assert_eq!(a.complement(0..10), RangeSet::new());
+
+    let mut c = a.clone();
+    c.insert(10..20);
+    assert_eq!(c, set(&[(0, 30)]));
+    c.remove(3..7);
+    assert_eq!(c, set(&[(0, 3), (7, 30)]));
+}
+
+#[test]
+fn mapping() {
+    // Seed-to-soil map from 2023 Day 5: "50 98 2" and "52 50 48".
+    let mut map = RangeMap::new();
+    map.insert(98..100, 50);
+    map.insert(50..98, 52);
+    assert_eq!(map.get(0), 0);
+    assert_eq!(map.get(53), 55);
+    assert_eq!(map.get(99), 51);
+    assert_eq!(map.get(100), 100);
+    let seeds: RangeSet<i64> = [79..93, 55..68].into_iter().collect();
+    assert_eq!(map.apply(&seeds).ranges(), &[57..70, 81..95]);
+    // Mapped intervals may overlap or abut, and are merged.
+    assert_eq!(map.apply(&RangeSet::from(45..105)), RangeSet::from(45..105));
+    assert_eq!(map.apply(&RangeSet::from(96..100)).ranges(), &[50..52, 98..100]);
+}
+
+#[test]
+#[should_panic]
+fn mapping_overlap() {
+    let mut map = RangeMap::new();
+    map.insert(0..10, 100);
+    map.insert(5..15, 200);
+}
+
+#[test]
+fn cuboids() {
+    let a = Cuboid::new([0, 0, 0], [3, 3, 3]);
+    let b = Cuboid::new([1, 1, 1], [4, 4, 4]);
+    assert_eq!(a.volume(), 27);
+    assert_eq!(a.intersect(&b), Some(Cuboid::new([1, 1, 1], [3, 3, 3])));
+    assert_eq!(a.intersect(&Cuboid::new([3, 0, 0], [4, 1, 1])), None);
+    let pieces = a.subtract(&b);
+    assert!(pieces.len() <= 6);
+    assert_eq!(pieces.iter().map(Cuboid::volume).sum::<u64>(), 27 - 8);
+    assert!(pieces.iter().all(|p| p.intersect(&b).is_none()));
+    assert_eq!(Cuboid::new([0, 0], [5, 0]).volume(), 0);
+}
+
+#[test]
+fn cuboid_set() {
+    // Example from 2021 Day 22, with upper bounds incremented.
+    let mut cubes = CuboidSet::new();
+    cubes.insert(Cuboid::new([10, 10, 10], [13, 13, 13]));
+    assert_eq!(cubes.volume(), 27);
+    cubes.insert(Cuboid::new([11, 11, 11], [14, 14, 14]));
+    assert_eq!(cubes.volume(), 27 + 19);
+    cubes.remove(&Cuboid::new([9, 9, 9], [12, 12, 12]));
+    assert_eq!(cubes.volume(), 27 + 19 - 8);
+    cubes.insert(Cuboid::new([10, 10, 10], [11, 11, 11]));
+    assert_eq!(cubes.volume(), 39);
+    assert!(cubes.contains(&[10, 10, 10]));
+    assert!(!cubes.contains(&[10, 10, 11]));
+    let clip = cubes.intersection(&Cuboid::new([0, 0, 0], [12, 12, 12]));
+    assert_eq!(clip.volume(), 1);
+}
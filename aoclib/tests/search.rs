@@ -0,0 +1,95 @@
+//! Test the shared search algorithms.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::grid::{Grid, Rc};
+use aoclib::search::{astar, bfs, dijkstra};
+
+const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+fn maze() -> (Grid<char>, Rc, Rc) {
+    let grid = Grid::parse(MAZE, |ch| ch);
+    let start = grid.position(&'S').unwrap();
+    let end = grid.position(&'E').unwrap();
+    (grid, start, end)
+}
+
+fn open(grid: &Grid<char>, rc: Rc) -> Vec<Rc> {
+    grid.neighbors4(rc).filter(|x| grid[*x] != '#').collect()
+}
+
+#[test]
+fn bfs_maze() {
+    let (grid, start, end) = maze();
+    let result = bfs(start, |rc| open(&grid, *rc), |rc| *rc == end);
+    assert_eq!(result.goal(), Some(&end));
+    assert_eq!(result.goal_cost(), Some(15));
+    let path = result.path(&end).unwrap();
+    assert_eq!(path.len(), 16);
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&end));
+    assert!(path.windows(2).all(|w| grid.neighbors4(w[0]).any(|x| x == w[1])));
+    assert!(result.expanded() <= result.visited());
+}
+
+#[test]
+fn bfs_unreachable() {
+    let (grid, start, _) = maze();
+    let result = bfs(start, |rc| open(&grid, *rc), |_| false);
+    assert_eq!(result.goal(), None);
+    assert_eq!(result.goal_cost(), None);
+    assert_eq!(result.visited(), grid.values().filter(|ch| **ch != '#').count());
+    assert_eq!(result.cost(&(4, 5)), Some(9));
+    assert_eq!(result.path(&(0, 3)), None);
+}
+
+#[test]
+fn all_best_paths() {
+    // Two equal routes around a central block, plus a longer detour.
+    let grid = Grid::parse("S...\n.##.\n...E", |ch| ch);
+    let result = bfs((0, 0), |rc| open(&grid, *rc), |rc| *rc == (2, 3));
+    assert_eq!(result.goal_cost(), Some(5));
+    assert_eq!(result.predecessors(&(2, 3)).len(), 2);
+    assert_eq!(result.on_best_paths().len(), 10);
+    assert_eq!(result.on_paths_to(&[(0, 2)]).len(), 3);
+}
+
+#[test]
+fn dijkstra_weighted() {
+    // Each step costs the digit being entered.
+    let grid = Grid::parse("1163\n1381\n2136", |ch| ch.to_digit(10).unwrap() as u64);
+    let end = (2, 3);
+    let next = |rc: &Rc| -> Vec<(Rc, u64)> {
+        grid.neighbors4(*rc).map(|x| (x, grid[x])).collect()
+    };
+    let result = dijkstra((0, 0), next, |rc| *rc == end);
+    assert_eq!(result.goal_cost(), Some(13));
+    let path = result.path(&end).unwrap();
+    let total: u64 = path[1..].iter().map(|rc| grid[*rc]).sum();
+    assert_eq!(total, 13);
+
+    // Ties with multiple goal states.
+    let ring = |n: &u32| [((n + 1) % 6, 1u32), ((n + 5) % 6, 1u32)];
+    let result = dijkstra(0, ring, |n| *n == 2 || *n == 4);
+    assert_eq!(result.goal_cost(), Some(2));
+    assert_eq!(result.goals().len(), 2);
+    assert_eq!(result.on_best_paths().len(), 5);
+}
+
+#[test]
+fn astar_maze() {
+    let (grid, start, end) = maze();
+    let next = |rc: &Rc| -> Vec<(Rc, usize)> {
+        open(&grid, *rc).into_iter().map(|x| (x, 1)).collect()
+    };
+    let dist = |rc: &Rc| end.0.abs_diff(rc.0) + end.1.abs_diff(rc.1);
+    let plain = dijkstra(start, next, |rc| *rc == end);
+    let fast = astar(start, next, dist, |rc| *rc == end);
+    assert_eq!(fast.goal_cost(), Some(15));
+    assert_eq!(fast.goal_cost(), plain.goal_cost());
+    assert!(fast.expanded() <= plain.expanded());
+}
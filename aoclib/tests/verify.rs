@@ -0,0 +1,74 @@
+//! Test recording and checking answers.
+//! Copyright 2025 by Alex Utter
+
+use aoclib::{AnswerKey, Check, Entry, Solution};
+
+struct Example;
+
+impl Solution for Example {
+    type Input = Vec<i64>;
+    fn parse(input: &str) -> Vec<i64> {
+        input.split(',').map(|x| x.trim().parse().unwrap()).collect()
+    }
+    fn part1(input: &Vec<i64>) -> impl std::fmt::Display {
+        input.iter().sum::<i64>()
+    }
+}
+
+fn temp_file(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("aoclib-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join("answers.toml")
+}
+
+#[test]
+fn check_answers() {
+    assert_eq!(aoclib::check(Some("6"), "6"), Check::Pass);
+    assert_eq!(aoclib::check(Some("6"), "7"), Check::Fail("6".to_string()));
+    assert_eq!(aoclib::check(None, "7"), Check::New);
+    assert_eq!(aoclib::check(None, ""), Check::Skip);
+}
+
+#[test]
+fn record_and_verify() {
+    let path = temp_file("record");
+    let entry = Entry::new::<Example>(2000, 1);
+    let result = entry.solve("1, 2, 3");
+
+    // A missing file is an error, but a new key has no answers, and is
+    // only created once recorded.
+    assert!(AnswerKey::load(2000, &path).is_err());
+    let mut key = AnswerKey::new(2000, &path);
+    assert_eq!(key.check(1, &result), [Check::New, Check::Skip]);
+    key.save().unwrap();
+    assert!(!path.exists());
+    assert!(key.record(1, &result));
+    key.save().unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "[day01]\npart1 = \"6\"\n");
+
+    // Reload and check, including a regression.
+    let mut key = AnswerKey::load(2000, &path).unwrap();
+    assert_eq!(key.get(1, 1), Some("6"));
+    assert_eq!(key.get(1, 2), None);
+    assert_eq!(key.check(1, &result), [Check::Pass, Check::Skip]);
+    let wrong = entry.solve("1, 2, 4");
+    assert_eq!(key.check(1, &wrong), [Check::Fail("6".to_string()), Check::Skip]);
+
+    // Existing answers are never replaced.
+    assert!(!key.record(1, &wrong));
+    assert_eq!(key.get(1, 1), Some("6"));
+}
+
+#[test]
+fn multi_line() {
+    let path = temp_file("multi");
+    std::fs::write(&path, "[day13]\npart2 = \"\"\"\n#..#\n####\n\"\"\"\n").unwrap();
+    let key = AnswerKey::load(2021, &path).unwrap();
+    assert_eq!(key.get(13, 2), Some("#..#\n####\n"));
+    assert_eq!(key.get(13, 1), None);
+
+    std::fs::write(&path, "[day01\n").unwrap();
+    assert!(AnswerKey::load(2021, &path).is_err());
+}